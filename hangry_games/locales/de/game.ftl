@@ -0,0 +1,363 @@
+# Erzählung für das Spielprotokoll.
+# Die Argumente der einzelnen Nachrichten stehen in locales/en/game.ftl.
+
+## Ankündigungen
+
+game-day-start = === ☀️ Tag { $day } beginnt! ===
+first-day-start = === 🎉 Die Hungerspiele beginnen! 🎉 ===
+feast-day-start = === 😋 Tag { $day }: Festmahl ===
+feast-announced =
+    { $backpacks ->
+        [tribute] === 📣 Festmahl am Füllhorn im Morgengrauen von Tag { $day }, mit einem Rucksack für jeden Tribut voller Dinge, die er braucht ===
+        [district] === 📣 Festmahl am Füllhorn im Morgengrauen von Tag { $day }, mit einem Rucksack für jeden Distrikt voller Dinge, die er braucht ===
+       *[none] === 📣 Festmahl am Füllhorn im Morgengrauen von Tag { $day } ===
+    }
+tributes-left = === 📌 Lebende Tribute: { $count } ===
+game-night-start = === 🌙 Nacht { $day } beginnt ===
+daily-death-announcement = === 💀 Tote Tribute: { $count } ===
+death-announcement = === 🪦 { $tribute } ist gestorben ===
+no-one-wins = === 🎭 Niemand gewinnt! ===
+tribute-wins =
+    { $tribute-pronouns ->
+        [plural] === 🏆 Die Sieger sind { $tribute } ===
+        [she] === 🏆 Die Siegerin ist { $tribute } ===
+        [he] === 🏆 Der Sieger ist { $tribute } ===
+       *[other] === 🏆 { $tribute } gewinnt die Spiele ===
+    }
+area-event = === ⚠️ Achtung: { $indefinite } { $location }! ===
+area-close = === 🔔 { $subject } ist unbewohnbar ===
+area-open = === 🔔 { $subject } ist wieder bewohnbar ===
+arena-hazard = === ⚠️ Achtung: { $indefinite } fegt durch die ganze Arena! ===
+sudden-death = === ⏰ Tag { $day }: Die Zeit ist um! Alle Tribute werden zum Füllhorn getrieben ===
+finale = === 🔥 Das Finale beginnt! Die Spielmacher treiben die Überlebenden zum Füllhorn ===
+pack-sighted = 🐾 Spuren von { $count } { $animal } { $location }
+mutts-released = === 🧬 Die Spielmacher lassen { $location } eine Meute von { $count } { $animal } los ===
+outbreak =
+    { $count ->
+        [one] === 🦠 Ausbruch { $location }! { $count } Tribut hat sich angesteckt ({ $status }) ===
+       *[other] === 🦠 Ausbruch { $location }! { $count } Tribute haben sich angesteckt ({ $status }) ===
+    }
+rule-change-announced = === 📢 Regeländerung: { $rule } ===
+rule-change-revoked = === 📢 Regeländerung zurückgenommen: { $rule } ===
+
+## Training und Interviews
+
+training-scores-start = === 🏹 Die Spielmacher verkünden die Trainingsergebnisse ===
+tribute-training-score = 🎯 { $tribute } erhält eine Trainingsbewertung von { $score }
+interviews-start = === 🎤 Die Tribute werden interviewt ===
+tribute-interview =
+    { $reception ->
+        [adored] 🌟 { $tribute } hat das Publikum fest im Griff (Beliebtheit { $likability })
+        [liked] 🎤 { $tribute } bezaubert das Publikum (Beliebtheit { $likability })
+        [flat] 😐 Das Interview von { $tribute } verpufft (Beliebtheit { $likability })
+       *[disliked] 🙄 { $tribute } verprellt das Publikum (Beliebtheit { $likability })
+    }
+
+## Ausruhen, verstecken und bewegen
+
+tribute-rest = 😪 { $tribute } ruht sich aus
+tribute-long-rest = 💤 { $tribute } ruht sich aus und erholt etwas Gesundheit und Verstand
+tribute-hide = 🫥 { $tribute } versucht sich zu verstecken
+tribute-spotted = 👀 { $spotter } entdeckt { $hider } in einem Versteck in der Nähe
+tribute-unspotted = 🌿 { $hider } bleibt vor { $spotter } verborgen
+tribute-hiding-spot-lost = 🍂 Das Versteck von { $tribute } bietet keinen Schutz mehr
+tribute-travel = 🚶 { $tribute } geht von { $from } nach { $to }
+tribute-travel-too-tired = 😴 { $tribute } ist zu müde zum Weitergehen und ruht sich { $location } aus
+tribute-travel-already-there = 🤔 { $tribute } ist schon { $location } und bleibt
+tribute-travel-follow = 🫡 { $tribute } folgt dem Distriktpartner nach { $area }
+tribute-travel-stay = 🪑 { $tribute } bleibt { $location }
+tribute-travel-hunt = 🎯 { $tribute } jagt { $target } bis nach { $area }
+tribute-travel-scavenge = 🧭 { $tribute } macht sich auf nach { $area }, wo noch Vorräte liegen
+tribute-travel-flee = 🏃 { $tribute } flieht nach { $area }
+trapped-in-area = 💥 { $tribute } sitzt { $location } fest.
+died-in-area = 💥 { $tribute } ist { $location } gestorben.
+
+## Gegenstände
+
+tribute-take-item = 🔨 { $tribute } nimmt: { $item }
+tribute-take-backpack =
+    { $label ->
+        [district] 🎒 { $tribute } schnappt sich den Rucksack mit der { $district } und findet: { $item }
+       *[tribute] 🎒 { $tribute } schnappt sich den Rucksack mit dem eigenen Namen und findet: { $item }
+    }
+tribute-cannot-use-item = ❌ { $tribute } kann das nicht benutzen: { $item }
+tribute-use-item = 💊 { $tribute } benutzt { $item } und erhält { $effect } { $attribute }
+weapon-break = 🗡️ { $tribute } zerbricht: { $item }
+shield-break = 🛡️ { $tribute } zerbricht: { $item }
+sponsor-gift = 🎁 { $tribute } erhält { $item } ({ $quantity }x { $attribute } +{ $effect })
+tribute-crafts = 🛠️ { $tribute } baut { $item } (+{ $effect } { $attribute })
+tribute-sets-trap = 🪤 { $tribute } stellt { $location } eine Falle ({ $trap })
+tribute-caught-in-trap = 🪤 { $tribute } gerät in die Falle von { $owner } ({ $trap }) und erleidet { $damage } Schaden!
+tribute-dies-in-trap = 💀 { $tribute } stirbt in der Falle von { $owner } ({ $trap })
+tribute-disarms-trap = 🔍 { $tribute } entdeckt gerade noch rechtzeitig eine Falle ({ $trap }) und macht sie unschädlich
+tribute-steals-trap = 🔍 { $tribute } entdeckt eine Falle ({ $trap }) und nimmt sie an sich
+tribute-gathers =
+    { $action ->
+        [forage] 🫐 { $tribute } sammelt und findet { $item }
+        [hunt] 🏹 { $tribute } geht auf die Jagd und bringt { $item } mit
+        [fish] 🎣 { $tribute } angelt und fängt { $item }
+       *[find-water] 💧 { $tribute } findet sauberes Wasser
+    }
+tribute-gathers-nothing =
+    { $action ->
+        [forage] 🫐 { $tribute } sucht nach Essbarem, findet aber nichts
+        [hunt] 🏹 { $tribute } geht auf die Jagd, kommt aber mit leeren Händen zurück
+        [fish] 🎣 { $tribute } angelt, fängt aber nichts
+       *[find-water] 💧 { $tribute } sucht nach Wasser, findet aber keines
+    }
+tribute-gathering-mishap =
+    { $action ->
+        [forage] ☠️ { $tribute } isst Beeren, die sich als giftig herausstellen!
+        [hunt] 🩸 { $tribute } verletzt sich bei der Jagd!
+        [fish] 🌊 { $tribute } wird beim Angeln von der Strömung mitgerissen!
+       *[find-water] 🤢 { $tribute } trinkt aus einem verseuchten Bach und wird krank!
+    }
+
+## Zustände
+
+tribute-bleeds = 🩸 { $tribute } blutet aus den Wunden.
+tribute-sick = 🤒 { $tribute } erkrankt an Ruhr, verliert Stärke und Geschwindigkeit
+tribute-electrocuted = 🌩️ { $tribute } wird vom Blitz getroffen, verliert Gesundheit
+tribute-frozen = 🥶 { $tribute } leidet an Unterkühlung, verliert Geschwindigkeit.
+tribute-overheated = 🥵 { $tribute } erleidet einen Hitzschlag, verliert Geschwindigkeit.
+tribute-dehydrated = 🌵 { $tribute } ist stark ausgetrocknet, verliert Stärke
+tribute-starving = 🍴 { $tribute } ist völlig ausgehungert, verliert Stärke
+tribute-poisoned = 🧪 { $tribute } isst etwas Giftiges, verliert Verstand
+tribute-broken-arm = 🦴 { $tribute } verletzt sich am Arm, verliert Stärke.
+tribute-broken-leg = 🦴 { $tribute } verletzt sich am Bein, verliert Geschwindigkeit.
+tribute-infected = 🤢 { $tribute } bekommt eine Infektion, verliert Gesundheit und Verstand
+tribute-falls-ill = 🦠 { $tribute } hat sich angesteckt ({ $status })
+tribute-drowned = 🏊 { $tribute } ertrinkt beinahe, verliert Gesundheit und Verstand
+tribute-mauled = 🐾 { $tribute } wird von { $count } { $animal } angegriffen und erleidet { $damage } Schaden!
+tribute-hunted-by-mutts = 🐺 Mutationen nehmen { $location } die Fährte von { $tribute } auf!
+tribute-kills-animals = ⚔️ { $tribute } wehrt sich und macht mit { $count } { $animal } kurzen Prozess!
+tribute-burned = 🔥 { $tribute } verbrennt sich, verliert Gesundheit
+
+## Verstand
+
+tribute-horrified = 😱 { $tribute } ist entsetzt von der Gewalt, verliert { $damage } Verstand.
+tribute-suffer = 😭 { $tribute } leidet unter Einsamkeit und Angst.
+tribute-witness-death = 😨 { $tribute } sieht { $victim } sterben, verliert { $damage } Verstand.
+tribute-mourn-district-mate = 🕯️ { $tribute } trauert um { $victim } aus dem eigenen Distrikt, verliert { $damage } Verstand.
+tribute-shaken = 😰 { $tribute } ist erschüttert von { $definite }, verliert { $damage } Verstand.
+tribute-breakdown =
+    { $breakdown ->
+        [paranoia] 🫨 { $tribute } dreht durch und traut niemandem mehr.
+        [hallucination] 🌀 { $tribute } sieht Dinge, die nicht da sind.
+       *[catatonia] 🧊 { $tribute } verstummt und reagiert nicht mehr.
+    }
+tribute-recovers = 🌅 { $tribute } fängt sich wieder.
+tribute-catatonic = 😶 { $tribute } starrt ins Leere.
+tribute-comforted = 🫂 { $tribute } findet Trost in der Gesellschaft von { $ally }.
+tribute-self-harm = 🤦 { $tribute } versucht, sich selbst anzugreifen!
+tribute-suicide = 🪒 { $tribute } versucht, sich das Leben zu nehmen.
+
+## Kämpfe
+
+tribute-attack-win = 🔪 { $tribute } greift { $target } an und gewinnt!
+tribute-attack-win-extra = 🔪 { $tribute } greift { $target } an und gewinnt deutlich!
+tribute-attack-wound = 🤕 { $tribute } verwundet { $target }
+tribute-attack-lose = 🤣 { $tribute } greift { $target } an, verliert aber!
+tribute-attack-lose-extra = 🤣 { $tribute } greift { $target } an, verliert aber deutlich!
+tribute-attack-miss = 😰 { $tribute } greift { $target } an, verfehlt aber!
+tribute-attack-died = ☠️ { $tribute } wird von { $target } getötet
+tribute-attack-success-kill = ☠️ { $tribute } tötet { $target }
+tribute-attack-hidden = 🤔 { $tribute } kann { $target } nicht angreifen, das Ziel ist versteckt
+tribute-revenge = 😤 { $tribute } entdeckt { $target } und sinnt auf Rache!
+
+## Tode
+
+tribute-dies-from-status = 💀 { $tribute } stirbt ({ $status })
+tribute-dies-from-area-event = 🪦 { $tribute } starb in { $definite }.
+tribute-dies-from-tribute-event = 💀 { $tribute } stirbt an { $event }
+tribute-already-dead = ‼️ { $tribute } ist bereits tot!
+tribute-dead = ❗️ { $tribute } ist tot!
+
+## Gebiete
+
+area-cornucopia = Füllhorn
+    .location = im Füllhorn
+    .subject = Das Füllhorn
+area-northeast = Nordosten
+    .location = im Nordosten
+    .subject = Der Nordosten
+area-northwest = Nordwesten
+    .location = im Nordwesten
+    .subject = Der Nordwesten
+area-southeast = Südosten
+    .location = im Südosten
+    .subject = Der Südosten
+area-southwest = Südwesten
+    .location = im Südwesten
+    .subject = Der Südwesten
+
+## Gebietsereignisse
+
+event-wildfire = Waldbrand
+    .indefinite = ein Waldbrand
+    .definite = dem Waldbrand
+event-flood = Überschwemmung
+    .indefinite = eine Überschwemmung
+    .definite = der Überschwemmung
+event-earthquake = Erdbeben
+    .indefinite = ein Erdbeben
+    .definite = dem Erdbeben
+event-avalanche = Lawine
+    .indefinite = eine Lawine
+    .definite = der Lawine
+event-blizzard = Schneesturm
+    .indefinite = ein Schneesturm
+    .definite = dem Schneesturm
+event-landslide = Erdrutsch
+    .indefinite = ein Erdrutsch
+    .definite = dem Erdrutsch
+event-heatwave = Hitzewelle
+    .indefinite = eine Hitzewelle
+    .definite = der Hitzewelle
+
+## Tributereignisse
+
+event-animal-attack = einem Tierangriff ({ $animal })
+event-dysentery = Ruhr
+event-lightning-strike = einem Blitzschlag
+event-hypothermia = Unterkühlung
+event-heat-stroke = einem Hitzschlag
+event-dehydration = Austrocknung
+event-starvation = Hunger
+event-poisoning = einer Vergiftung
+event-broken-bone = einem Knochenbruch
+event-infection = einer Infektion
+event-drowning = Ertrinken
+event-burn = Verbrennungen
+
+## Zustände
+
+status-healthy = gesund
+status-wounded = verwundet
+status-starving = hungernd
+status-dehydrated = ausgetrocknet
+status-sick = krank
+status-poisoned = vergiftet
+status-recently-dead = kürzlich verstorben
+status-dead = tot
+status-electrocuted = vom Blitz getroffen
+status-frozen = erfroren
+status-overheated = überhitzt
+status-broken = gebrochen
+status-infected = infiziert
+status-drowned = ertrunken
+status-burned = verbrannt
+status-buried = verschüttet
+status-mauled = zerfleischt ({ $animal })
+
+## Gegenstandsattribute
+
+attribute-health = Gesundheit
+attribute-sanity = Verstand
+attribute-movement = Bewegung
+attribute-bravery = Tapferkeit
+attribute-speed = Geschwindigkeit
+attribute-strength = Stärke
+attribute-defense = Verteidigung
+attribute-stealth = Tarnung
+
+## Tiere, nach Anzahl (im Dativ, wie in „von drei Wölfen angegriffen“)
+
+animal-squirrel =
+    { $count ->
+        [one] Eichhörnchen
+       *[other] Eichhörnchen
+    }
+animal-bear =
+    { $count ->
+        [one] Bären
+       *[other] Bären
+    }
+animal-wolf =
+    { $count ->
+        [one] Wolf
+       *[other] Wölfen
+    }
+animal-cougar =
+    { $count ->
+        [one] Puma
+       *[other] Pumas
+    }
+animal-boar =
+    { $count ->
+        [one] Wildschwein
+       *[other] Wildschweinen
+    }
+animal-snake =
+    { $count ->
+        [one] Schlange
+       *[other] Schlangen
+    }
+animal-monkey =
+    { $count ->
+        [one] Affen
+       *[other] Affen
+    }
+animal-baboon =
+    { $count ->
+        [one] Pavian
+       *[other] Pavianen
+    }
+animal-hyena =
+    { $count ->
+        [one] Hyäne
+       *[other] Hyänen
+    }
+animal-lion =
+    { $count ->
+        [one] Löwen
+       *[other] Löwen
+    }
+animal-tiger =
+    { $count ->
+        [one] Tiger
+       *[other] Tigern
+    }
+animal-elephant =
+    { $count ->
+        [one] Elefanten
+       *[other] Elefanten
+    }
+animal-rhino =
+    { $count ->
+        [one] Nashorn
+       *[other] Nashörnern
+    }
+animal-hippo =
+    { $count ->
+        [one] Nilpferd
+       *[other] Nilpferden
+    }
+animal-tracker-jacker =
+    { $count ->
+        [one] Jägerwespe
+       *[other] Jägerwespen
+    }
+animal-mutt =
+    { $count ->
+        [one] Mutation
+       *[other] Mutationen
+    }
+
+## Rulesets
+
+ruleset-standard = Standard
+ruleset-victors-only = Nur Sieger
+ruleset-double-tributes = Doppelte Tribute
+ruleset-co-victors = Gemeinsame Sieger aus einem Distrikt
+ruleset-no-sponsors = Keine Sponsoren
+ruleset-night-hazards = Nächtliche Gefahren in der ganzen Arena
+
+## Siegbedingungen und Regeländerungen
+
+win-condition-last-standing = Der letzte Überlebende
+win-condition-last-district = Der letzte Distrikt
+win-condition-last-alliance = Das letzte Bündnis
+win-condition-time-limit = Der letzte Überlebende, Sudden Death nach { $days } Tagen
+rule-change-district-co-victors = zwei Tribute aus demselben Distrikt können jetzt gemeinsam gewinnen
+rule-change-district-co-victors-revoked = es kann doch nur einen Sieger geben
@@ -0,0 +1,84 @@
+# Beschriftungen der Desktop-App.
+
+gui-home = Start
+gui-back-to-game = Zurück zum Spiel
+gui-back-to-log = Zurück zum Protokoll
+gui-game = Spiel
+gui-game-name = Name des Spiels
+gui-roster-size = Tribute
+gui-districts = Distrikte
+gui-ruleset = Regeln
+gui-create-game = Spiel erstellen
+gui-language = Sprache
+gui-fill-game = Spiel auffüllen
+gui-play-next-day = Nächsten Tag spielen
+gui-day = Tag
+gui-day-number = Tag { $day }
+gui-days = Tage
+gui-day-log = Tagesprotokoll
+gui-full-log = Gesamtes Protokoll
+gui-log-by = { $message } von { $tribute }
+gui-wins = { $name } gewinnt!
+gui-no-one-wins = Niemand gewinnt!
+gui-no-winner = Kein Sieger
+gui-or = oder
+gui-yes = Ja
+gui-no = Nein
+gui-fill-with-tributes = Mit Tributen auffüllen?
+gui-confirm-fill = Soll { $name } wirklich mit Tributen aufgefüllt werden?
+gui-delete-game = Spiel löschen?
+gui-delete-tribute = Tribut löschen?
+gui-confirm-delete = Soll { $name } wirklich gelöscht werden?
+gui-living-tributes = Lebende Tribute
+gui-winner = Sieger
+gui-actions = Aktionen
+
+## Tribute
+
+gui-name = Name
+gui-real-name = Echter Name
+gui-pronouns = Pronomen
+gui-district = Distrikt
+gui-district-number = Distrikt { $district }
+gui-status = Zustand
+gui-add-tribute = Tribut hinzufügen
+gui-update-tribute = Tribut aktualisieren
+gui-preset = Profil
+gui-custom-build = Eigenes
+gui-preset-balanced = Ausgewogen
+gui-preset-brawler = Schläger
+gui-preset-sneak = Schleicher
+gui-preset-survivor = Überlebenskünstler
+gui-points-spent = { $spent } / { $budget } Punkte
+gui-import-roster = Kader importieren (CSV oder JSON)
+gui-roster-format = Kader müssen .csv- oder .json-Dateien sein
+gui-upload-image = Bild hochladen
+gui-attributes = Eigenschaften
+gui-statistics = Statistiken
+gui-death = Tod
+gui-day-killed = Todestag
+gui-killed-by = Getötet von
+gui-health = Gesundheit
+gui-sanity = Verstand
+gui-movement = Bewegung
+gui-strength = Stärke
+gui-defense = Verteidigung
+gui-bravery = Tapferkeit
+gui-loyalty = Loyalität
+gui-speed = Geschwindigkeit
+gui-intelligence = Intelligenz
+gui-persuasion = Überzeugungskraft
+gui-luck = Glück
+gui-dexterity = Geschicklichkeit
+gui-kills = Tötungen
+gui-wins-count = Siege
+gui-defeats = Niederlagen
+gui-draws = Unentschieden
+gui-games = Spiele
+gui-training-score = Trainingsbewertung
+gui-likability = Beliebtheit
+gui-standings = Wett-Rangliste
+gui-balance = Guthaben
+gui-open-bets = Offene Wetten
+gui-bets-won = Gewonnene Wetten
+gui-no-spectators = Noch hat niemand gewettet.
@@ -0,0 +1,371 @@
+# Narration for the game log.
+# Tributes and items are passed in by name. Areas come with `$area`, plus
+# `$location` ("in the Northeast") and `$subject` ("The Northeast") forms.
+# Area events come with `$event`, `$indefinite` and `$definite` forms.
+
+## Announcements
+
+game-day-start = === ☀️ Day { $day } begins! ===
+first-day-start = === 🎉 The Hunger Games begin! 🎉 ===
+feast-day-start = === 😋 Day { $day }: Feast Day ===
+feast-announced =
+    { $backpacks ->
+        [tribute] === 📣 A feast at the Cornucopia at dawn on day { $day }, with a backpack for every tribute holding something they need ===
+        [district] === 📣 A feast at the Cornucopia at dawn on day { $day }, with a backpack for every district holding something it needs ===
+       *[none] === 📣 A feast at the Cornucopia at dawn on day { $day } ===
+    }
+tributes-left = === 📌 Tributes alive: { $count } ===
+game-night-start = === 🌙 Night { $day } begins ===
+daily-death-announcement = === 💀 Tributes dead: { $count } ===
+death-announcement = === 🪦 { $tribute } has died ===
+no-one-wins = === 🎭 No one wins! ===
+tribute-wins =
+    { $tribute-pronouns ->
+        [plural] === 🏆 The winners are { $tribute } ===
+       *[other] === 🏆 The winner is { $tribute } ===
+    }
+area-event = === ⚠️ A(n) { $event } has occurred { $location } ===
+area-close = === 🔔 { $subject } is uninhabitable ===
+area-open = === 🔔 { $subject } is habitable again ===
+arena-hazard = === ⚠️ A(n) { $event } sweeps the whole arena ===
+sudden-death = === ⏰ Day { $day }: Time's up! Every tribute is driven to the Cornucopia ===
+finale = === 🔥 The finale begins! The gamemakers are driving the survivors to the Cornucopia ===
+pack-sighted =
+    { $count ->
+        [one] 🐾 { $count } { $animal } turns up { $location }
+       *[other] 🐾 { $count } { $animal } turn up { $location }
+    }
+mutts-released = === 🧬 The gamemakers release { $count } { $animal } { $location } ===
+outbreak =
+    { $count ->
+        [one] === 🦠 Outbreak { $location }! { $count } tribute has caught something ({ $status }) ===
+       *[other] === 🦠 Outbreak { $location }! { $count } tributes have caught something ({ $status }) ===
+    }
+rule-change-announced = === 📢 Rule change: { $rule } ===
+rule-change-revoked = === 📢 Rule change revoked: { $rule } ===
+
+## Training and interviews
+
+training-scores-start = === 🏹 The gamemakers announce the training scores ===
+tribute-training-score = 🎯 { $tribute } receives a training score of { $score }
+interviews-start = === 🎤 The tributes are interviewed ===
+tribute-interview =
+    { $reception ->
+        [adored] 🌟 { $tribute } has the crowd eating out of { $tribute-possessive } hand (likability { $likability })
+        [liked] 🎤 { $tribute } charms the audience (likability { $likability })
+        [flat] 😐 { $tribute }'s interview falls flat (likability { $likability })
+       *[disliked] 🙄 { $tribute } puts the audience off (likability { $likability })
+    }
+
+## Resting, hiding and moving
+
+tribute-rest = 😪 { $tribute } rests
+tribute-long-rest = 💤 { $tribute } rests and recovers a little health and sanity
+tribute-hide = 🫥 { $tribute } tries to hide
+tribute-spotted = 👀 { $spotter } spots { $hider } hiding nearby
+tribute-unspotted = 🌿 { $hider } stays hidden from { $spotter }
+tribute-hiding-spot-lost = 🍂 { $tribute }'s hiding spot no longer conceals { $tribute-object }
+tribute-travel = 🚶 { $tribute } moves from { $from } to { $to }
+tribute-travel-too-tired = 😴 { $tribute } is too tired to move from { $area }, rests instead
+tribute-travel-already-there = 🤔 { $tribute } is already { $location }, stays put
+tribute-travel-follow = 🫡 { $tribute } follows { $tribute-possessive } district mate to { $area }
+tribute-travel-stay = 🪑 { $tribute } stays in { $area }
+tribute-travel-hunt = 🎯 { $tribute } hunts { $target } into { $area }
+tribute-travel-scavenge = 🧭 { $tribute } heads to { $area }, remembering the supplies there
+tribute-travel-flee = 🏃 { $tribute } flees toward { $area }
+trapped-in-area = 💥 { $tribute } is trapped { $location }.
+died-in-area = 💥 { $tribute } died { $location }.
+
+## Items
+
+tribute-take-item = 🔨 { $tribute } takes a(n) { $item }
+tribute-take-backpack =
+    { $label ->
+        [district] 🎒 { $tribute } grabs the backpack marked { $district } and finds { $item }
+       *[tribute] 🎒 { $tribute } grabs the backpack with { $tribute-possessive } name on it and finds { $item }
+    }
+tribute-cannot-use-item = ❌ { $tribute } cannot use a(n) { $item }
+tribute-use-item = 💊 { $tribute } uses a(n) { $item }, gains { $effect } { $attribute }
+weapon-break = 🗡️ { $tribute } breaks { $tribute-possessive } { $item }
+shield-break = 🛡️ { $tribute } breaks { $tribute-possessive } { $item }
+sponsor-gift = 🎁 { $tribute } receives a(n) { $item } ({ $quantity }x { $attribute } +{ $effect })
+tribute-crafts = 🛠️ { $tribute } crafts a(n) { $item } (+{ $effect } { $attribute })
+tribute-sets-trap = 🪤 { $tribute } sets a { $trap } { $location }
+tribute-caught-in-trap = 🪤 { $tribute } is caught in { $owner }'s { $trap }, takes { $damage } damage!
+tribute-dies-in-trap = 💀 { $tribute } dies in { $owner }'s { $trap }
+tribute-disarms-trap = 🔍 { $tribute } spots a { $trap } just in time and breaks it
+tribute-steals-trap = 🔍 { $tribute } spots a { $trap } and takes it for { $tribute-reflexive }
+tribute-gathers =
+    { $action ->
+        [forage] 🫐 { $tribute } forages and finds some { $item }
+        [hunt] 🏹 { $tribute } goes hunting and brings back some { $item }
+        [fish] 🎣 { $tribute } fishes and catches some { $item }
+       *[find-water] 💧 { $tribute } finds clean { $item }
+    }
+tribute-gathers-nothing =
+    { $action ->
+        [forage] 🫐 { $tribute } forages but finds nothing safe to eat
+        [hunt] 🏹 { $tribute } goes hunting but comes back empty-handed
+        [fish] 🎣 { $tribute } fishes but catches nothing
+       *[find-water] 💧 { $tribute } searches for water but finds none
+    }
+tribute-gathering-mishap =
+    { $action ->
+        [forage] ☠️ { $tribute } eats berries that turn out to be poisonous!
+        [hunt] 🩸 { $tribute } gets hurt on the hunt!
+        [fish] 🌊 { $tribute } is swept away by the current while fishing!
+       *[find-water] 🤢 { $tribute } drinks from a tainted stream and falls sick!
+    }
+
+## Statuses
+
+tribute-bleeds = 🩸 { $tribute } bleeds from { $tribute-possessive } wounds.
+tribute-sick = 🤒 { $tribute } contracts dysentery, loses strength and speed
+tribute-electrocuted = 🌩️ { $tribute } is struck by lightning, loses health
+tribute-frozen = 🥶 { $tribute } suffers from hypothermia, loses speed.
+tribute-overheated = 🥵 { $tribute } suffers from heat stroke, loses speed.
+tribute-dehydrated = 🌵 { $tribute } is severely dehydrated, loses strength
+tribute-starving = 🍴 { $tribute } is ravenously hungry, loses strength
+tribute-poisoned = 🧪 { $tribute } eats something poisonous, loses sanity
+tribute-broken-arm = 🦴 { $tribute } injures { $tribute-possessive } arm, loses strength.
+tribute-broken-leg = 🦴 { $tribute } injures { $tribute-possessive } leg, loses speed.
+tribute-infected = 🤢 { $tribute } gets an infection, loses health and sanity
+tribute-falls-ill = 🦠 { $tribute } caught something and is now { $status }
+tribute-drowned = 🏊 { $tribute } partially drowns, loses health and sanity
+tribute-mauled = 🐾 { $tribute } is attacked by { $count } { $animal }, takes { $damage } damage!
+tribute-hunted-by-mutts = 🐺 Mutts pick up { $tribute }'s scent { $location }!
+tribute-kills-animals = ⚔️ { $tribute } fights back and kills { $count } { $animal }!
+tribute-burned = 🔥 { $tribute } gets burned, loses health
+
+## Sanity
+
+tribute-horrified = 😱 { $tribute } is horrified by the violence, loses { $damage } sanity.
+tribute-suffer = 😭 { $tribute } suffers from loneliness and terror.
+tribute-witness-death = 😨 { $tribute } watches { $victim } die, loses { $damage } sanity.
+tribute-mourn-district-mate = 🕯️ { $tribute } mourns { $tribute-possessive } district mate { $victim }, loses { $damage } sanity.
+tribute-shaken = 😰 { $tribute } is shaken by { $definite }, loses { $damage } sanity.
+tribute-breakdown =
+    { $breakdown ->
+        [paranoia] 🫨 { $tribute } snaps and trusts no one anymore.
+        [hallucination] 🌀 { $tribute } starts seeing things that aren't there.
+       *[catatonia] 🧊 { $tribute } shuts down and stops responding.
+    }
+tribute-recovers = 🌅 { $tribute } pulls { $tribute-reflexive } back together.
+tribute-catatonic = 😶 { $tribute } stares blankly into the distance.
+tribute-comforted = 🫂 { $tribute } takes comfort in { $ally }'s company.
+tribute-self-harm = 🤦 { $tribute } tries to attack { $tribute-reflexive }!
+tribute-suicide = 🪒 { $tribute } attempts suicide.
+
+## Fighting
+
+tribute-attack-win = 🔪 { $tribute } attacks { $target }, and wins!
+tribute-attack-win-extra = 🔪 { $tribute } attacks { $target }, and wins decisively!
+tribute-attack-wound = 🤕 { $tribute } wounds { $target }
+tribute-attack-lose = 🤣 { $tribute } attacks { $target }, but loses!
+tribute-attack-lose-extra = 🤣 { $tribute } attacks { $target }, but loses decisively!
+tribute-attack-miss = 😰 { $tribute } attacks { $target }, but misses!
+tribute-attack-died = ☠️ { $tribute } is killed by { $target }
+tribute-attack-success-kill = ☠️ { $tribute } successfully kills { $target }
+tribute-attack-hidden =
+    { $target-pronouns ->
+        [they] 🤔 { $tribute } can't attack { $target }, they're hidden
+       *[other] 🤔 { $tribute } can't attack { $target }, { $target-subject } is hidden
+    }
+tribute-revenge = 😤 { $tribute } spots { $target } and seeks revenge!
+
+## Deaths
+
+tribute-dies-from-status = 💀 { $tribute } dies from { $status }
+tribute-dies-from-area-event = 🪦 { $tribute } died in { $definite }.
+tribute-dies-from-tribute-event = 💀 { $tribute } dies by { $event }
+tribute-already-dead = ‼️ { $tribute } is already dead!
+tribute-dead = ❗️ { $tribute } is dead!
+
+## Areas
+
+area-cornucopia = The Cornucopia
+    .location = in the Cornucopia
+    .subject = The Cornucopia
+area-northeast = Northeast
+    .location = in the Northeast
+    .subject = The Northeast
+area-northwest = Northwest
+    .location = in the Northwest
+    .subject = The Northwest
+area-southeast = Southeast
+    .location = in the Southeast
+    .subject = The Southeast
+area-southwest = Southwest
+    .location = in the Southwest
+    .subject = The Southwest
+
+## Area events
+
+event-wildfire = wildfire
+    .indefinite = a wildfire
+    .definite = the wildfire
+event-flood = flood
+    .indefinite = a flood
+    .definite = the flood
+event-earthquake = earthquake
+    .indefinite = an earthquake
+    .definite = the earthquake
+event-avalanche = avalanche
+    .indefinite = an avalanche
+    .definite = the avalanche
+event-blizzard = blizzard
+    .indefinite = a blizzard
+    .definite = the blizzard
+event-landslide = landslide
+    .indefinite = a landslide
+    .definite = the landslide
+event-heatwave = heatwave
+    .indefinite = a heatwave
+    .definite = the heatwave
+
+## Tribute events
+
+event-animal-attack = animal attack { $animal }
+event-dysentery = dysentery
+event-lightning-strike = lightning strike
+event-hypothermia = hypothermia
+event-heat-stroke = heat stroke
+event-dehydration = dehydration
+event-starvation = starvation
+event-poisoning = poisoning
+event-broken-bone = broken bone
+event-infection = infection
+event-drowning = drowning
+event-burn = burn
+
+## Statuses
+
+status-healthy = healthy
+status-wounded = wounded
+status-starving = starving
+status-dehydrated = dehydrated
+status-sick = sick
+status-poisoned = poisoned
+status-recently-dead = recently dead
+status-dead = dead
+status-electrocuted = electrocuted
+status-frozen = frozen
+status-overheated = overheated
+status-broken = broken
+status-infected = infected
+status-drowned = drowned
+status-burned = burned
+status-buried = buried
+status-mauled = mauled { $animal }
+
+## Item attributes
+
+attribute-health = Health
+attribute-sanity = Sanity
+attribute-movement = Movement
+attribute-bravery = Bravery
+attribute-speed = Speed
+attribute-strength = Strength
+attribute-defense = Defense
+attribute-stealth = Stealth
+
+## Animals, by how many there are
+
+animal-squirrel =
+    { $count ->
+        [one] squirrel
+       *[other] squirrels
+    }
+animal-bear =
+    { $count ->
+        [one] bear
+       *[other] bears
+    }
+animal-wolf =
+    { $count ->
+        [one] wolf
+       *[other] wolves
+    }
+animal-cougar =
+    { $count ->
+        [one] cougar
+       *[other] cougars
+    }
+animal-boar =
+    { $count ->
+        [one] boar
+       *[other] boars
+    }
+animal-snake =
+    { $count ->
+        [one] snake
+       *[other] snakes
+    }
+animal-monkey =
+    { $count ->
+        [one] monkey
+       *[other] monkeys
+    }
+animal-baboon =
+    { $count ->
+        [one] baboon
+       *[other] baboons
+    }
+animal-hyena =
+    { $count ->
+        [one] hyena
+       *[other] hyenas
+    }
+animal-lion =
+    { $count ->
+        [one] lion
+       *[other] lions
+    }
+animal-tiger =
+    { $count ->
+        [one] tiger
+       *[other] tigers
+    }
+animal-elephant =
+    { $count ->
+        [one] elephant
+       *[other] elephants
+    }
+animal-rhino =
+    { $count ->
+        [one] rhino
+       *[other] rhinos
+    }
+animal-hippo =
+    { $count ->
+        [one] hippo
+       *[other] hippos
+    }
+animal-tracker-jacker =
+    { $count ->
+        [one] tracker jacker
+       *[other] tracker jackers
+    }
+animal-mutt =
+    { $count ->
+        [one] mutt
+       *[other] mutts
+    }
+
+## Rulesets
+
+ruleset-standard = Standard
+ruleset-victors-only = Victors only
+ruleset-double-tributes = Double tributes
+ruleset-co-victors = Co-victors from the same district
+ruleset-no-sponsors = No sponsors
+ruleset-night-hazards = Arena-wide night hazards
+
+## Win conditions and rule changes
+
+win-condition-last-standing = Last tribute standing
+win-condition-last-district = Last district standing
+win-condition-last-alliance = Last alliance standing
+win-condition-time-limit = Last tribute standing, sudden death after { $days } days
+rule-change-district-co-victors = two tributes from the same district can now win together
+rule-change-district-co-victors-revoked = only one tribute can win after all
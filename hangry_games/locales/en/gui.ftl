@@ -0,0 +1,84 @@
+# Labels for the desktop app.
+
+gui-home = Home
+gui-back-to-game = Back to game
+gui-back-to-log = Back to log
+gui-game = Game
+gui-game-name = Game Name
+gui-roster-size = Tributes
+gui-districts = Districts
+gui-ruleset = Rules
+gui-create-game = Create Game
+gui-language = Language
+gui-fill-game = Fill game
+gui-play-next-day = Play next day
+gui-day = Day
+gui-day-number = Day { $day }
+gui-days = Days
+gui-day-log = Day Log
+gui-full-log = Full Log
+gui-log-by = { $message } by { $tribute }
+gui-wins = { $name } wins!
+gui-no-one-wins = No one wins!
+gui-no-winner = No winner
+gui-or = or
+gui-yes = Yes
+gui-no = No
+gui-fill-with-tributes = Fill with tributes?
+gui-confirm-fill = Are you sure you want to fill { $name } with tributes?
+gui-delete-game = Delete game?
+gui-delete-tribute = Delete tribute?
+gui-confirm-delete = Are you sure you want to delete { $name }?
+gui-living-tributes = Living Tributes
+gui-winner = Winner
+gui-actions = Actions
+
+## Tributes
+
+gui-name = Name
+gui-real-name = Real name
+gui-pronouns = Pronouns
+gui-district = District
+gui-district-number = District { $district }
+gui-status = Status
+gui-add-tribute = Add Tribute
+gui-update-tribute = Update Tribute
+gui-preset = Build
+gui-custom-build = Custom
+gui-preset-balanced = Balanced
+gui-preset-brawler = Brawler
+gui-preset-sneak = Sneak
+gui-preset-survivor = Survivor
+gui-points-spent = { $spent } / { $budget } points
+gui-import-roster = Import roster (CSV or JSON)
+gui-roster-format = Rosters must be .csv or .json files
+gui-upload-image = Upload Image
+gui-attributes = Attributes
+gui-statistics = Statistics
+gui-death = Death
+gui-day-killed = Day Killed
+gui-killed-by = Killed By
+gui-health = Health
+gui-sanity = Sanity
+gui-movement = Movement
+gui-strength = Strength
+gui-defense = Defense
+gui-bravery = Bravery
+gui-loyalty = Loyalty
+gui-speed = Speed
+gui-intelligence = Intelligence
+gui-persuasion = Persuasion
+gui-luck = Luck
+gui-dexterity = Dexterity
+gui-kills = Kills
+gui-wins-count = Wins
+gui-defeats = Defeats
+gui-draws = Draws
+gui-games = Games
+gui-training-score = Training score
+gui-likability = Likability
+gui-standings = Betting standings
+gui-balance = Balance
+gui-open-bets = Open bets
+gui-bets-won = Bets won
+gui-no-spectators = No one has placed a bet yet.
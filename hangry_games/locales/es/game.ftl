@@ -0,0 +1,396 @@
+# Narración del registro de la partida.
+# Ver locales/en/game.ftl para los argumentos de cada mensaje.
+
+## Anuncios
+
+game-day-start = === ☀️ ¡Comienza el día { $day }! ===
+first-day-start = === 🎉 ¡Comienzan los Juegos del Hambre! 🎉 ===
+feast-day-start = === 😋 Día { $day }: Día del Banquete ===
+feast-announced =
+    { $backpacks ->
+        [tribute] === 📣 Banquete en la Cornucopia al amanecer del día { $day }, con una mochila para cada tributo con algo que necesita ===
+        [district] === 📣 Banquete en la Cornucopia al amanecer del día { $day }, con una mochila para cada distrito con algo que necesita ===
+       *[none] === 📣 Banquete en la Cornucopia al amanecer del día { $day } ===
+    }
+tributes-left = === 📌 Tributos con vida: { $count } ===
+game-night-start = === 🌙 Comienza la noche { $day } ===
+daily-death-announcement = === 💀 Tributos muertos: { $count } ===
+death-announcement = === 🪦 { $tribute } ha muerto ===
+no-one-wins = === 🎭 ¡Nadie gana! ===
+tribute-wins =
+    { $tribute-pronouns ->
+        [plural] === 🏆 Los ganadores son { $tribute } ===
+        [she] === 🏆 La ganadora es { $tribute } ===
+        [he] === 🏆 El ganador es { $tribute } ===
+       *[other] === 🏆 { $tribute } gana los Juegos ===
+    }
+area-event = === ⚠️ Se ha producido { $indefinite } { $location } ===
+area-close = === 🔔 { $subject } es inhabitable ===
+area-open = === 🔔 { $subject } vuelve a ser habitable ===
+arena-hazard = === ⚠️ ¡{ $indefinite } arrasa toda la arena! ===
+sudden-death = === ⏰ Día { $day }: ¡Se acabó el tiempo! Todos los tributos son conducidos a la Cornucopia ===
+finale = === 🔥 ¡Comienza la final! Los Vigilantes empujan a los supervivientes hacia la Cornucopia ===
+pack-sighted =
+    { $count ->
+        [one] 🐾 { $count } { $animal } merodea { $location }
+       *[other] 🐾 { $count } { $animal } merodean { $location }
+    }
+mutts-released = === 🧬 Los Vigilantes sueltan { $count } { $animal } { $location } ===
+outbreak =
+    { $count ->
+        [one] === 🦠 ¡Brote { $location }! { $count } tributo se ha contagiado ({ $status }) ===
+       *[other] === 🦠 ¡Brote { $location }! { $count } tributos se han contagiado ({ $status }) ===
+    }
+rule-change-announced = === 📢 Cambio de reglas: { $rule } ===
+rule-change-revoked = === 📢 Cambio de reglas revocado: { $rule } ===
+
+## Entrenamiento y entrevistas
+
+training-scores-start = === 🏹 Los Vigilantes anuncian las puntuaciones del entrenamiento ===
+tribute-training-score = 🎯 { $tribute } recibe una puntuación de { $score } en el entrenamiento
+interviews-start = === 🎤 Entrevistas a los tributos ===
+tribute-interview =
+    { $reception ->
+        [adored] 🌟 { $tribute } se mete al público en el bolsillo (simpatía { $likability })
+        [liked] 🎤 { $tribute } encandila a la audiencia (simpatía { $likability })
+        [flat] 😐 La entrevista de { $tribute } pasa sin pena ni gloria (simpatía { $likability })
+       *[disliked] 🙄 { $tribute } se gana la antipatía del público (simpatía { $likability })
+    }
+
+## Descansar, esconderse y moverse
+
+tribute-rest = 😪 { $tribute } descansa
+tribute-long-rest = 💤 { $tribute } descansa y recupera algo de salud y cordura
+tribute-hide = 🫥 { $tribute } intenta esconderse
+tribute-spotted = 👀 { $spotter } descubre el escondite de { $hider }
+tribute-unspotted = 🌿 { $spotter } no logra encontrar a { $hider }
+tribute-hiding-spot-lost = 🍂 El escondite de { $tribute } ya no le oculta
+tribute-travel = 🚶 { $tribute } se mueve de { $from } a { $to }
+tribute-travel-too-tired =
+    { $tribute-pronouns ->
+        [she] 😴 { $tribute } está demasiado cansada para moverse y descansa { $location }
+        [he] 😴 { $tribute } está demasiado cansado para moverse y descansa { $location }
+       *[other] 😴 { $tribute } no tiene fuerzas para moverse y descansa { $location }
+    }
+tribute-travel-already-there = 🤔 { $tribute } ya está { $location } y se queda
+tribute-travel-follow = 🫡 { $tribute } sigue el rastro de su distrito hasta { $area }
+tribute-travel-stay = 🪑 { $tribute } se queda { $location }
+tribute-travel-hunt = 🎯 { $tribute } persigue a { $target } hasta { $area }
+tribute-travel-scavenge = 🧭 { $tribute } se dirige a { $area }, recordando los suministros que vio allí
+tribute-travel-flee = 🏃 { $tribute } huye hacia { $area }
+trapped-in-area =
+    { $tribute-pronouns ->
+        [she] 💥 { $tribute } queda atrapada { $location }.
+        [he] 💥 { $tribute } queda atrapado { $location }.
+       *[other] 💥 { $tribute } no puede salir { $location }.
+    }
+died-in-area = 💥 { $tribute } muere { $location }.
+
+## Objetos
+
+tribute-take-item = 🔨 { $tribute } toma: { $item }
+tribute-take-backpack =
+    { $label ->
+        [district] 🎒 { $tribute } agarra la mochila marcada con el { $district } y encuentra: { $item }
+       *[tribute] 🎒 { $tribute } agarra la mochila con su nombre y encuentra: { $item }
+    }
+tribute-cannot-use-item = ❌ { $tribute } no puede usar: { $item }
+tribute-use-item = 💊 { $tribute } usa { $item } y gana { $effect } de { $attribute }
+weapon-break = 🗡️ { $tribute } rompe su { $item }
+shield-break = 🛡️ { $tribute } rompe su { $item }
+sponsor-gift = 🎁 { $tribute } recibe { $item } ({ $quantity }x { $attribute } +{ $effect })
+tribute-crafts = 🛠️ { $tribute } fabrica { $item } (+{ $effect } { $attribute })
+tribute-sets-trap = 🪤 { $tribute } coloca una trampa ({ $trap }) { $location }
+tribute-caught-in-trap = 🪤 ¡{ $tribute } cae en la trampa de { $owner } ({ $trap }) y recibe { $damage } de daño!
+tribute-dies-in-trap = 💀 { $tribute } muere en la trampa de { $owner } ({ $trap })
+tribute-disarms-trap = 🔍 { $tribute } descubre una trampa ({ $trap }) justo a tiempo y la desarma
+tribute-steals-trap = 🔍 { $tribute } descubre una trampa ({ $trap }) y se la queda
+tribute-gathers =
+    { $action ->
+        [forage] 🫐 { $tribute } recolecta y encuentra { $item }
+        [hunt] 🏹 { $tribute } sale a cazar y vuelve con { $item }
+        [fish] 🎣 { $tribute } pesca y consigue { $item }
+       *[find-water] 💧 { $tribute } encuentra agua limpia
+    }
+tribute-gathers-nothing =
+    { $action ->
+        [forage] 🫐 { $tribute } busca comida pero no encuentra nada comestible
+        [hunt] 🏹 { $tribute } sale a cazar pero vuelve con las manos vacías
+        [fish] 🎣 { $tribute } pesca pero no consigue nada
+       *[find-water] 💧 { $tribute } busca agua pero no la encuentra
+    }
+tribute-gathering-mishap =
+    { $action ->
+        [forage] ☠️ ¡{ $tribute } come bayas que resultan ser venenosas!
+        [hunt] 🩸 ¡{ $tribute } se hiere durante la caza!
+        [fish] 🌊 ¡La corriente arrastra a { $tribute } mientras pesca!
+       *[find-water] 🤢 ¡{ $tribute } bebe de un arroyo contaminado y enferma!
+    }
+
+## Estados
+
+tribute-bleeds = 🩸 { $tribute } sangra por sus heridas.
+tribute-sick = 🤒 { $tribute } contrae disentería, pierde fuerza y velocidad
+tribute-electrocuted = 🌩️ A { $tribute } le cae un rayo, pierde salud
+tribute-frozen = 🥶 { $tribute } sufre hipotermia, pierde velocidad.
+tribute-overheated = 🥵 { $tribute } sufre un golpe de calor, pierde velocidad.
+tribute-dehydrated = 🌵 { $tribute } sufre una deshidratación grave, pierde fuerza
+tribute-starving = 🍴 { $tribute } pasa un hambre atroz, pierde fuerza
+tribute-poisoned = 🧪 { $tribute } come algo venenoso, pierde cordura
+tribute-broken-arm = 🦴 { $tribute } se lesiona el brazo, pierde fuerza.
+tribute-broken-leg = 🦴 { $tribute } se lesiona la pierna, pierde velocidad.
+tribute-infected = 🤢 { $tribute } sufre una infección, pierde salud y cordura
+tribute-falls-ill = 🦠 { $tribute } se ha contagiado ({ $status })
+tribute-drowned = 🏊 { $tribute } casi se ahoga, pierde salud y cordura
+tribute-mauled =
+    { $count ->
+        [one] 🐾 A { $tribute } le ataca { $count } { $animal }, ¡recibe { $damage } de daño!
+       *[other] 🐾 A { $tribute } le atacan { $count } { $animal }, ¡recibe { $damage } de daño!
+    }
+tribute-hunted-by-mutts = 🐺 ¡Los mutos siguen el rastro de { $tribute } { $location }!
+tribute-kills-animals = ⚔️ ¡{ $tribute } se defiende y mata { $count } { $animal }!
+tribute-burned = 🔥 { $tribute } se quema, pierde salud
+
+## Cordura
+
+tribute-horrified = 😱 { $tribute } siente horror ante la violencia, pierde { $damage } de cordura.
+tribute-suffer = 😭 { $tribute } sufre de soledad y terror.
+tribute-witness-death = 😨 { $tribute } ve morir a { $victim }, pierde { $damage } de cordura.
+tribute-mourn-district-mate =
+    { $victim-pronouns ->
+        [she] 🕯️ { $tribute } llora a { $victim }, su compañera de distrito, pierde { $damage } de cordura.
+        [he] 🕯️ { $tribute } llora a { $victim }, su compañero de distrito, pierde { $damage } de cordura.
+       *[other] 🕯️ { $tribute } llora a { $victim }, de su mismo distrito, pierde { $damage } de cordura.
+    }
+tribute-shaken = 😰 { $tribute } sufre una conmoción por { $definite }, pierde { $damage } de cordura.
+tribute-breakdown =
+    { $breakdown ->
+        [paranoia] 🫨 { $tribute } pierde los nervios y ya no confía en nadie.
+        [hallucination] 🌀 { $tribute } empieza a ver cosas que no existen.
+       *[catatonia] 🧊 { $tribute } se bloquea y deja de responder.
+    }
+tribute-recovers = 🌅 { $tribute } logra recomponerse.
+tribute-catatonic = 😶 { $tribute } mira fijamente a la nada.
+tribute-comforted = 🫂 { $tribute } encuentra consuelo en la compañía de { $ally }.
+tribute-self-harm = 🤦 ¡{ $tribute } intenta hacerse daño!
+tribute-suicide = 🪒 { $tribute } intenta suicidarse.
+
+## Combate
+
+tribute-attack-win = 🔪 { $tribute } ataca a { $target } ¡y gana!
+tribute-attack-win-extra = 🔪 { $tribute } ataca a { $target } ¡y gana con claridad!
+tribute-attack-wound = 🤕 { $tribute } hiere a { $target }
+tribute-attack-lose = 🤣 { $tribute } ataca a { $target }, ¡pero pierde!
+tribute-attack-lose-extra = 🤣 { $tribute } ataca a { $target }, ¡pero pierde con claridad!
+tribute-attack-miss = 😰 { $tribute } ataca a { $target }, ¡pero falla!
+tribute-attack-died = ☠️ { $tribute } muere a manos de { $target }
+tribute-attack-success-kill = ☠️ { $tribute } mata a { $target }
+tribute-attack-hidden = 🤔 { $tribute } no puede atacar a { $target }, sigue a cubierto
+tribute-revenge = 😤 ¡{ $tribute } ve a { $target } y busca venganza!
+
+## Muertes
+
+tribute-dies-from-status = 💀 { $tribute } muere ({ $status })
+tribute-dies-from-area-event = 🪦 { $tribute } murió en { $definite }.
+tribute-dies-from-tribute-event = 💀 { $tribute } muere por { $event }
+tribute-already-dead =
+    { $tribute-pronouns ->
+        [she] ‼️ ¡{ $tribute } ya está muerta!
+        [he] ‼️ ¡{ $tribute } ya está muerto!
+       *[other] ‼️ ¡{ $tribute } ya ha muerto!
+    }
+tribute-dead =
+    { $tribute-pronouns ->
+        [she] ❗️ ¡{ $tribute } está muerta!
+        [he] ❗️ ¡{ $tribute } está muerto!
+       *[other] ❗️ ¡{ $tribute } ha muerto!
+    }
+
+## Zonas
+
+area-cornucopia = la Cornucopia
+    .location = en la Cornucopia
+    .subject = La Cornucopia
+area-northeast = Noreste
+    .location = en el Noreste
+    .subject = El Noreste
+area-northwest = Noroeste
+    .location = en el Noroeste
+    .subject = El Noroeste
+area-southeast = Sureste
+    .location = en el Sureste
+    .subject = El Sureste
+area-southwest = Suroeste
+    .location = en el Suroeste
+    .subject = El Suroeste
+
+## Eventos de zona
+
+event-wildfire = incendio forestal
+    .indefinite = un incendio forestal
+    .definite = el incendio forestal
+event-flood = inundación
+    .indefinite = una inundación
+    .definite = la inundación
+event-earthquake = terremoto
+    .indefinite = un terremoto
+    .definite = el terremoto
+event-avalanche = avalancha
+    .indefinite = una avalancha
+    .definite = la avalancha
+event-blizzard = ventisca
+    .indefinite = una ventisca
+    .definite = la ventisca
+event-landslide = deslizamiento de tierra
+    .indefinite = un deslizamiento de tierra
+    .definite = el deslizamiento de tierra
+event-heatwave = ola de calor
+    .indefinite = una ola de calor
+    .definite = la ola de calor
+
+## Eventos de tributo
+
+event-animal-attack = ataque de { $animal }
+event-dysentery = disentería
+event-lightning-strike = la caída de un rayo
+event-hypothermia = hipotermia
+event-heat-stroke = golpe de calor
+event-dehydration = deshidratación
+event-starvation = inanición
+event-poisoning = envenenamiento
+event-broken-bone = un hueso roto
+event-infection = infección
+event-drowning = ahogamiento
+event-burn = quemaduras
+
+## Estados
+
+status-healthy = sano
+status-wounded = herido
+status-starving = hambriento
+status-dehydrated = deshidratado
+status-sick = enfermo
+status-poisoned = envenenado
+status-recently-dead = recién muerto
+status-dead = muerto
+status-electrocuted = electrocutado
+status-frozen = congelado
+status-overheated = sobrecalentado
+status-broken = fracturado
+status-infected = infectado
+status-drowned = ahogado
+status-burned = quemado
+status-buried = enterrado
+status-mauled = atacado por { $animal }
+
+## Atributos de objetos
+
+attribute-health = Salud
+attribute-sanity = Cordura
+attribute-movement = Movimiento
+attribute-bravery = Valentía
+attribute-speed = Velocidad
+attribute-strength = Fuerza
+attribute-defense = Defensa
+attribute-stealth = Sigilo
+
+## Animales, según cuántos haya
+
+animal-squirrel =
+    { $count ->
+        [one] ardilla
+       *[other] ardillas
+    }
+animal-bear =
+    { $count ->
+        [one] oso
+       *[other] osos
+    }
+animal-wolf =
+    { $count ->
+        [one] lobo
+       *[other] lobos
+    }
+animal-cougar =
+    { $count ->
+        [one] puma
+       *[other] pumas
+    }
+animal-boar =
+    { $count ->
+        [one] jabalí
+       *[other] jabalíes
+    }
+animal-snake =
+    { $count ->
+        [one] serpiente
+       *[other] serpientes
+    }
+animal-monkey =
+    { $count ->
+        [one] mono
+       *[other] monos
+    }
+animal-baboon =
+    { $count ->
+        [one] babuino
+       *[other] babuinos
+    }
+animal-hyena =
+    { $count ->
+        [one] hiena
+       *[other] hienas
+    }
+animal-lion =
+    { $count ->
+        [one] león
+       *[other] leones
+    }
+animal-tiger =
+    { $count ->
+        [one] tigre
+       *[other] tigres
+    }
+animal-elephant =
+    { $count ->
+        [one] elefante
+       *[other] elefantes
+    }
+animal-rhino =
+    { $count ->
+        [one] rinoceronte
+       *[other] rinocerontes
+    }
+animal-hippo =
+    { $count ->
+        [one] hipopótamo
+       *[other] hipopótamos
+    }
+animal-tracker-jacker =
+    { $count ->
+        [one] rastrevíspula
+       *[other] rastrevíspulas
+    }
+animal-mutt =
+    { $count ->
+        [one] muto
+       *[other] mutos
+    }
+
+## Rulesets
+
+ruleset-standard = Estándar
+ruleset-victors-only = Solo vencedores
+ruleset-double-tributes = Tributos dobles
+ruleset-co-victors = Covencedores del mismo distrito
+ruleset-no-sponsors = Sin patrocinadores
+ruleset-night-hazards = Peligros nocturnos en toda la arena
+
+## Condiciones de victoria y cambios de reglas
+
+win-condition-last-standing = El último tributo en pie
+win-condition-last-district = El último distrito en pie
+win-condition-last-alliance = La última alianza en pie
+win-condition-time-limit = El último tributo en pie, muerte súbita tras { $days } días
+rule-change-district-co-victors = dos tributos del mismo distrito ya pueden ganar juntos
+rule-change-district-co-victors-revoked = al final solo puede ganar un tributo
@@ -0,0 +1,84 @@
+# Etiquetas de la aplicación de escritorio.
+
+gui-home = Inicio
+gui-back-to-game = Volver a la partida
+gui-back-to-log = Volver al registro
+gui-game = Partida
+gui-game-name = Nombre de la partida
+gui-roster-size = Tributos
+gui-districts = Distritos
+gui-ruleset = Reglas
+gui-create-game = Crear partida
+gui-language = Idioma
+gui-fill-game = Completar partida
+gui-play-next-day = Jugar el siguiente día
+gui-day = Día
+gui-day-number = Día { $day }
+gui-days = Días
+gui-day-log = Registro diario
+gui-full-log = Registro completo
+gui-log-by = { $message } por { $tribute }
+gui-wins = ¡{ $name } gana!
+gui-no-one-wins = ¡Nadie gana!
+gui-no-winner = Sin ganador
+gui-or = o
+gui-yes = Sí
+gui-no = No
+gui-fill-with-tributes = ¿Completar con tributos?
+gui-confirm-fill = ¿Seguro que quieres completar { $name } con tributos?
+gui-delete-game = ¿Borrar la partida?
+gui-delete-tribute = ¿Borrar el tributo?
+gui-confirm-delete = ¿Seguro que quieres borrar { $name }?
+gui-living-tributes = Tributos con vida
+gui-winner = Ganador
+gui-actions = Acciones
+
+## Tributos
+
+gui-name = Nombre
+gui-real-name = Nombre real
+gui-pronouns = Pronombres
+gui-district = Distrito
+gui-district-number = Distrito { $district }
+gui-status = Estado
+gui-add-tribute = Añadir tributo
+gui-update-tribute = Actualizar tributo
+gui-preset = Perfil
+gui-custom-build = Personalizado
+gui-preset-balanced = Equilibrado
+gui-preset-brawler = Luchador
+gui-preset-sneak = Sigiloso
+gui-preset-survivor = Superviviente
+gui-points-spent = { $spent } / { $budget } puntos
+gui-import-roster = Importar lista (CSV o JSON)
+gui-roster-format = Las listas deben ser archivos .csv o .json
+gui-upload-image = Subir imagen
+gui-attributes = Atributos
+gui-statistics = Estadísticas
+gui-death = Muerte
+gui-day-killed = Día de la muerte
+gui-killed-by = Asesinado por
+gui-health = Salud
+gui-sanity = Cordura
+gui-movement = Movimiento
+gui-strength = Fuerza
+gui-defense = Defensa
+gui-bravery = Valentía
+gui-loyalty = Lealtad
+gui-speed = Velocidad
+gui-intelligence = Inteligencia
+gui-persuasion = Persuasión
+gui-luck = Suerte
+gui-dexterity = Destreza
+gui-kills = Muertes causadas
+gui-wins-count = Victorias
+gui-defeats = Derrotas
+gui-draws = Empates
+gui-games = Partidas
+gui-training-score = Puntuación de entrenamiento
+gui-likability = Simpatía
+gui-standings = Clasificación de apuestas
+gui-balance = Saldo
+gui-open-bets = Apuestas abiertas
+gui-bets-won = Apuestas ganadas
+gui-no-spectators = Nadie ha apostado todavía.
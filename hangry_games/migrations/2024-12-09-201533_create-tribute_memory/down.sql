@@ -0,0 +1,2 @@
+-- Drop the tribute_memory table.
+DROP TABLE tribute_memory;
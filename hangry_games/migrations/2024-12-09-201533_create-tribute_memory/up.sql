@@ -0,0 +1,19 @@
+-- Create the tribute_memory table.
+-- Things a tribute has seen or suffered during a game: other tributes and where they were,
+-- who attacked them or killed their district mate, and where unclaimed items were lying.
+CREATE TABLE tribute_memory (
+    id SERIAL PRIMARY KEY,
+    tribute_id INT NOT NULL,
+    game_id INT NOT NULL,
+    day INT NOT NULL,
+    -- Kind of memory, e.g. "saw tribute" or "attacked by"
+    kind VARCHAR(255) NOT NULL,
+    -- Tribute or item the memory is about, depending on the kind
+    subject_id INT NOT NULL,
+    -- Where it happened, if anywhere
+    area_id INT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (tribute_id) REFERENCES tribute(id) ON DELETE CASCADE,
+    FOREIGN KEY (game_id) REFERENCES game(id) ON DELETE CASCADE,
+    FOREIGN KEY (area_id) REFERENCES area(id) ON DELETE CASCADE
+);
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE tribute DROP COLUMN destination_id;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- Remember where a tribute is headed so journeys can span several phases
+ALTER TABLE tribute ADD COLUMN destination_id INT;
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE spot_check;
+ALTER TABLE tribute DROP COLUMN stealth;
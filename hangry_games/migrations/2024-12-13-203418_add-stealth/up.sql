@@ -0,0 +1,16 @@
+-- How well a hidden tribute is concealed. Set when they hide and worn down every phase.
+ALTER TABLE tribute ADD COLUMN stealth INT DEFAULT 0;
+
+-- Results of one tribute trying to spot another hidden tribute.
+-- Only the current phase's results are kept, so a hider is either seen or not for the whole phase.
+CREATE TABLE spot_check (
+    id SERIAL PRIMARY KEY,
+    game_id INT NOT NULL,
+    spotter_id INT NOT NULL,
+    hider_id INT NOT NULL,
+    spotted BOOLEAN NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (game_id) REFERENCES game(id) ON DELETE CASCADE,
+    FOREIGN KEY (spotter_id) REFERENCES tribute(id) ON DELETE CASCADE,
+    FOREIGN KEY (hider_id) REFERENCES tribute(id) ON DELETE CASCADE
+);
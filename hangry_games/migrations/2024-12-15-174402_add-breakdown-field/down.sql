@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE tribute DROP COLUMN breakdown;
\ No newline at end of file
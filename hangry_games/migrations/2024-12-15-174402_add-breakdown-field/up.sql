@@ -0,0 +1,2 @@
+-- Mental breakdown a tribute is suffering from, if any
+ALTER TABLE tribute ADD COLUMN breakdown VARCHAR(255);
\ No newline at end of file
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX log_entry_game_id_kind_idx;
+ALTER TABLE log_entry DROP COLUMN payload;
+ALTER TABLE log_entry DROP COLUMN kind;
@@ -0,0 +1,5 @@
+-- Store what kind of event each log entry is, and the event itself as JSON,
+-- so logs can be filtered and replayed without parsing the rendered message.
+ALTER TABLE log_entry ADD COLUMN kind VARCHAR(255);
+ALTER TABLE log_entry ADD COLUMN payload JSONB;
+CREATE INDEX log_entry_game_id_kind_idx ON log_entry (game_id, kind);
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE game DROP COLUMN locale;
\ No newline at end of file
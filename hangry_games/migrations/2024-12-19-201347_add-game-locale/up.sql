@@ -0,0 +1,2 @@
+-- Language the game's log is narrated in
+ALTER TABLE game ADD COLUMN locale VARCHAR(8) NOT NULL DEFAULT 'en';
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- Pronoun set the log uses for a tribute, e.g. she/her or xe/xem/xyr/xemself
+ALTER TABLE tribute ADD COLUMN pronouns VARCHAR(255);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- Points players can spread across a hand-built tribute's stats
+ALTER TABLE game ADD COLUMN stat_budget INTEGER NOT NULL DEFAULT 450;
\ No newline at end of file
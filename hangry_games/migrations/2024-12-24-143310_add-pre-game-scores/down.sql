@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE tribute DROP COLUMN training_score;
+ALTER TABLE tribute DROP COLUMN likability;
@@ -0,0 +1,3 @@
+-- Pre-game results: the gamemakers' 1-12 training score and how well the interview went over
+ALTER TABLE tribute ADD COLUMN training_score INT;
+ALTER TABLE tribute ADD COLUMN likability INT;
@@ -0,0 +1,29 @@
+-- Create the spectator and bet tables.
+-- Spectators are the people watching and betting. Their balance carries over from game to game.
+CREATE TABLE spectator (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL UNIQUE,
+    balance INT NOT NULL DEFAULT 1000,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- A wager on one tribute in one game. The stake leaves the spectator's balance when the bet is placed.
+CREATE TABLE bet (
+    id SERIAL PRIMARY KEY,
+    game_id INT NOT NULL,
+    spectator_id INT NOT NULL,
+    tribute_id INT NOT NULL,
+    -- What's being bet on: "winner", "first death" or "kills"
+    kind VARCHAR(255) NOT NULL,
+    -- For "kills" bets, how many kills the tribute needs
+    kills INT,
+    stake INT NOT NULL,
+    odds DOUBLE PRECISION NOT NULL,
+    day INT NOT NULL,
+    -- What was paid back when the game ended, zero for a lost bet. Empty until then.
+    payout INT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (game_id) REFERENCES game(id) ON DELETE CASCADE,
+    FOREIGN KEY (spectator_id) REFERENCES spectator(id) ON DELETE CASCADE,
+    FOREIGN KEY (tribute_id) REFERENCES tribute(id) ON DELETE CASCADE
+);
@@ -0,0 +1,2 @@
+-- Balance settings a game plays with instead of the global ones
+ALTER TABLE game ADD COLUMN tuning JSONB;
\ No newline at end of file
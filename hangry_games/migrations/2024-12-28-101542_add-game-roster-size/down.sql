@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE game DROP COLUMN districts;
+ALTER TABLE game DROP COLUMN roster_size;
\ No newline at end of file
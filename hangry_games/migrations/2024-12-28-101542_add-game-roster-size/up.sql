@@ -0,0 +1,3 @@
+-- How many tributes a game takes and how many districts they are reaped from
+ALTER TABLE game ADD COLUMN roster_size INTEGER NOT NULL DEFAULT 24;
+ALTER TABLE game ADD COLUMN districts INTEGER NOT NULL DEFAULT 12;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- The house rules a game is played under
+ALTER TABLE game ADD COLUMN ruleset VARCHAR(32) NOT NULL DEFAULT 'standard';
\ No newline at end of file
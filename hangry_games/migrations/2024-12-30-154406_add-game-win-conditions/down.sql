@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE game DROP COLUMN rule_changes;
+ALTER TABLE game DROP COLUMN alliances;
+ALTER TABLE game DROP COLUMN win_condition;
\ No newline at end of file
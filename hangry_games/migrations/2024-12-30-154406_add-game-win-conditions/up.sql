@@ -0,0 +1,4 @@
+-- How a game is won, which districts stand together, and any rules changed mid-game
+ALTER TABLE game ADD COLUMN win_condition VARCHAR(32) NOT NULL DEFAULT 'last standing';
+ALTER TABLE game ADD COLUMN alliances JSONB;
+ALTER TABLE game ADD COLUMN rule_changes JSONB;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- The day the gamemakers began driving the survivors to the Cornucopia
+ALTER TABLE game ADD COLUMN finale_day INTEGER;
\ No newline at end of file
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE item DROP COLUMN for_district;
+ALTER TABLE item DROP COLUMN for_tribute_id;
+ALTER TABLE game DROP COLUMN feasts;
\ No newline at end of file
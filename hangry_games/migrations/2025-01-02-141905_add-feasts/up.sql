@@ -0,0 +1,4 @@
+-- Scheduled feasts, and backpacks left at them for a tribute or district
+ALTER TABLE game ADD COLUMN feasts JSONB;
+ALTER TABLE item ADD COLUMN for_tribute_id INTEGER;
+ALTER TABLE item ADD COLUMN for_district INTEGER;
\ No newline at end of file
@@ -0,0 +1,14 @@
+-- Packs of animals and mutts roaming the arena, each with its own area, head count and health.
+-- Mutts released by the gamemakers can carry their own name and bite.
+CREATE TABLE pack (
+    id SERIAL PRIMARY KEY,
+    game_id INT NOT NULL,
+    area_id INT NOT NULL,
+    animal VARCHAR(32) NOT NULL,
+    count INT NOT NULL,
+    health INT NOT NULL,
+    name VARCHAR(64),
+    damage INT,
+    FOREIGN KEY (game_id) REFERENCES game(id) ON DELETE CASCADE,
+    FOREIGN KEY (area_id) REFERENCES area(id) ON DELETE CASCADE
+);
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM tribute_action WHERE action_id IN (SELECT id FROM action WHERE name IN ('forage', 'hunt', 'fish', 'find water'));
+DELETE FROM action WHERE name IN ('forage', 'hunt', 'fish', 'find water');
\ No newline at end of file
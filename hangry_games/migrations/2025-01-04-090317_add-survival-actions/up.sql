@@ -0,0 +1,5 @@
+-- Ways for tributes to live off the land.
+INSERT INTO action (name, description) VALUES ('forage', 'Search for something to eat');
+INSERT INTO action (name, description) VALUES ('hunt', 'Hunt for game');
+INSERT INTO action (name, description) VALUES ('fish', 'Fish for a meal');
+INSERT INTO action (name, description) VALUES ('find water', 'Search for clean water');
\ No newline at end of file
@@ -0,0 +1,4 @@
+-- What a crafted item was made from, by name.
+ALTER TABLE item ADD COLUMN components JSONB;
+
+INSERT INTO action (name, description) VALUES ('craft', 'Make something out of what they carry');
\ No newline at end of file
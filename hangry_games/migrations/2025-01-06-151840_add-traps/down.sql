@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM tribute_action WHERE action_id IN (SELECT id FROM action WHERE name = 'set trap');
+DELETE FROM action WHERE name = 'set trap';
+DROP TABLE trap;
\ No newline at end of file
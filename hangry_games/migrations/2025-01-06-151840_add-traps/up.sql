@@ -0,0 +1,16 @@
+-- Traps tributes have set and left behind. Each belongs to whoever set it, and how hard it is to notice
+-- depends on how well they hid it.
+CREATE TABLE trap (
+    id SERIAL PRIMARY KEY,
+    game_id INT NOT NULL,
+    area_id INT NOT NULL,
+    owner_id INT NOT NULL,
+    name VARCHAR(64) NOT NULL,
+    strength INT NOT NULL,
+    difficulty INT NOT NULL,
+    FOREIGN KEY (game_id) REFERENCES game(id) ON DELETE CASCADE,
+    FOREIGN KEY (area_id) REFERENCES area(id) ON DELETE CASCADE,
+    FOREIGN KEY (owner_id) REFERENCES tribute(id) ON DELETE CASCADE
+);
+
+INSERT INTO action (name, description) VALUES ('set trap', 'Leave a trap for whoever comes through next');
\ No newline at end of file
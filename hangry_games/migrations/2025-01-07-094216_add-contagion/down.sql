@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE tribute DROP COLUMN incubation;
+ALTER TABLE tribute DROP COLUMN incubating;
\ No newline at end of file
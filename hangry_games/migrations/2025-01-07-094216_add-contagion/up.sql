@@ -0,0 +1,3 @@
+-- A contagious illness a tribute has caught, and how many phases until it shows
+ALTER TABLE tribute ADD COLUMN incubating VARCHAR(255);
+ALTER TABLE tribute ADD COLUMN incubation INT;
\ No newline at end of file
@@ -1,9 +1,12 @@
+use crate::i18n::{translate, Locale};
+use fluent_bundle::FluentArgs;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::str::FromStr;
 use strum::EnumIter;
 
-#[derive(Clone, Debug, Default, EnumIter, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Default, EnumIter, Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub enum Animal {
     #[default]
     Squirrel,
@@ -21,6 +24,8 @@ pub enum Animal {
     Rhino,
     Hippo,
     TrackerJacker,
+    /// Bred by the gamemakers. They never turn up by chance.
+    Mutt,
 }
 
 impl Animal {
@@ -41,17 +46,15 @@ impl Animal {
             Animal::Rhino => "rhino",
             Animal::Hippo => "hippo",
             Animal::TrackerJacker => "tracker jacker",
+            Animal::Mutt => "mutt",
         }
     }
 
-    pub fn plural(&self) -> String {
-        match self {
-            Animal::Wolf => "wolves".to_string(),
-            _ => {
-                let pluralized = format!("{}s", self.as_str());
-                pluralized
-            },
-        }
+    /// The animal's name in `locale`, pluralized to match `count`.
+    pub fn localized(&self, locale: &Locale, count: i32) -> String {
+        let mut args = FluentArgs::new();
+        args.set("count", count);
+        translate(locale, &format!("animal-{}", self.as_str().replace(' ', "-")), Some(&args))
     }
 
     pub fn random() -> Animal {
@@ -94,6 +97,7 @@ impl Animal {
             Animal::Rhino => 10,
             Animal::Hippo => 20,
             Animal::TrackerJacker => 5,
+            Animal::Mutt => 8,
         }
     }
 }
@@ -118,6 +122,7 @@ impl FromStr for Animal {
             "rhino" => Ok(Animal::Rhino),
             "hippo" => Ok(Animal::Hippo),
             "tracker jacker" => Ok(Animal::TrackerJacker),
+            "mutt" => Ok(Animal::Mutt),
             _ => Err(()),
         }
     }
@@ -141,6 +146,7 @@ impl Display for Animal {
             Animal::Rhino => write!(f, "rhino"),
             Animal::Hippo => write!(f, "hippo"),
             Animal::TrackerJacker => write!(f, "tracker jacker"),
+            Animal::Mutt => write!(f, "mutt"),
         }
     }
 }
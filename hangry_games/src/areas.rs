@@ -1,17 +1,21 @@
 use crate::events::AreaEvent;
+use crate::i18n::{translate, translate_attribute, Locale};
 use crate::models;
 use crate::models::area::Area as AreaModel;
 use crate::models::tribute::Tribute as ModelTribute;
-use crate::models::{create_full_log, get_game_by_id, update_tribute};
+use crate::models::{create_full_log, get_all_living_tributes, get_game_by_id, update_tribute};
+use crate::tributes::actions::TributeAction;
 use crate::tributes::actors::Tribute;
-use crate::tributes::statuses::TributeStatus;
+use rand::prelude::SliceRandom;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::fmt::Display;
 use std::str::FromStr;
 use crate::items::Item;
 use crate::messages::GameMessage;
 
-#[derive(Clone, Default, Debug, Eq, PartialEq)]
+#[derive(Clone, Default, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Area {
     #[default]
     Cornucopia,
@@ -65,6 +69,31 @@ impl Area {
         }
     }
 
+    fn catalog_key(&self) -> &str {
+        match self {
+            Area::Cornucopia => "area-cornucopia",
+            Area::Northeast => "area-northeast",
+            Area::Northwest => "area-northwest",
+            Area::Southeast => "area-southeast",
+            Area::Southwest => "area-southwest",
+        }
+    }
+
+    /// The area's name in `locale`.
+    pub fn localized(&self, locale: &Locale) -> String {
+        translate(locale, self.catalog_key(), None)
+    }
+
+    /// Where something happens, e.g. "in the Northeast".
+    pub fn localized_location(&self, locale: &Locale) -> String {
+        translate_attribute(locale, self.catalog_key(), "location", None)
+    }
+
+    /// The area at the start of a sentence, e.g. "The Northeast".
+    pub fn localized_subject(&self, locale: &Locale) -> String {
+        translate_attribute(locale, self.catalog_key(), "subject", None)
+    }
+
     pub fn neighbors(&self) -> Vec<Area> {
         match self {
             Area::Cornucopia => vec![Area::Northeast, Area::Northwest, Area::Southeast, Area::Southwest],
@@ -75,6 +104,59 @@ impl Area {
         }
     }
 
+    /// Shortest open route to `destination`, ending with it. Empty if already there.
+    pub fn path_to(&self, destination: &Area, closed_areas: &[Area]) -> Option<Vec<Area>> {
+        if self == destination {
+            return Some(vec![]);
+        }
+        shortest_path(self.clone(), closed_areas, Area::neighbors, |a| a == destination)
+    }
+
+    /// Shortest open route to the closest area matching `is_goal`, ending with it.
+    pub fn path_to_nearest<F>(&self, closed_areas: &[Area], is_goal: F) -> Option<Vec<Area>>
+    where
+        F: Fn(&Area) -> bool,
+    {
+        shortest_path(self.clone(), closed_areas, Area::neighbors, is_goal)
+    }
+
+    /// How much cover the terrain gives anyone hiding there.
+    pub fn cover(&self) -> i32 {
+        match self {
+            Area::Cornucopia => 0,
+            Area::Northeast => 20,
+            Area::Northwest => 15,
+            Area::Southeast => 10,
+            Area::Southwest => 25,
+        }
+    }
+
+    /// How much the area has to offer anyone living off the land there, 0-100.
+    /// The lake by the Cornucopia has fish and water, the woods have game and plants.
+    pub fn resources(&self, action: &TributeAction) -> i32 {
+        match (self, action) {
+            (Area::Cornucopia, TributeAction::Fish) => 40,
+            (Area::Cornucopia, TributeAction::FindWater) => 60,
+            (Area::Cornucopia, _) => 10,
+            (Area::Northeast, TributeAction::Forage) => 50,
+            (Area::Northeast, TributeAction::Hunt) => 40,
+            (Area::Northeast, TributeAction::Fish) => 10,
+            (Area::Northeast, _) => 20,
+            (Area::Northwest, TributeAction::Forage) => 40,
+            (Area::Northwest, TributeAction::Hunt) => 50,
+            (Area::Northwest, TributeAction::Fish) => 20,
+            (Area::Northwest, _) => 30,
+            (Area::Southeast, TributeAction::Forage) => 30,
+            (Area::Southeast, TributeAction::Hunt) => 20,
+            (Area::Southeast, TributeAction::Fish) => 50,
+            (Area::Southeast, _) => 60,
+            (Area::Southwest, TributeAction::Forage) => 60,
+            (Area::Southwest, TributeAction::Hunt) => 30,
+            (Area::Southwest, TributeAction::Fish) => 0,
+            (Area::Southwest, _) => 10,
+        }
+    }
+
     pub fn random() -> Area {
         let mut rng = rand::thread_rng();
         match rng.gen_range(0..5) {
@@ -148,14 +230,30 @@ impl Area {
     }
 
     pub fn do_area_event(game_id: i32) {
+        let game = get_game_by_id(game_id).expect("Game doesn't exist");
+        Area::do_area_event_in(game_id, Area::random_open_area(game.closed_areas()));
+    }
+
+    /// Closes one of the outer areas still open. The Cornucopia is never picked.
+    pub fn close_outer_area(game_id: i32) {
+        let game = get_game_by_id(game_id).expect("Game doesn't exist");
+        let closed_areas = game.closed_areas();
+        let open_areas = Area::Cornucopia.neighbors().into_iter()
+            .filter(|area| !closed_areas.contains(area))
+            .collect::<Vec<Area>>();
+
+        if let Some(area) = open_areas.choose(&mut rand::thread_rng()) {
+            Area::do_area_event_in(game_id, area.clone());
+        }
+    }
+
+    fn do_area_event_in(game_id: i32, area: Area) {
         let event = AreaEvent::random();
         let mut game = get_game_by_id(game_id).expect("Game doesn't exist");
-        let closed_areas = game.closed_areas();
-        let area = Area::random_open_area(closed_areas);
 
         create_full_log(
             game_id,
-            GameMessage::AreaEvent(event.clone(), area.clone()).to_string(),
+            GameMessage::AreaEvent(event.clone(), area.clone()),
             Some(area.id()),
             None,
             None,
@@ -167,6 +265,36 @@ impl Area {
         game.close_area(&model_area);
     }
 
+    /// A hazard that sweeps the whole arena at once. Nowhere closes, but anyone
+    /// without the luck to dodge it is left reeling.
+    pub fn do_arena_hazard(game_id: i32) {
+        let mut rng = rand::thread_rng();
+        let event = AreaEvent::random();
+        let game = get_game_by_id(game_id).expect("Game doesn't exist");
+
+        create_full_log(game_id, GameMessage::ArenaHazard(event.clone()), None, None, None, None);
+
+        for tribute in get_all_living_tributes(&game) {
+            let mut tribute = Tribute::from(tribute);
+            if rng.gen_bool(tribute.luck.unwrap_or(0) as f64 / 100.0) {
+                continue;
+            }
+
+            tribute.status = event.status();
+            let damage = 10;
+            tribute.takes_mental_damage(damage);
+            create_full_log(
+                game_id,
+                GameMessage::TributeShaken(tribute.clone(), event.clone(), damage),
+                tribute.area.as_ref().map(|area| area.id()),
+                Some(tribute.id.unwrap()),
+                None,
+                None,
+            );
+            update_tribute(tribute.id.unwrap(), ModelTribute::from(tribute.clone()));
+        }
+    }
+
     pub fn clean_up_area_events(game_id: i32) {
         let mut rng = rand::thread_rng();
         let mut game = get_game_by_id(game_id).expect("Game doesn't exist");
@@ -185,7 +313,7 @@ impl Area {
             for mut tribute in tributes {
                 create_full_log(
                     game_id,
-                    GameMessage::TrappedInArea(tribute.clone(), area.clone()).to_string(),
+                    GameMessage::TrappedInArea(tribute.clone(), area.clone()),
                     Some(area.id()),
                     Some(tribute.id.unwrap()),
                     None,
@@ -195,29 +323,19 @@ impl Area {
                 if rng.gen_bool(tribute.luck.unwrap_or(0) as f64 / 100.0) {
                     // If the tribute is lucky, they're just harmed by the event
                     let area_event = AreaEvent::from_str(&last_event.name).unwrap();
-                    match area_event {
-                        AreaEvent::Wildfire => {
-                            tribute.status = TributeStatus::Burned
-                        }
-                        AreaEvent::Flood => {
-                            tribute.status = TributeStatus::Drowned
-                        }
-                        AreaEvent::Earthquake => {
-                            tribute.status = TributeStatus::Buried
-                        }
-                        AreaEvent::Avalanche => {
-                            tribute.status = TributeStatus::Buried
-                        }
-                        AreaEvent::Blizzard => {
-                            tribute.status = TributeStatus::Frozen
-                        }
-                        AreaEvent::Landslide => {
-                            tribute.status = TributeStatus::Buried
-                        }
-                        AreaEvent::Heatwave => {
-                            tribute.status = TributeStatus::Overheated
-                        }
-                    };
+                    tribute.status = area_event.status();
+
+                    // Surviving it still leaves a mark
+                    let damage = 15;
+                    tribute.takes_mental_damage(damage);
+                    create_full_log(
+                        game_id,
+                        GameMessage::TributeShaken(tribute.clone(), area_event, damage),
+                        Some(area.id()),
+                        Some(tribute.id.unwrap()),
+                        None,
+                        None,
+                    );
                 } else {
                     // If the tribute is unlucky, they die
                     tribute.dies();
@@ -225,7 +343,7 @@ impl Area {
                     tribute.killed_by = Some(last_event.name.clone());
                     create_full_log(
                         game_id,
-                        GameMessage::DiedInArea(tribute.clone(), area.clone()).to_string(),
+                        GameMessage::DiedInArea(tribute.clone(), area.clone()),
                         Some(area.id()),
                         Some(tribute.id.unwrap()),
                         None,
@@ -235,11 +353,11 @@ impl Area {
                 update_tribute(tribute.id.unwrap(), ModelTribute::from(tribute.clone()));
             }
 
-            // Re-open the area?
-            if rng.gen_bool(0.5) {
+            // Re-open the area? Not once the finale is driving everyone inward.
+            if (!game.in_finale() || area == Area::Cornucopia) && rng.gen_bool(game.tuning().area_reopen_chance) {
                 create_full_log(
                     game_id,
-                    GameMessage::AreaOpen(area.clone()).to_string(),
+                    GameMessage::AreaOpen(area.clone()),
                     Some(area.id()),
                     None,
                     None,
@@ -251,6 +369,34 @@ impl Area {
     }
 }
 
+/// Breadth-first search over an arena graph, never stepping into a closed node.
+/// The start itself may be closed, so tributes can always escape.
+/// Returns every step after `start`, ending with the first node matching `is_goal`.
+pub fn shortest_path<T, N, G>(start: T, closed: &[T], neighbors: N, is_goal: G) -> Option<Vec<T>>
+where
+    T: Clone + PartialEq,
+    N: Fn(&T) -> Vec<T>,
+    G: Fn(&T) -> bool,
+{
+    let mut visited = vec![start.clone()];
+    let mut queue = VecDeque::from([(start, vec![])]);
+
+    while let Some((node, path)) = queue.pop_front() {
+        for next in neighbors(&node) {
+            if visited.contains(&next) || closed.contains(&next) {
+                continue;
+            }
+            let mut path: Vec<T> = path.clone();
+            path.push(next.clone());
+            if is_goal(&next) {
+                return Some(path);
+            }
+            visited.push(next.clone());
+            queue.push_back((next, path));
+        }
+    }
+    None
+}
 
 impl From<AreaModel> for Area {
     fn from(area: AreaModel) -> Self {
@@ -266,7 +412,7 @@ impl From<String> for Area {
 
 #[cfg(test)]
 mod tests {
-    use super::Area;
+    use super::{shortest_path, Area};
 
     #[test]
     fn area_from_str() {
@@ -315,4 +461,45 @@ mod tests {
         assert_eq!(Area::Southeast.neighbors(), vec![Area::Cornucopia, Area::Southwest, Area::Northeast]);
         assert_eq!(Area::Southwest.neighbors(), vec![Area::Cornucopia, Area::Southeast, Area::Northwest]);
     }
+
+    #[test]
+    fn area_path_to() {
+        assert_eq!(Area::Northeast.path_to(&Area::Northeast, &[]), Some(vec![]));
+        assert_eq!(Area::Northeast.path_to(&Area::Cornucopia, &[]), Some(vec![Area::Cornucopia]));
+        assert_eq!(Area::Northeast.path_to(&Area::Southwest, &[]), Some(vec![Area::Cornucopia, Area::Southwest]));
+        assert_eq!(
+            Area::Northeast.path_to(&Area::Southwest, &[Area::Cornucopia]),
+            Some(vec![Area::Northwest, Area::Southwest])
+        );
+        assert_eq!(Area::Northeast.path_to(&Area::Southwest, &[Area::Southwest]), None);
+        assert_eq!(
+            Area::Northeast.path_to(&Area::Southwest, &[Area::Cornucopia, Area::Northwest, Area::Southeast]),
+            None
+        );
+        // Tributes can always walk out of a closed area
+        assert_eq!(Area::Cornucopia.path_to(&Area::Northwest, &[Area::Cornucopia]), Some(vec![Area::Northwest]));
+    }
+
+    #[test]
+    fn shortest_path_on_larger_arena() {
+        // A 6x6 grid with a wall down column 3, open only at the bottom row
+        let neighbors = |n: &i32| {
+            let (x, y) = (n % 6, n / 6);
+            let mut out = vec![];
+            if x > 0 { out.push(n - 1); }
+            if x < 5 { out.push(n + 1); }
+            if y > 0 { out.push(n - 6); }
+            if y < 5 { out.push(n + 6); }
+            out
+        };
+        let wall = vec![3, 9, 15, 21, 27];
+        let path = shortest_path(0, &wall, neighbors, |n| *n == 5).unwrap();
+        assert_eq!(path.len(), 15);
+        assert_eq!(path.last(), Some(&5));
+        assert!(path.iter().all(|n| !wall.contains(n)));
+
+        let mut sealed = wall.clone();
+        sealed.push(33);
+        assert_eq!(shortest_path(0, &sealed, neighbors, |n| *n == 5), None);
+    }
 }
\ No newline at end of file
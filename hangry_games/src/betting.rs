@@ -0,0 +1,300 @@
+use crate::models::{get_all_living_tributes, get_tribute_by_id, Bet, Game, LogEntry, NewBet, Spectator};
+use crate::tributes::actors::Tribute;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The bookmaker's cut, taken off every price.
+const HOUSE_EDGE: f64 = 0.1;
+/// Even a sure thing pays a little.
+const MIN_ODDS: f64 = 1.05;
+/// A long shot still gets a price, however unlikely.
+const MAX_ODDS: f64 = 1000.0;
+/// Roughly how many deaths in a game are kills rather than accidents, hazards or starvation.
+const KILL_SHARE: f64 = 0.6;
+
+/// What a bet on a tribute is riding on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Wager {
+    /// They're the last one standing.
+    Winner,
+    /// They're the first to fall.
+    FirstDeath,
+    /// They finish the game with at least this many kills.
+    Kills(i32),
+}
+
+impl Wager {
+    pub fn kind(&self) -> &str {
+        match self {
+            Wager::Winner => "winner",
+            Wager::FirstDeath => "first death",
+            Wager::Kills(_) => "kills",
+        }
+    }
+
+    fn from_bet(bet: &Bet) -> Wager {
+        match bet.kind.as_str() {
+            "winner" => Wager::Winner,
+            "first death" => Wager::FirstDeath,
+            _ => Wager::Kills(bet.kills.unwrap_or(1)),
+        }
+    }
+}
+
+impl Display for Wager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Wager::Kills(kills) => write!(f, "{} kills", kills),
+            _ => write!(f, "{}", self.kind()),
+        }
+    }
+}
+
+impl FromStr for Wager {
+    type Err = String;
+
+    /// "winner", "first-death", or a kill count like "kills=3".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        match s.as_str() {
+            "winner" => Ok(Wager::Winner),
+            "first death" | "first-death" | "first_death" => Ok(Wager::FirstDeath),
+            _ => match s.split_once('=') {
+                Some(("kills", kills)) => match kills.trim().parse::<i32>() {
+                    Ok(kills) if kills > 0 => Ok(Wager::Kills(kills)),
+                    _ => Err(format!("{} isn't a kill count", kills)),
+                },
+                _ => Err(format!("Unknown wager {}", s)),
+            },
+        }
+    }
+}
+
+/// How dangerous a tribute looks right now: their training score and kills so far,
+/// discounted by the state they're in.
+fn form(tribute: &Tribute) -> f64 {
+    let record = tribute.training_score.unwrap_or(6) as f64 + tribute.kills.unwrap_or(0) as f64 * 2.0;
+    let condition = (tribute.health + tribute.sanity) as f64 / 200.0;
+    record * condition.max(0.05)
+}
+
+/// The chance of `wager` coming in for `tribute`, given who's still alive and whether anyone has died yet.
+/// Zero if the bet can't be won any more.
+pub fn chance(wager: Wager, tribute: &Tribute, living: &[Tribute], anyone_dead: bool) -> f64 {
+    if !living.iter().any(|t| t.id == tribute.id) {
+        return 0.0;
+    }
+    match wager {
+        Wager::Winner => form(tribute) / living.iter().map(form).sum::<f64>(),
+        Wager::FirstDeath if anyone_dead => 0.0,
+        Wager::FirstDeath => {
+            let weakness = |t: &Tribute| 1.0 / form(t);
+            weakness(tribute) / living.iter().map(weakness).sum::<f64>()
+        }
+        Wager::Kills(kills) => {
+            let needed = kills - tribute.kills.unwrap_or(0);
+            if needed <= 0 {
+                return 1.0;
+            }
+            let share = form(tribute) / living.iter().map(form).sum::<f64>();
+            let expected = (living.len() - 1) as f64 * KILL_SHARE * share;
+            at_least(needed, expected)
+        }
+    }
+}
+
+/// Poisson odds of at least `count` events when `expected` are likely.
+fn at_least(count: i32, expected: f64) -> f64 {
+    let mut term = (-expected).exp();
+    let mut below = 0.0;
+    for i in 0..count {
+        below += term;
+        term *= expected / (i + 1) as f64;
+    }
+    (1.0 - below).max(0.0)
+}
+
+/// Decimal odds for a bet with `chance` of coming in: a winning stake of 10 at 3.5 pays back 35.
+pub fn odds(chance: f64) -> f64 {
+    if chance <= 0.0 {
+        return MAX_ODDS;
+    }
+    let odds = ((1.0 - HOUSE_EDGE) / chance).clamp(MIN_ODDS, MAX_ODDS);
+    (odds * 100.0).round() / 100.0
+}
+
+/// Current odds on each living tribute winning and, until someone dies, being the first to go.
+pub fn odds_board(game: &Game) -> Vec<(Tribute, f64, Option<f64>)> {
+    let living = living_tributes(game);
+    let anyone_dead = living.len() < game.tributes().len();
+    living.iter()
+        .map(|tribute| {
+            let winner = odds(chance(Wager::Winner, tribute, &living, anyone_dead));
+            let first_death = (!anyone_dead).then(|| odds(chance(Wager::FirstDeath, tribute, &living, anyone_dead)));
+            (tribute.clone(), winner, first_death)
+        })
+        .collect()
+}
+
+/// Takes `stake` from the spectator's balance and books the bet at today's odds.
+pub fn place_bet(game: &Game, spectator: &mut Spectator, tribute: &Tribute, wager: Wager, stake: i32) -> Result<Bet, String> {
+    if game.ended_at.is_some() {
+        return Err(format!("{} is over", game.name));
+    }
+    if tribute.game_id != Some(game.id) {
+        return Err(format!("{} isn't in {}", tribute.name, game.name));
+    }
+    if stake <= 0 {
+        return Err("Stake must be more than zero".to_string());
+    }
+    if stake > spectator.balance {
+        return Err(format!("{} can't cover a stake of {}, their balance is {}", spectator.name, stake, spectator.balance));
+    }
+
+    let living = living_tributes(game);
+    let anyone_dead = living.len() < game.tributes().len();
+    if !tribute.is_alive() {
+        return Err(format!("{} is already dead", tribute.name));
+    }
+    match wager {
+        Wager::FirstDeath if anyone_dead => return Err("Someone has already died".to_string()),
+        Wager::Kills(kills) if tribute.kills.unwrap_or(0) >= kills => {
+            return Err(format!("{} already has {} kills", tribute.name, tribute.kills.unwrap_or(0)));
+        }
+        _ => {}
+    }
+
+    spectator.adjust_balance(-stake);
+    Ok(Bet::create(NewBet {
+        game_id: game.id,
+        spectator_id: spectator.id,
+        tribute_id: tribute.id.unwrap(),
+        kind: wager.kind().to_string(),
+        kills: match wager {
+            Wager::Kills(kills) => Some(kills),
+            _ => None,
+        },
+        stake,
+        odds: odds(chance(wager, tribute, &living, anyone_dead)),
+        day: game.day.unwrap_or(0),
+    }))
+}
+
+/// Pays out every open bet on a game that's over. A bet on any of several co-victors wins,
+/// and so does one on any of the tributes who fell together in the first death announcement.
+/// Winner bets are refunded if the game was called off with no one having won.
+pub fn settle_bets(game: &Game) {
+    let living = get_all_living_tributes(game);
+    let won = game.is_won(&living.iter().map(|t| t.district).collect::<Vec<i32>>());
+    let called_off = !won && !living.is_empty();
+    let winners = if won { living.iter().map(|t| t.id).collect::<Vec<i32>>() } else { vec![] };
+    let first_deaths = first_deaths(&game.logs_by_kind(&["DailyDeathAnnouncement", "DeathAnnouncement"]));
+
+    for mut bet in Bet::get_all_for_game(game.id).into_iter().filter(|b| !b.is_settled()) {
+        let won = match Wager::from_bet(&bet) {
+            Wager::Winner if called_off => {
+                bet.settle(bet.stake);
+                continue;
+            }
+            Wager::Winner => winners.contains(&bet.tribute_id),
+            Wager::FirstDeath => first_deaths.contains(&bet.tribute_id),
+            Wager::Kills(kills) => get_tribute_by_id(bet.tribute_id).kills.unwrap_or(0) >= kills,
+        };
+        let payout = if won { (bet.stake as f64 * bet.odds).round() as i32 } else { 0 };
+        bet.settle(payout);
+    }
+}
+
+/// Everyone named in the first death announcement that named anyone. Deaths within a phase
+/// aren't ordered, so they all count as first.
+fn first_deaths(logs: &[LogEntry]) -> Vec<i32> {
+    logs.iter()
+        .skip_while(|log| log.kind.as_deref() != Some("DeathAnnouncement"))
+        .take_while(|log| log.kind.as_deref() == Some("DeathAnnouncement"))
+        .filter_map(|log| log.tribute_id)
+        .collect()
+}
+
+fn living_tributes(game: &Game) -> Vec<Tribute> {
+    get_all_living_tributes(game).into_iter().map(Tribute::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field() -> Vec<Tribute> {
+        (1..=4).map(|id| {
+            let mut tribute = Tribute::new(format!("Tribute {}", id), Some(id), None);
+            tribute.id = Some(id);
+            tribute.training_score = Some(id * 3);
+            tribute
+        }).collect()
+    }
+
+    #[test]
+    fn favourites_get_shorter_odds() {
+        let living = field();
+        let winner = |t: &Tribute| chance(Wager::Winner, t, &living, false);
+        let total: f64 = living.iter().map(winner).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert!(odds(winner(&living[3])) < odds(winner(&living[0])));
+
+        let first_death = |t: &Tribute| chance(Wager::FirstDeath, t, &living, false);
+        assert!(first_death(&living[0]) > first_death(&living[3]));
+        assert_eq!(chance(Wager::FirstDeath, &living[0], &living, true), 0.0);
+    }
+
+    #[test]
+    fn kill_counts_get_harder() {
+        let mut living = field();
+        let one = chance(Wager::Kills(1), &living[3], &living, false);
+        let three = chance(Wager::Kills(3), &living[3], &living, false);
+        assert!(one > three && three > 0.0);
+
+        living[3].kills = Some(3);
+        assert_eq!(chance(Wager::Kills(3), &living[3], &living, false), 1.0);
+        assert_eq!(odds(1.0), MIN_ODDS);
+        assert_eq!(odds(0.0), MAX_ODDS);
+        assert_eq!(odds(1e-9), MAX_ODDS);
+    }
+
+    fn announcement(id: i32, kind: &str, tribute_id: Option<i32>) -> LogEntry {
+        LogEntry {
+            id,
+            created_at: chrono::NaiveDateTime::default(),
+            game_id: 1,
+            day: 1,
+            message: String::new(),
+            area_id: None,
+            tribute_id,
+            action_target_type: None,
+            action_target_id: None,
+            kind: Some(kind.to_string()),
+            payload: None,
+        }
+    }
+
+    #[test]
+    fn first_deaths_announced_together_all_count() {
+        let logs = vec![
+            announcement(1, "DailyDeathAnnouncement", None),
+            announcement(2, "DailyDeathAnnouncement", None),
+            announcement(3, "DeathAnnouncement", Some(4)),
+            announcement(4, "DeathAnnouncement", Some(2)),
+            announcement(5, "DailyDeathAnnouncement", None),
+            announcement(6, "DeathAnnouncement", Some(1)),
+        ];
+        assert_eq!(first_deaths(&logs), vec![4, 2]);
+        assert_eq!(first_deaths(&logs[..2]), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn wagers_parse() {
+        assert_eq!(Wager::from_str("winner"), Ok(Wager::Winner));
+        assert_eq!(Wager::from_str("First-Death"), Ok(Wager::FirstDeath));
+        assert_eq!(Wager::from_str("kills=2"), Ok(Wager::Kills(2)));
+        assert!(Wager::from_str("kills=0").is_err());
+        assert!(Wager::from_str("sponsor").is_err());
+    }
+}
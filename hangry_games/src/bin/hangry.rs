@@ -5,9 +5,14 @@ use hangry_games::gui::components::*;
 use hangry_games::gui::router::Routes;
 use hangry_games::gui::states::HGState;
 use hangry_games::gui::functions::list_of_games;
+use hangry_games::i18n::{set_gui_locale, Locale};
+use std::str::FromStr;
 
 fn main() {
     dioxus_logger::init(Level::INFO).expect("logger failed to init");
+    if let Ok(locale) = std::env::var("HANGRY_LOCALE") {
+        set_gui_locale(Locale::from_str(&locale).unwrap_or_default());
+    }
     let mut head = r#"<script src="https://cdn.tailwindcss.com"></script>
         <link rel="preconnect" href="https://fonts.googleapis.com">
         <link rel="preconnect" href="https://fonts.gstatic.com" crossorigin>
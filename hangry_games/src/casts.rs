@@ -0,0 +1,298 @@
+use crate::event_packs::{game_pack_dir, CustomEvent, Effect, EventPack, Phase};
+use crate::models::{create_tribute, Game};
+use crate::tributes::actors::Tribute;
+use crate::tributes::pronouns::Pronouns;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// A roster saved from the BrantSteele web simulator: a list of tributes in reaping order,
+/// optionally wrapped with the cast's name and its custom events.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum CastFile {
+    Roster(Vec<CastMember>),
+    Cast(Cast),
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Cast {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(alias = "tributes", alias = "cast")]
+    pub members: Vec<CastMember>,
+    #[serde(default)]
+    pub events: Vec<CastEvent>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct CastMember {
+    pub name: String,
+    #[serde(default, alias = "nick")]
+    pub nickname: Option<String>,
+    /// A URL or a path relative to the cast file.
+    #[serde(default, alias = "img", alias = "imageUrl", alias = "image_url")]
+    pub image: Option<String>,
+    /// Members without one fill the game's districts in turn: the first two are District 1 in a standard game, and so on.
+    #[serde(default)]
+    pub district: Option<i32>,
+    /// "male", "female", "m", "f", or a pronoun set like "they/them".
+    #[serde(default)]
+    pub gender: Option<String>,
+}
+
+/// A custom event in the simulator's format, e.g. "(Player1) pushes (Player2) off a cliff."
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct CastEvent {
+    pub text: String,
+    #[serde(default)]
+    pub phase: Option<Phase>,
+    /// Players who die, numbered from 1.
+    #[serde(default, alias = "fatalities")]
+    pub killed: Vec<usize>,
+    /// Players credited with those deaths, in the same order.
+    #[serde(default)]
+    pub killers: Vec<usize>,
+}
+
+impl Cast {
+    pub fn load(path: &Path) -> Result<Cast, String> {
+        let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        match serde_json::from_str::<CastFile>(&contents).map_err(|e| e.to_string())? {
+            CastFile::Roster(members) => Ok(Cast { members, ..Cast::default() }),
+            CastFile::Cast(cast) => Ok(cast),
+        }
+    }
+
+    /// Adds every member to `game`, copying avatars into `assets/avatars/<game id>/`.
+    /// Images are looked up next to the cast file in `base_dir`.
+    /// Returns the new tributes and a warning for anything that couldn't be brought over.
+    pub fn import(&self, game: &Game, base_dir: &Path) -> (Vec<Tribute>, Vec<String>) {
+        let mut tributes = vec![];
+        let mut warnings = vec![];
+        for member in &self.members {
+            let name = member.nickname.clone().filter(|n| !n.trim().is_empty()).unwrap_or(member.name.clone());
+            let mut model = create_tribute(&name, None);
+            if let Err(error) = model.try_set_game(game) {
+                warnings.push(format!("{}: {}", member.name, error));
+                Tribute::delete(model.id);
+                break;
+            }
+            model.game_id = Some(game.id);
+
+            let mut tribute = Tribute::from(model);
+            match member.district {
+                Some(district) if (1..=game.districts).contains(&district) => tribute.district = district,
+                Some(district) => warnings.push(format!("{}: there's no District {}", member.name, district)),
+                None => {}
+            }
+            if member.nickname.is_some() {
+                tribute.real_name = Some(member.name.clone());
+            }
+            tribute.pronouns = member.gender.as_ref().and_then(|gender| {
+                let pronouns = gender_pronouns(gender);
+                if pronouns.is_none() {
+                    warnings.push(format!("{}: unknown gender {}", member.name, gender));
+                }
+                pronouns
+            });
+            if let Some(image) = &member.image {
+                match copy_avatar(image, base_dir, game.id, tribute.id.unwrap()) {
+                    Ok(avatar) => tribute.avatar = Some(avatar),
+                    Err(error) => warnings.push(format!("{}: couldn't copy {}, {}", member.name, image, error)),
+                }
+            }
+            tribute.update(tribute.clone().into());
+            tributes.push(tribute);
+        }
+        (tributes, warnings)
+    }
+
+    /// The cast's custom events as an event pack, if it has any.
+    pub fn event_pack(&self, fallback_name: &str) -> Option<EventPack> {
+        if self.events.is_empty() {
+            return None;
+        }
+        let name = self.name.clone().unwrap_or(fallback_name.to_string());
+        let events = self.events.iter().enumerate()
+            .map(|(index, event)| event.to_custom_event(&format!("{} #{}", name, index + 1)))
+            .collect();
+        Some(EventPack { name, events })
+    }
+
+    /// Writes `event_pack` into `game`'s own pack directory so only it draws from them, returning its path.
+    pub fn install_event_pack(&self, game: &Game) -> Option<Result<String, Vec<String>>> {
+        let pack = self.event_pack(&game.name)?;
+        if let Err(errors) = pack.validate() {
+            return Some(Err(errors));
+        }
+        let dir = game_pack_dir(game.id);
+        let path = format!("{}/{}.json", dir, slug(&pack.name));
+        if Path::new(&path).exists() {
+            return Some(Err(vec![format!("{} already exists", path)]));
+        }
+        fs::create_dir_all(&dir).expect("Unable to create event pack directory");
+        let json = serde_json::to_string_pretty(&pack).expect("Error serializing event pack");
+        fs::write(&path, json).expect("Unable to write event pack");
+        Some(Ok(path))
+    }
+}
+
+impl CastEvent {
+    fn to_custom_event(&self, name: &str) -> CustomEvent {
+        let tags = [
+            ("Player", None),
+            ("he/she", Some("subject")),
+            ("him/her", Some("object")),
+            ("his/her", Some("possessive")),
+            ("himself/herself", Some("reflexive")),
+        ];
+        let mut text = self.text.clone();
+        let mut participants = 1;
+        for n in (1..=24).rev() {
+            for (tag, form) in tags {
+                let tag = format!("({}{})", tag, n);
+                if text.contains(&tag) {
+                    participants = participants.max(n);
+                    let placeholder = match form {
+                        Some(form) => format!("{{{}.{}}}", n, form),
+                        None => format!("{{{}}}", n),
+                    };
+                    text = text.replace(&tag, &placeholder);
+                }
+            }
+        }
+        let mentioned = self.killed.iter().chain(&self.killers).max().copied().unwrap_or(1);
+        participants = participants.max(mentioned);
+        // Killers are listed alongside their victims; a lone killer gets every death
+        let effects = self.killed.iter().enumerate()
+            .map(|(index, tribute)| {
+                let killer = match self.killers[..] {
+                    [only] => Some(only),
+                    _ => self.killers.get(index).copied(),
+                };
+                Effect::Death { tribute: *tribute, killer }
+            })
+            .collect();
+        CustomEvent {
+            name: name.to_string(),
+            text,
+            translations: HashMap::new(),
+            participants,
+            weight: 1.0,
+            phase: self.phase,
+            areas: vec![],
+            requires: vec![],
+            effects,
+        }
+    }
+}
+
+fn gender_pronouns(gender: &str) -> Option<Pronouns> {
+    match gender.trim().to_lowercase().as_str() {
+        "m" | "male" | "man" | "boy" => Some(Pronouns::He),
+        "f" | "female" | "woman" | "girl" => Some(Pronouns::She),
+        "n" | "nonbinary" | "non-binary" | "neutral" => Some(Pronouns::They),
+        other => Pronouns::from_str(other).ok(),
+    }
+}
+
+/// `name` made safe to use as a file name: lowercase, with anything but letters and digits as dashes.
+fn slug(name: &str) -> String {
+    name.to_lowercase().chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+/// Downloads are given up on after this long, or once they're this big.
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(30);
+const MAX_AVATAR_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Downloads or copies `image` to `assets/avatars/<game id>/<tribute id>.<ext>`,
+/// returning the path the GUI loads it from.
+fn copy_avatar(image: &str, base_dir: &Path, game_id: i32, tribute_id: i32) -> Result<String, String> {
+    let extension = Path::new(image.split('?').next().unwrap_or(image))
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .filter(|e| ["png", "gif", "jpg", "jpeg"].contains(&e.as_str()))
+        .unwrap_or("png".to_string());
+
+    let contents = if image.starts_with("http://") || image.starts_with("https://") {
+        let response = ureq::get(image).timeout(DOWNLOAD_TIMEOUT).call().map_err(|e| e.to_string())?;
+        let mut bytes = vec![];
+        response.into_reader().take(MAX_AVATAR_BYTES + 1).read_to_end(&mut bytes).map_err(|e| e.to_string())?;
+        if bytes.len() as u64 > MAX_AVATAR_BYTES {
+            return Err(format!("it's over {} MB", MAX_AVATAR_BYTES / 1024 / 1024));
+        }
+        bytes
+    } else {
+        fs::read(base_dir.join(image)).map_err(|e| e.to_string())?
+    };
+
+    let filename = format!("{}.{}", tribute_id, extension);
+    let avatar_path = format!("avatars/{}/", game_id);
+    let save_path = format!("./assets/{}", avatar_path);
+    fs::create_dir_all(&save_path).map_err(|e| e.to_string())?;
+    fs::write(format!("{}{}", save_path, filename), contents).map_err(|e| e.to_string())?;
+    Ok(format!("{}{}", avatar_path, filename))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simulator_events_become_custom_events() {
+        let event = CastEvent {
+            text: "(Player1) pushes (Player2) off (his/her1) raft, and (he/she2) sinks.".to_string(),
+            phase: Some(Phase::Night),
+            killed: vec![2],
+            killers: vec![1],
+        };
+        let custom = event.to_custom_event("cast #1");
+        assert_eq!(custom.text, "{1} pushes {2} off {1.possessive} raft, and {2.subject} sinks.");
+        assert_eq!(custom.participants, 2);
+        assert!(matches!(custom.effects[..], [Effect::Death { tribute: 2, killer: Some(1) }]));
+    }
+
+    #[test]
+    fn each_death_goes_to_its_own_killer() {
+        let event = CastEvent {
+            text: "(Player1) and (Player2) each take out (Player3) and (Player4).".to_string(),
+            killed: vec![3, 4],
+            killers: vec![1, 2],
+            ..CastEvent::default()
+        };
+        let custom = event.to_custom_event("cast #2");
+        assert!(matches!(custom.effects[..], [
+            Effect::Death { tribute: 3, killer: Some(1) },
+            Effect::Death { tribute: 4, killer: Some(2) },
+        ]));
+
+        let event = CastEvent { killers: vec![1], ..event };
+        assert!(matches!(event.to_custom_event("cast #3").effects[..], [
+            Effect::Death { tribute: 3, killer: Some(1) },
+            Effect::Death { tribute: 4, killer: Some(1) },
+        ]));
+    }
+
+    #[test]
+    fn names_cannot_escape_their_directory() {
+        assert_eq!(slug("Foxface"), "foxface");
+        assert_eq!(slug("../../x"), "------x");
+        assert!(!slug("a/b\\c..d").contains(['/', '\\', '.']));
+    }
+
+    #[test]
+    fn genders_map_to_pronouns() {
+        assert_eq!(gender_pronouns("Female"), Some(Pronouns::She));
+        assert_eq!(gender_pronouns("m"), Some(Pronouns::He));
+        assert_eq!(gender_pronouns("they/them"), Some(Pronouns::They));
+        assert_eq!(gender_pronouns("unknown"), None);
+    }
+}
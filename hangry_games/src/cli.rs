@@ -1,10 +1,26 @@
-use crate::models::game::{fill_tributes, get_all_living_tributes, get_dead_tributes, get_game_tributes};
-use crate::models::{create_area, create_game, create_tribute, get_action, get_all_tributes, get_area, get_area_by_id, get_areas, get_game, get_games, get_recently_dead_tributes, get_tribute, get_tribute_by_id, place_tribute_in_area, Item};
+use crate::models::game::{fill_tributes, Game as GameModel, get_all_living_tributes, get_dead_tributes, get_game_tributes};
+use crate::models::{create_area, create_game, create_tribute, get_action, get_all_tributes, get_area, get_area_by_id, get_areas, get_game, get_game_by_id, get_games, get_recently_dead_tributes, get_spectator, get_spectator_by_id, get_standings, get_tribute, get_tribute_by_id, place_tribute_in_area, Bet, Item, Spectator};
 use clap::{Parser, Subcommand};
+use crate::areas::Area;
+use crate::betting::{odds_board, place_bet, Wager};
+use crate::casts::Cast;
+use crate::event_packs::{load_packs, EventPack, EVENT_PACK_DIR};
+use crate::feasts::{Backpacks, Feast};
 use crate::games::Game;
+use crate::i18n::Locale;
+use crate::rulesets::Ruleset;
+use crate::simulation::simulate;
+use crate::tuning::Tuning;
+use crate::win_conditions::{RuleChange, WinCondition};
+use crate::tributes::actors::Tribute as TributeActor;
+use crate::tributes::point_buy::{Preset, Stat};
+use crate::tributes::pronouns::Pronouns;
+use crate::tributes::statuses::TributeStatus;
+use crate::tributes::rosters::{export_roster, import_roster, read_roster, write_roster, RosterFormat};
 use std::fs;
 use std::io::Write;
 use std::path::Path;
+use std::str::FromStr;
 
 #[derive(Debug, Parser)]
 #[command(version, about, long_about = None)]
@@ -18,27 +34,209 @@ enum Commands {
     AddArea { name: String },
     ShowAreas,
     GetArea { name: String },
-    AddTribute { name: String, game_id: String },
+    AddTribute {
+        name: String,
+        game_id: String,
+        /// e.g. she/her, he/him, they/them or xe/xem/xyr/xemself
+        #[arg(long)]
+        pronouns: Option<String>,
+        /// How to spend the game's stat budget: balanced, brawler, sneak or survivor
+        #[arg(long, default_value = "balanced")]
+        preset: String,
+    },
+    /// Rebuild a tribute's stats within their game's budget, from a preset and/or e.g. strength=40
+    SetStats {
+        tribute_id: String,
+        #[arg(long)]
+        preset: Option<String>,
+        stats: Vec<String>,
+    },
+    /// Change how many stat points hand-built tributes get in a game
+    SetStatBudget { game_id: String, points: i32 },
+    /// Set a tribute's pronouns, or clear them if none are given
+    SetPronouns { tribute_id: String, pronouns: Option<String> },
     ShowAllTributes,
     ShowTributes { game_id: String },
     ShowTribute { game_id: String, tribute_id: String },
-    FillTributes { game_id: String },
+    /// Add every tribute in a CSV or JSON roster to a game
+    ImportTributes { game_id: String, file: String },
+    /// Write a game's tributes to a CSV or JSON roster
+    ExportTributes { game_id: String, file: String },
+    FillTributes {
+        game_id: String,
+        /// Give each district one she/her and one he/him tribute
+        #[arg(long)]
+        pair_districts: bool,
+    },
     PlaceTribute { tribute_id: String, area_id: String },
     ShowTributeActions { tribute_id: String },
     TributeTakesAction { tribute_id: String, action_id: String },
-    AddGame,
+    AddGame {
+        /// Language to narrate the game in: en, es or de
+        #[arg(long)]
+        locale: Option<String>,
+        /// How many tributes the game takes
+        #[arg(long)]
+        tributes: Option<i32>,
+        /// How many districts the tributes are reaped from
+        #[arg(long)]
+        districts: Option<i32>,
+        /// House rules: standard, victors-only, double-tributes, co-victors, no-sponsors or night-hazards
+        #[arg(long)]
+        ruleset: Option<String>,
+        /// How the game is won: last-standing, last-district, last-alliance or time-limit=<days>
+        #[arg(long)]
+        win_condition: Option<String>,
+        /// Districts that stand together under last-alliance, e.g. 1,2,4. Repeat for more alliances
+        #[arg(long = "alliance")]
+        alliances: Vec<String>,
+    },
     ShowGames,
+    /// Hold training and interviews now, rather than when the game starts
+    PreGame { game_id: String },
     StartGame { game_id: String },
     RunNextDay { game_id: String },
     EndGame { game_id: String },
     GameStats { game_id: String },
     CloseArea { game_id: String, area_id: String },
     OpenArea { game_id: String, area_id: String },
-    QuickStart,
+    SetLocale { game_id: String, locale: String },
+    /// Change how many tributes a game takes, and optionally how many districts, before it starts
+    SetRosterSize {
+        game_id: String,
+        tributes: i32,
+        #[arg(long)]
+        districts: Option<i32>,
+    },
+    /// Change a game's house rules before it starts
+    SetRuleset { game_id: String, ruleset: String },
+    /// Change how a game is won before it starts
+    SetWinCondition {
+        game_id: String,
+        win_condition: String,
+        #[arg(long = "alliance")]
+        alliances: Vec<String>,
+    },
+    /// Announce a rule change in a game, e.g. co-victors
+    AnnounceRuleChange { game_id: String, rule_change: String },
+    /// Take back an earlier rule change
+    RevokeRuleChange { game_id: String, rule_change: String },
+    /// Schedule a feast at the Cornucopia, optionally with backpacks for each tribute or district
+    ScheduleFeast {
+        game_id: String,
+        day: i32,
+        /// Who gets a backpack: none, tribute or district
+        #[arg(long, default_value = "none")]
+        backpacks: String,
+    },
+    /// Call off a feast that hasn't happened yet
+    CancelFeast { game_id: String, day: i32 },
+    /// Let a pack of mutts loose in an area, e.g. the northeast
+    ReleaseMutts {
+        game_id: String,
+        area: String,
+        #[arg(long, default_value_t = 3)]
+        count: i32,
+        /// What the mutts are called, e.g. jabberjays
+        #[arg(long)]
+        name: Option<String>,
+        /// Damage each mutt does, instead of the usual for mutts
+        #[arg(long)]
+        damage: Option<i32>,
+    },
+    /// Seed a contagious illness in an area, e.g. the northeast
+    SeedEpidemic {
+        game_id: String,
+        area: String,
+        /// What they catch: sick or infected
+        #[arg(long, default_value = "sick")]
+        disease: String,
+    },
+    QuickStart {
+        /// Language to narrate the game in: en, es or de
+        #[arg(long)]
+        locale: Option<String>,
+        /// How many tributes the game takes
+        #[arg(long)]
+        tributes: Option<i32>,
+        /// How many districts the tributes are reaped from
+        #[arg(long)]
+        districts: Option<i32>,
+        /// House rules: standard, victors-only, double-tributes, co-victors, no-sponsors or night-hazards
+        #[arg(long)]
+        ruleset: Option<String>,
+        /// How the game is won: last-standing, last-district, last-alliance or time-limit=<days>
+        #[arg(long)]
+        win_condition: Option<String>,
+        /// Districts that stand together under last-alliance, e.g. 1,2,4. Repeat for more alliances
+        #[arg(long = "alliance")]
+        alliances: Vec<String>,
+        /// Give each district one she/her and one he/him tribute
+        #[arg(long)]
+        pair_districts: bool,
+    },
     RunFullGame { game_id: String },
-    ShowGameLog { game_id: String },
+    /// Bring in a cast saved from the BrantSteele simulator, in a new game unless one is given
+    ImportCast {
+        file: String,
+        #[arg(long)]
+        game_id: Option<String>,
+        /// Start the game and play it through once the cast is in
+        #[arg(long)]
+        run: bool,
+    },
+    ShowGameLog {
+        game_id: String,
+        /// Only show these kinds of events, e.g. --kind TributeAttackSuccessKill
+        #[arg(long)]
+        kind: Vec<String>,
+        /// Re-render the log in this language instead of printing the stored entries
+        #[arg(long)]
+        locale: Option<String>,
+    },
     LogTributes { game_id: String },
-    Gui,
+    /// List the event packs the simulation will load, and any problems with them
+    ShowEventPacks,
+    /// Check an event pack file without installing it
+    CheckEventPack { file: String },
+    /// Add someone who can bet on games
+    AddSpectator { name: String },
+    /// Bet on a tribute: winner, first-death or e.g. kills=3
+    PlaceBet {
+        game_id: String,
+        spectator: String,
+        tribute_id: String,
+        wager: String,
+        stake: i32,
+    },
+    /// Current odds on every living tribute
+    ShowOdds { game_id: String },
+    ShowBets { game_id: String },
+    /// Every spectator's balance, richest first
+    Standings,
+    /// Play many games in memory with random rosters and report how they went
+    Simulate {
+        #[arg(long, default_value_t = 100)]
+        games: u32,
+        /// Same seed, same games. Random if not given
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Print the report as JSON instead of tables
+        #[arg(long)]
+        json: bool,
+        /// Balance settings to play with instead of the global ones
+        #[arg(long)]
+        tuning: Option<String>,
+    },
+    /// Give a game its own balance settings from a tuning file, or leave the file out to go back to the global ones
+    SetTuning { game_id: String, file: Option<String> },
+    /// Print a game's balance settings, or the global ones if no game is given
+    ShowTuning { game_id: Option<String> },
+    Gui {
+        /// Language for the GUI labels: en, es or de
+        #[arg(long)]
+        locale: Option<String>,
+    },
 }
 
 pub fn parse() {
@@ -70,12 +268,54 @@ pub fn parse() {
         }
 
         // Tributes
-        Commands::AddTribute { name, game_id: game } => {
+        Commands::AddTribute { name, game_id: game, pronouns, preset } => {
             let game = get_game(&game).expect("Game not found");
+            if let Err(error) = game.check_room() {
+                println!("{}", error);
+                return;
+            }
             let mut tribute = create_tribute(&name, None);
             tribute.try_set_game(&game).expect("Error adding tribute to game");
+            tribute.game_id = Some(game.id);
+            if let Some(pronouns) = pronouns {
+                tribute.set_pronouns(Some(&parse_pronouns(&pronouns)));
+            }
+            let mut tribute = TributeActor::from(tribute);
+            parse_preset(&preset).apply(&mut tribute, &game.point_buy());
+            tribute.update(tribute.clone().into());
             dbg!(&tribute);
         }
+        Commands::SetStats { tribute_id, preset, stats } => {
+            let mut tribute = TributeActor::from(get_tribute(&tribute_id));
+            let game = get_game_by_id(tribute.game_id.expect("Tribute isn't in a game")).expect("Game not found");
+            let point_buy = game.point_buy();
+            if let Some(preset) = preset {
+                parse_preset(&preset).apply(&mut tribute, &point_buy);
+            }
+            for assignment in stats {
+                let (stat, value) = assignment.split_once('=')
+                    .unwrap_or_else(|| panic!("Expected stat=value, got {}", assignment));
+                let stat = Stat::from_str(stat).unwrap_or_else(|_| panic!("Unknown stat {}", stat));
+                stat.set(&mut tribute, value.trim().parse().unwrap_or_else(|_| panic!("{} isn't a number", value)));
+            }
+            match point_buy.check(&tribute) {
+                Ok(()) => {
+                    tribute.update(tribute.clone().into());
+                    println!("{} spends {} of {} points", tribute.name, point_buy.spent(&tribute), point_buy.budget);
+                }
+                Err(problems) => println!("Stats not saved: {}", problems.join("; ")),
+            }
+        }
+        Commands::SetStatBudget { game_id, points } => {
+            let game = get_game(&game_id).expect("Game not found");
+            game.set_stat_budget(points);
+        }
+        Commands::SetPronouns { tribute_id, pronouns } => {
+            let mut tribute = get_tribute(&tribute_id);
+            let pronouns = pronouns.map(|p| parse_pronouns(&p));
+            tribute.set_pronouns(pronouns.as_ref());
+            println!("{} uses {}", tribute.name, tribute.pronouns.unwrap_or("no set pronouns".to_string()));
+        }
         Commands::ShowAllTributes => {
             for tribute in get_all_tributes() {
                 println!("{}, District {}", tribute.name, tribute.district);
@@ -96,11 +336,32 @@ pub fn parse() {
             }
             println!("{:?}", tribute);
         }
-        Commands::FillTributes { game_id: game } => {
+        Commands::FillTributes { game_id: game, pair_districts } => {
             let game = get_game(&game).expect("Game not found");
-            let count = fill_tributes(&game);
+            let count = fill_tributes(&game, pair_districts);
             println!("{} tributes created", count);
         }
+        Commands::ImportTributes { game_id, file } => {
+            let game = get_game(&game_id).expect("Game not found");
+            let format = RosterFormat::from_path(Path::new(&file)).expect("Rosters must be .csv or .json files");
+            let contents = fs::read_to_string(&file).expect("Unable to read roster");
+            match read_roster(&contents, format, game.districts).and_then(|rows| import_roster(&game, &rows)) {
+                Ok(tributes) => println!("{} tributes imported into {}", tributes.len(), game.name),
+                Err(errors) => {
+                    println!("Nothing was imported:");
+                    for error in errors {
+                        println!("  {}", error);
+                    }
+                }
+            }
+        }
+        Commands::ExportTributes { game_id, file } => {
+            let game = get_game(&game_id).expect("Game not found");
+            let format = RosterFormat::from_path(Path::new(&file)).expect("Rosters must be .csv or .json files");
+            let rows = export_roster(&game);
+            fs::write(&file, write_roster(&rows, format)).expect("Unable to write roster");
+            println!("{} tributes exported to {}", rows.len(), file);
+        }
         Commands::PlaceTribute {
             tribute_id: name,
             area_id: area,
@@ -133,20 +394,97 @@ pub fn parse() {
         }
 
         // Games
-        Commands::AddGame => {
-            let game = create_game(None);
+        Commands::AddGame { locale, tributes, districts, ruleset, win_condition, alliances } => {
+            let game = create_set_up_game(locale, tributes, districts, ruleset, win_condition, alliances);
             println!("Game created: {}", game.name);
         }
+        Commands::SetLocale { game_id, locale } => {
+            let game = get_game(&game_id).expect("Game not found");
+            game.set_locale(&parse_locale(&locale));
+        }
+        Commands::SetRosterSize { game_id, tributes, districts } => {
+            let game = get_game(&game_id).expect("Game not found");
+            let game = set_roster_size(&game, Some(tributes), districts).unwrap_or_else(|error| panic!("{}", error));
+            println!("{} takes {} tributes from {} districts", game.name, game.roster_size, game.districts);
+        }
+        Commands::SetRuleset { game_id, ruleset } => {
+            let game = get_game(&game_id).expect("Game not found");
+            let game = set_ruleset(&game, Some(parse_ruleset(&ruleset))).unwrap_or_else(|error| panic!("{}", error));
+            println!("{} is played under {} rules with {} tributes", game.name, game.ruleset(), game.roster_size);
+        }
+        Commands::SetWinCondition { game_id, win_condition, alliances } => {
+            let game = get_game(&game_id).expect("Game not found");
+            let alliances = alliances.iter().map(|a| parse_alliance(a)).collect();
+            let game = set_win_condition(&game, Some(parse_win_condition(&win_condition)), alliances)
+                .unwrap_or_else(|error| panic!("{}", error));
+            println!("{} is won by {}", game.name, game.win_condition().localized(&Locale::English).to_lowercase());
+        }
+        Commands::AnnounceRuleChange { game_id, rule_change } => {
+            let game = Game::from(get_game(&game_id).expect("Game not found"));
+            if let Err(error) = game.announce_rule_change(parse_rule_change(&rule_change)) {
+                println!("{}", error);
+            }
+        }
+        Commands::RevokeRuleChange { game_id, rule_change } => {
+            let game = Game::from(get_game(&game_id).expect("Game not found"));
+            if let Err(error) = game.revoke_rule_change(parse_rule_change(&rule_change)) {
+                println!("{}", error);
+            }
+        }
+        Commands::ScheduleFeast { game_id, day, backpacks } => {
+            let mut game = get_game(&game_id).expect("Game not found");
+            match game.schedule_feast(Feast::new(day, parse_backpacks(&backpacks))) {
+                Ok(()) => print_feasts(&game),
+                Err(error) => println!("{}", error),
+            }
+        }
+        Commands::CancelFeast { game_id, day } => {
+            let mut game = get_game(&game_id).expect("Game not found");
+            match game.cancel_feast(day) {
+                Ok(()) => print_feasts(&game),
+                Err(error) => println!("{}", error),
+            }
+        }
+        Commands::ReleaseMutts { game_id, area, count, name, damage } => {
+            let game = Game::from(get_game(&game_id).expect("Game not found"));
+            match game.release_mutts(parse_area(&area), count, name, damage) {
+                Ok(pack) => println!("{} {} roam {}", pack.count, pack.label(&game.locale), pack.area),
+                Err(error) => println!("{}", error),
+            }
+        }
+        Commands::SeedEpidemic { game_id, area, disease } => {
+            let game = Game::from(get_game(&game_id).expect("Game not found"));
+            let disease = TributeStatus::from_str(&disease).unwrap_or_else(|_| panic!("Unknown status {}, try sick or infected", disease));
+            match game.seed_epidemic(parse_area(&area), disease) {
+                Ok(exposed) => println!("{} tributes have caught it", exposed),
+                Err(error) => println!("{}", error),
+            }
+        }
         Commands::ShowGames => {
             for _game in get_games() {
-                println!("{}, Day {}, Tributes {}/24 {}",
+                println!("{}, Day {}, Tributes {}/{}, {} rules {}",
                          _game.name,
                          _game.day.unwrap_or(0),
                          get_all_living_tributes(&_game).len(),
+                         _game.roster_size,
+                         _game.ruleset(),
                          if _game.ended_at.is_some() { "Closed" } else { "" }
                 );
             }
         }
+        Commands::PreGame { game_id } => {
+            let game = Game::from(get_game(&game_id).expect("Game not found"));
+            game.pre_game();
+            for tribute in game.tributes() {
+                println!(
+                    "{}, District {}: training score {}, likability {}",
+                    tribute.name,
+                    tribute.district,
+                    tribute.training_score.unwrap_or(0),
+                    tribute.likability.unwrap_or(0),
+                );
+            }
+        }
         Commands::StartGame { game_id } => {
             let game = get_game(&game_id).expect("Game not found");
             game.start();
@@ -211,26 +549,65 @@ pub fn parse() {
                 println!("({}) {}: {}", location, item.name, item.quantity);
             }
         }
-        Commands::QuickStart => {
-            let game = create_game(None);
+        Commands::QuickStart { locale, tributes, districts, ruleset, win_condition, alliances, pair_districts } => {
+            let game = create_set_up_game(locale, tributes, districts, ruleset, win_condition, alliances);
             println!("Game created: {}", game.name);
-            let count = fill_tributes(&game);
+            let count = fill_tributes(&game, pair_districts);
             println!("{} tributes created", count);
             game.start();
         }
         Commands::RunFullGame { game_id } => {
             let game = get_game(&game_id).expect("Game not found");
-            game.start();
-            let mut game_actor = Game::from(game.clone());
-            while game_actor.living_tributes().len() > 1 {
-                game_actor.run_day_night_cycle();
+            run_full_game(&game);
+        }
+        Commands::ImportCast { file, game_id, run } => {
+            let cast = Cast::load(Path::new(&file)).unwrap_or_else(|e| panic!("Couldn't read {}: {}", file, e));
+            let game = match game_id {
+                Some(game_id) => get_game(&game_id).expect("Game not found"),
+                None => create_game(None),
+            };
+            let base_dir = Path::new(&file).parent().unwrap_or(Path::new("."));
+            let (tributes, warnings) = cast.import(&game, base_dir);
+            for warning in warnings {
+                println!("Warning: {}", warning);
+            }
+            println!("{} tributes imported into {}", tributes.len(), game.name);
+            match cast.install_event_pack(&game) {
+                Some(Ok(path)) => println!("{} events installed in {}", cast.events.len(), path),
+                Some(Err(errors)) => println!("Events not installed: {}", errors.join("; ")),
+                None => {}
+            }
+            if run {
+                run_full_game(&game);
+                let winners = Game::from(game.clone()).winners();
+                match winners.is_empty() {
+                    false => println!("The winner is {}", winners.iter().map(|w| w.name.clone()).collect::<Vec<String>>().join(" and ")),
+                    true => println!("No one wins"),
+                }
             }
-            game.end();
         }
-        Commands::ShowGameLog { game_id } => {
+        Commands::ShowGameLog { game_id, kind, locale } => {
             let game = get_game(&game_id).expect("Game not found");
-            for log in game.logs() {
-                println!("{:?}", log);
+            let logs = if kind.is_empty() {
+                game.logs()
+            } else {
+                game.logs_by_kind(&kind.iter().map(String::as_str).collect::<Vec<&str>>())
+            };
+            match locale {
+                Some(locale) => {
+                    let locale = parse_locale(&locale);
+                    for log in logs {
+                        match log.game_message() {
+                            Some(message) => println!("{}", message.localized(&locale)),
+                            None => println!("{}", log.message),
+                        }
+                    }
+                }
+                None => {
+                    for log in logs {
+                        println!("{:?}", log);
+                    }
+                }
             }
         }
         Commands::LogTributes { game_id } => {
@@ -255,10 +632,235 @@ pub fn parse() {
                 }
             }
         }
-        Commands::Gui => {
+        Commands::ShowEventPacks => {
+            for (path, pack) in load_packs(Path::new(EVENT_PACK_DIR)) {
+                match pack {
+                    Ok(pack) => println!("{}: {} ({} events)", path, pack.name, pack.events.len()),
+                    Err(errors) => println!("{}: skipped, {}", path, errors.join("; ")),
+                }
+            }
+        }
+        Commands::CheckEventPack { file } => {
+            match EventPack::load(Path::new(&file)) {
+                Ok(pack) => println!("{} is ready with {} events", pack.name, pack.events.len()),
+                Err(errors) => {
+                    for error in errors {
+                        println!("{}", error);
+                    }
+                }
+            }
+        }
+        Commands::AddSpectator { name } => {
+            if get_spectator(&name).is_some() {
+                println!("{} is already a spectator", name);
+                return;
+            }
+            let spectator = Spectator::create(&name);
+            println!("{} joins with {} to bet", spectator.name, spectator.balance);
+        }
+        Commands::PlaceBet { game_id, spectator, tribute_id, wager, stake } => {
+            let game = get_game(&game_id).expect("Game not found");
+            let mut spectator = get_spectator(&spectator).expect("Spectator not found");
+            let tribute = TributeActor::from(get_tribute(&tribute_id));
+            let wager = Wager::from_str(&wager).unwrap_or_else(|e| panic!("{}", e));
+            match place_bet(&game, &mut spectator, &tribute, wager, stake) {
+                Ok(bet) => println!(
+                    "{} bets {} on {} ({}) at {:.2}, {} left",
+                    spectator.name, bet.stake, tribute.name, wager, bet.odds, spectator.balance
+                ),
+                Err(error) => println!("Bet not placed: {}", error),
+            }
+        }
+        Commands::ShowOdds { game_id } => {
+            let game = get_game(&game_id).expect("Game not found");
+            for (tribute, winner, first_death) in odds_board(&game) {
+                match first_death {
+                    Some(first_death) => println!("{}: {:.2} to win, {:.2} to die first", tribute.name, winner, first_death),
+                    None => println!("{}: {:.2} to win", tribute.name, winner),
+                }
+            }
+        }
+        Commands::ShowBets { game_id } => {
+            let game = get_game(&game_id).expect("Game not found");
+            for bet in Bet::get_all_for_game(game.id) {
+                let spectator = get_spectator_by_id(bet.spectator_id);
+                let tribute = get_tribute_by_id(bet.tribute_id);
+                let result = match bet.payout {
+                    Some(payout) => format!("paid {}", payout),
+                    None => "open".to_string(),
+                };
+                println!(
+                    "Day {}: {} bets {} on {} ({}) at {:.2}, {}",
+                    bet.day, spectator.name, bet.stake, tribute.name, bet.kind, bet.odds, result
+                );
+            }
+        }
+        Commands::Standings => {
+            for (place, spectator) in get_standings().iter().enumerate() {
+                println!("{}. {}: {}", place + 1, spectator.name, spectator.balance);
+            }
+        }
+        Commands::Simulate { games, seed, json, tuning } => {
+            let tuning = match tuning {
+                Some(file) => load_tuning(&file),
+                None => Tuning::global().clone(),
+            };
+            let report = simulate(games, seed.unwrap_or_else(rand::random), &tuning);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report).expect("Error serializing report"));
+            } else {
+                print!("{}", report);
+            }
+        }
+        Commands::SetTuning { game_id, file } => {
+            let game = get_game(&game_id).expect("Game not found");
+            let tuning = file.map(|file| load_tuning(&file));
+            game.set_tuning(tuning.as_ref())
+                .unwrap_or_else(|errors| panic!("Couldn't use the tuning: {}", errors.join("; ")));
+            match tuning {
+                Some(_) => println!("{} has its own tuning", game.name),
+                None => println!("{} uses the global tuning", game.name),
+            }
+        }
+        Commands::ShowTuning { game_id } => {
+            let tuning = match game_id {
+                Some(game_id) => get_game(&game_id).expect("Game not found").tuning(),
+                None => Tuning::global().clone(),
+            };
+            println!("{}", serde_json::to_string_pretty(&tuning).expect("Error serializing tuning"));
+        }
+        Commands::Gui { locale } => {
             // run hangry bin
             println!("Launching GUI...");
-            let _ = std::process::Command::new("hangry").spawn();
+            let mut command = std::process::Command::new("hangry");
+            if let Some(locale) = locale {
+                command.env("HANGRY_LOCALE", parse_locale(&locale).code());
+            }
+            let _ = command.spawn();
         }
     }
 }
+
+fn load_tuning(file: &str) -> Tuning {
+    Tuning::load(Path::new(file)).unwrap_or_else(|errors| panic!("Couldn't use {}: {}", file, errors.join("; ")))
+}
+
+/// Creates a game set up as asked. The options are read before the game is created, and a
+/// game that can't be set up that way is deleted again rather than left half made.
+fn create_set_up_game(
+    locale: Option<String>,
+    tributes: Option<i32>,
+    districts: Option<i32>,
+    ruleset: Option<String>,
+    win_condition: Option<String>,
+    alliances: Vec<String>,
+) -> GameModel {
+    let locale = locale.map(|locale| parse_locale(&locale));
+    let ruleset = ruleset.map(|ruleset| parse_ruleset(&ruleset));
+    let win_condition = win_condition.map(|w| parse_win_condition(&w));
+    let alliances = alliances.iter().map(|a| parse_alliance(a)).collect::<Vec<Vec<i32>>>();
+
+    let game = create_game(None);
+    if let Some(locale) = locale {
+        game.set_locale(&locale);
+    }
+    set_roster_size(&game, tributes, districts)
+        .and_then(|game| set_ruleset(&game, ruleset))
+        .and_then(|game| set_win_condition(&game, win_condition, alliances))
+        .unwrap_or_else(|error| {
+            Game::delete(game.id);
+            panic!("{}", error)
+        })
+}
+
+/// Resizes the game if asked to and returns it reloaded. Without `districts`, the game keeps
+/// its districts unless that would leave fewer than two tributes in each.
+fn set_roster_size(game: &GameModel, tributes: Option<i32>, districts: Option<i32>) -> Result<GameModel, String> {
+    if tributes.is_some() || districts.is_some() {
+        let tributes = tributes.unwrap_or(game.roster_size);
+        let districts = districts.unwrap_or(game.districts.min(tributes / 2).max(1));
+        game.set_roster_size(tributes, districts)?;
+    }
+    Ok(get_game_by_id(game.id).expect("Game not found"))
+}
+
+/// Switches the game's rules if asked to and returns it reloaded.
+fn set_ruleset(game: &GameModel, ruleset: Option<Ruleset>) -> Result<GameModel, String> {
+    if let Some(ruleset) = ruleset {
+        game.set_ruleset(&ruleset)?;
+    }
+    Ok(get_game_by_id(game.id).expect("Game not found"))
+}
+
+/// Changes how the game is won if asked to and returns it reloaded.
+fn set_win_condition(game: &GameModel, win_condition: Option<WinCondition>, alliances: Vec<Vec<i32>>) -> Result<GameModel, String> {
+    if win_condition.is_some() || !alliances.is_empty() {
+        let win_condition = win_condition.unwrap_or(game.win_condition());
+        game.set_win_condition(&win_condition, &alliances)?;
+    }
+    Ok(get_game_by_id(game.id).expect("Game not found"))
+}
+
+fn print_feasts(game: &GameModel) {
+    let feasts = game.feasts();
+    if feasts.is_empty() {
+        println!("{} has no feasts", game.name);
+        return;
+    }
+    println!("{} feasts on:", game.name);
+    for feast in feasts {
+        println!("  Day {}, backpacks: {}", feast.day, feast.backpacks);
+    }
+}
+
+fn run_full_game(game: &GameModel) {
+    game.start();
+    let mut game_actor = Game::from(game.clone());
+    while !game_actor.is_over() {
+        game_actor.run_day_night_cycle();
+    }
+    game.end();
+}
+
+fn parse_pronouns(pronouns: &str) -> Pronouns {
+    Pronouns::from_str(pronouns)
+        .unwrap_or_else(|_| panic!("Unknown pronouns {}, try she/her, he/him, they/them or subject/object/possessive/reflexive", pronouns))
+}
+
+fn parse_preset(preset: &str) -> Preset {
+    Preset::from_str(preset).unwrap_or_else(|_| panic!("Unknown preset {}, try balanced, brawler, sneak or survivor", preset))
+}
+
+fn parse_ruleset(ruleset: &str) -> Ruleset {
+    Ruleset::from_str(ruleset).unwrap_or_else(|_| {
+        panic!("Unknown ruleset {}, try standard, victors-only, double-tributes, co-victors, no-sponsors or night-hazards", ruleset)
+    })
+}
+
+fn parse_win_condition(win_condition: &str) -> WinCondition {
+    WinCondition::from_str(win_condition).unwrap_or_else(|_| {
+        panic!("Unknown win condition {}, try last-standing, last-district, last-alliance or time-limit=<days>", win_condition)
+    })
+}
+
+fn parse_alliance(alliance: &str) -> Vec<i32> {
+    alliance.split(',')
+        .map(|district| district.trim().parse().unwrap_or_else(|_| panic!("{} isn't a district number", district)))
+        .collect()
+}
+
+fn parse_backpacks(backpacks: &str) -> Backpacks {
+    Backpacks::from_str(backpacks).unwrap_or_else(|_| panic!("Unknown backpacks {}, try none, tribute or district", backpacks))
+}
+
+fn parse_area(area: &str) -> Area {
+    Area::from_str(area).unwrap_or_else(|| panic!("Unknown area {}, try cornucopia, northeast, northwest, southeast or southwest", area))
+}
+
+fn parse_rule_change(rule_change: &str) -> RuleChange {
+    RuleChange::from_str(rule_change).unwrap_or_else(|_| panic!("Unknown rule change {}, try co-victors", rule_change))
+}
+
+fn parse_locale(locale: &str) -> Locale {
+    Locale::from_str(locale).unwrap_or_else(|_| panic!("Unknown locale {}, try en, es or de", locale))
+}
@@ -0,0 +1,177 @@
+use crate::items::{Attribute, Item, ItemType};
+use crate::tributes::actors::Tribute;
+
+/// What foraging turns up besides food, for making things out of.
+pub const MATERIALS: [&str; 4] = ["sticks", "stones", "branches", "rope"];
+
+/// What a recipe leans on: knowing how, or a steady hand.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Skill {
+    Intelligence,
+    Dexterity,
+}
+
+impl Skill {
+    pub fn of(&self, tribute: &Tribute) -> i32 {
+        match self {
+            Skill::Intelligence => tribute.intelligence.unwrap_or(0),
+            Skill::Dexterity => tribute.dexterity.unwrap_or(0),
+        }
+    }
+}
+
+/// How to make something out of what's lying around the arena.
+#[derive(Debug)]
+pub struct Recipe {
+    pub name: &'static str,
+    /// Each ingredient by name, and how many it takes.
+    pub ingredients: &'static [(&'static str, i32)],
+    pub skill: Skill,
+    /// The least skill it takes to pull off at all.
+    pub minimum: i32,
+    pub item_type: ItemType,
+    pub attribute: Attribute,
+    /// The effect of the worst and the best it can come out.
+    pub effect: (i32, i32),
+}
+
+pub const RECIPES: [Recipe; 4] = [
+    Recipe {
+        name: "spear",
+        ingredients: &[("sticks", 1), ("stones", 1)],
+        skill: Skill::Dexterity,
+        minimum: 30,
+        item_type: ItemType::Weapon,
+        attribute: Attribute::Strength,
+        effect: (3, 7),
+    },
+    Recipe {
+        name: "sling",
+        ingredients: &[("rope", 1), ("stones", 2)],
+        skill: Skill::Dexterity,
+        minimum: 45,
+        item_type: ItemType::Weapon,
+        attribute: Attribute::Strength,
+        effect: (2, 6),
+    },
+    Recipe {
+        name: "wicker shield",
+        ingredients: &[("branches", 3)],
+        skill: Skill::Intelligence,
+        minimum: 25,
+        item_type: ItemType::Weapon,
+        attribute: Attribute::Defense,
+        effect: (2, 5),
+    },
+    // Not something to fight with, so it's kept with the materials. Its effect is how hard it bites.
+    Recipe {
+        name: "snare",
+        ingredients: &[("rope", 1), ("branches", 2)],
+        skill: Skill::Intelligence,
+        minimum: 40,
+        item_type: ItemType::Material,
+        attribute: Attribute::Strength,
+        effect: (10, 25),
+    },
+];
+
+impl Recipe {
+    /// Whether the tribute knows how, and is carrying enough of everything it takes.
+    pub fn can_make(&self, tribute: &Tribute, held: &[Item]) -> bool {
+        self.skill.of(tribute) >= self.minimum && self.ingredients.iter().all(|(ingredient, count)| {
+            held.iter().filter(|item| item.name == *ingredient).map(|item| item.quantity).sum::<i32>() >= *count
+        })
+    }
+
+    /// How well it comes out, from 0 to 1: mostly the roll of a d20, plus how far their skill clears the minimum.
+    pub fn quality(&self, skill: i32, roll: i32) -> f64 {
+        let knack = ((skill - self.minimum) as f64 / 100.0).clamp(0.0, 0.3);
+        (roll as f64 / 20.0 * 0.7 + knack).clamp(0.0, 1.0)
+    }
+
+    pub fn effect_for(&self, quality: f64) -> i32 {
+        let (worst, best) = self.effect;
+        worst + ((best - worst) as f64 * quality).round() as i32
+    }
+
+    /// A name that says how it came out, e.g. "crude spear".
+    pub fn name_for(&self, quality: f64) -> String {
+        let adjective = match quality {
+            q if q < 0.35 => "crude",
+            q if q < 0.7 => "sturdy",
+            _ => "fine",
+        };
+        format!("{} {}", adjective, self.name)
+    }
+
+    /// Every ingredient it uses up, once for each of them it takes.
+    pub fn components(&self) -> Vec<String> {
+        self.ingredients.iter()
+            .flat_map(|(ingredient, count)| std::iter::repeat_n(ingredient.to_string(), *count as usize))
+            .collect()
+    }
+}
+
+/// What the tribute would make out of what they're carrying: a weapon if they have none,
+/// otherwise whatever they're best at.
+pub fn pick_recipe(tribute: &Tribute, held: &[Item], armed: bool) -> Option<&'static Recipe> {
+    let makeable = RECIPES.iter().filter(|recipe| recipe.can_make(tribute, held));
+    match armed {
+        false => makeable.max_by_key(|recipe| (recipe.attribute == Attribute::Strength && recipe.item_type == ItemType::Weapon, recipe.effect.1)),
+        true => makeable.max_by_key(|recipe| recipe.skill.of(tribute) - recipe.minimum),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn material(name: &str, quantity: i32) -> Item {
+        Item {
+            id: None,
+            name: name.to_string(),
+            item_type: ItemType::Material,
+            game_id: None,
+            area_id: None,
+            tribute_id: None,
+            quantity,
+            attribute: Attribute::Strength,
+            effect: 0,
+            for_tribute_id: None,
+            for_district: None,
+            components: vec![],
+        }
+    }
+
+    #[test]
+    fn recipes_need_skill_and_ingredients() {
+        let mut tribute = Tribute::new("Katniss".to_string(), None, None);
+        tribute.dexterity = Some(50);
+        tribute.intelligence = Some(20);
+        let held = vec![material("sticks", 1), material("stones", 1), material("branches", 2), material("rope", 1)];
+
+        let spear = &RECIPES[0];
+        let snare = &RECIPES[3];
+        assert!(spear.can_make(&tribute, &held));
+        assert!(!snare.can_make(&tribute, &held));
+        tribute.intelligence = Some(55);
+        assert!(snare.can_make(&tribute, &held));
+        assert!(!spear.can_make(&tribute, &held[1..]));
+
+        assert_eq!(pick_recipe(&tribute, &held, false).unwrap().name, "spear");
+        assert_eq!(pick_recipe(&tribute, &held, true).unwrap().name, "spear");
+        assert_eq!(snare.components(), vec!["rope", "branches", "branches"]);
+    }
+
+    #[test]
+    fn quality_sets_the_stats_and_name() {
+        let spear = &RECIPES[0];
+        let worst = spear.quality(spear.minimum, 1);
+        let best = spear.quality(100, 20);
+        assert_eq!(best, 1.0);
+        assert_eq!(spear.effect_for(worst), 3);
+        assert_eq!(spear.effect_for(best), 7);
+        assert_eq!(spear.name_for(worst), "crude spear");
+        assert_eq!(spear.name_for(best), "fine spear");
+    }
+}
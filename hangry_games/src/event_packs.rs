@@ -0,0 +1,464 @@
+use crate::areas::Area;
+use crate::i18n::Locale;
+use crate::items::Item;
+use crate::messages::GameMessage;
+use crate::models::{create_full_log, get_game_by_id, update_tribute};
+use crate::tributes::actors::{remember_killer, Tribute};
+use crate::tributes::contagion::{is_contagious, proximity, spread_chance};
+use crate::tributes::statuses::TributeStatus;
+use crate::tuning::Tuning;
+use rand::prelude::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Every `*.json` file in here is loaded as an event pack when the simulation first needs one.
+/// Subdirectories, like the bundled `examples/`, are not.
+pub const EVENT_PACK_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/event_packs");
+
+/// A user-authored collection of flavor events, drawn alongside the built-in `TributeEvent`s.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EventPack {
+    pub name: String,
+    pub events: Vec<CustomEvent>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Day,
+    Night,
+}
+
+/// What a participant needs to be cast in an event. Participants are numbered from 1.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Requirement {
+    pub tribute: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// An item name, or "weapon", "shield" or "consumable" for any item of that kind.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Effect {
+    Damage { tribute: usize, amount: i32 },
+    Status { tribute: usize, status: String },
+    /// Hands over the named item, or any item if none is named.
+    TransferItem {
+        from: usize,
+        to: usize,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        item: Option<String>,
+    },
+    /// Kills the participant, crediting `killer` if there is one.
+    Death {
+        tribute: usize,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        killer: Option<usize>,
+    },
+}
+
+/// An event like `{1} trips over {2}'s snare and loses {1.possessive} boot`.
+/// Placeholders are `{N}` for a name, `{N.subject}`, `{N.object}`, `{N.possessive}` and
+/// `{N.reflexive}` for pronouns, and `{area}` for where it happens.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CustomEvent {
+    pub name: String,
+    pub text: String,
+    /// The text in other languages, keyed by locale code.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub translations: HashMap<String, String>,
+    #[serde(default = "default_participants")]
+    pub participants: usize,
+    /// How likely the event is next to a single built-in event, which weighs 1.
+    #[serde(default = "default_weight")]
+    pub weight: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phase: Option<Phase>,
+    /// Where the event can happen. Empty means anywhere.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub areas: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub requires: Vec<Requirement>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub effects: Vec<Effect>,
+}
+
+fn default_participants() -> usize { 1 }
+
+fn default_weight() -> f64 { 1.0 }
+
+impl EventPack {
+    /// Reads and validates a pack file.
+    pub fn load(path: &Path) -> Result<EventPack, Vec<String>> {
+        let contents = fs::read_to_string(path).map_err(|e| vec![e.to_string()])?;
+        let pack: EventPack = serde_json::from_str(&contents).map_err(|e| vec![e.to_string()])?;
+        pack.validate()?;
+        Ok(pack)
+    }
+
+    /// Every problem in the pack, prefixed with the event's name.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let errors = self.events.iter()
+            .flat_map(|event| event.problems().into_iter().map(move |p| format!("{}: {}", event.name, p)))
+            .collect::<Vec<String>>();
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+}
+
+/// Packs in here are only drawn from in game `game_id`, like the events of a cast imported into it.
+pub fn game_pack_dir(game_id: i32) -> String {
+    format!("{}/games/{}", EVENT_PACK_DIR, game_id)
+}
+
+/// Every event from the packs in `EVENT_PACK_DIR`. Broken packs are reported and skipped.
+pub fn loaded_events() -> &'static Vec<CustomEvent> {
+    static EVENTS: OnceLock<Vec<CustomEvent>> = OnceLock::new();
+    EVENTS.get_or_init(|| usable_events(Path::new(EVENT_PACK_DIR)))
+}
+
+/// Every event from the packs in `dir`, reporting and skipping broken ones.
+fn usable_events(dir: &Path) -> Vec<CustomEvent> {
+    load_packs(dir).into_iter()
+        .filter_map(|(path, pack)| match pack {
+            Ok(pack) => Some(pack.events),
+            Err(errors) => {
+                eprintln!("Skipping event pack {}: {}", path, errors.join("; "));
+                None
+            }
+        })
+        .flatten()
+        .collect()
+}
+
+/// Every `*.json` pack in `dir` by file name, loaded or not.
+pub fn load_packs(dir: &Path) -> Vec<(String, Result<EventPack, Vec<String>>)> {
+    let Ok(entries) = fs::read_dir(dir) else { return vec![] };
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect::<Vec<_>>();
+    paths.sort();
+    paths.into_iter()
+        .map(|path| (path.display().to_string(), EventPack::load(&path)))
+        .collect()
+}
+
+impl CustomEvent {
+    /// The events from the packs in `game_pack_dir(game_id)`.
+    pub fn for_game(game_id: i32) -> Vec<CustomEvent> {
+        usable_events(Path::new(&game_pack_dir(game_id)))
+    }
+
+    /// Picks a loaded event, or one of `game_events`, for `lead` and casts the rest of its
+    /// participants from the lead's area.
+    /// `builtin_count` built-in events compete for the draw; `None` means one of those won.
+    pub fn draw(lead: &Tribute, day: bool, builtin_count: usize, game_events: &[CustomEvent]) -> Option<(CustomEvent, Vec<Tribute>)> {
+        let candidates = loaded_events().iter()
+            .chain(game_events)
+            .filter(|event| event.fits(lead, 1, day))
+            .collect::<Vec<&CustomEvent>>();
+        let total = builtin_count as f64 + candidates.iter().map(|e| e.weight).sum::<f64>();
+        let mut roll = rand::thread_rng().gen_range(0.0..total) - builtin_count as f64;
+        for event in candidates {
+            roll -= event.weight;
+            if roll < 0.0 {
+                return event.cast(lead, day).map(|participants| (event.clone(), participants));
+            }
+        }
+        None
+    }
+
+    fn problems(&self) -> Vec<String> {
+        let mut problems = vec![];
+        let slot = |n: usize| (1..=self.participants).contains(&n);
+        if self.participants == 0 {
+            problems.push("needs at least one participant".to_string());
+        }
+        if self.weight <= 0.0 {
+            problems.push("weight must be positive".to_string());
+        }
+        for area in &self.areas {
+            if Area::from_str(area).is_none() {
+                problems.push(format!("unknown area {}", area));
+            }
+        }
+        for requirement in &self.requires {
+            if !slot(requirement.tribute) {
+                problems.push(format!("requirement for missing participant {}", requirement.tribute));
+            }
+            if let Some(status) = &requirement.status {
+                if TributeStatus::from_str(status).is_err() {
+                    problems.push(format!("unknown status {}", status));
+                }
+            }
+        }
+        for effect in &self.effects {
+            let participants = match effect {
+                Effect::Damage { tribute, .. } => vec![*tribute],
+                Effect::Status { tribute, status } => {
+                    if TributeStatus::from_str(status).is_err() {
+                        problems.push(format!("unknown status {}", status));
+                    }
+                    vec![*tribute]
+                }
+                Effect::TransferItem { from, to, .. } => {
+                    if from == to {
+                        problems.push(format!("participant {} hands an item to themselves", from));
+                    }
+                    vec![*from, *to]
+                }
+                Effect::Death { tribute, killer } => vec![*tribute, killer.unwrap_or(*tribute)],
+            };
+            for n in participants.into_iter().filter(|n| !slot(*n)) {
+                problems.push(format!("effect on missing participant {}", n));
+            }
+        }
+        for text in std::iter::once(&self.text).chain(self.translations.values()) {
+            for placeholder in placeholders(text) {
+                match parse_placeholder(placeholder) {
+                    Some((Some(n), _)) if !slot(n) => problems.push(format!("{{{}}} is not a participant", placeholder)),
+                    None => problems.push(format!("unknown placeholder {{{}}}", placeholder)),
+                    _ => {}
+                }
+            }
+        }
+        problems
+    }
+
+    /// Can `tribute` fill participant slot `slot` right now?
+    fn fits(&self, tribute: &Tribute, slot: usize, day: bool) -> bool {
+        let phase = if day { Phase::Day } else { Phase::Night };
+        if self.phase.is_some_and(|p| p != phase) || !tribute.is_alive() {
+            return false;
+        }
+        if slot == 1 && !self.areas.is_empty() {
+            let here = tribute.area.clone().unwrap_or_default();
+            if !self.areas.iter().any(|a| Area::from_str(a) == Some(here.clone())) {
+                return false;
+            }
+        }
+        self.requires.iter().filter(|r| r.tribute == slot).all(|requirement| {
+            let status_ok = requirement.status.as_ref()
+                .is_none_or(|s| TributeStatus::from_str(s).is_ok_and(|s| s == tribute.status));
+            let item_ok = requirement.item.as_ref()
+                .is_none_or(|name| tribute.items().iter().any(|item| item_matches(item, name)));
+            status_ok && item_ok
+        })
+    }
+
+    /// The lead plus enough tributes from the lead's area to fill every slot, if there are any.
+    fn cast(&self, lead: &Tribute, day: bool) -> Option<Vec<Tribute>> {
+        let mut participants = vec![lead.clone()];
+        if self.participants == 1 {
+            return Some(participants);
+        }
+
+        let mut others = lead.area.clone()?.tributes(lead.game_id?).into_iter()
+            .filter(|t| t.id != lead.id)
+            .collect::<Vec<Tribute>>();
+        others.shuffle(&mut rand::thread_rng());
+        for slot in 2..=self.participants {
+            let index = others.iter().position(|t| self.fits(t, slot, day))?;
+            participants.push(others.remove(index));
+        }
+        Some(participants)
+    }
+
+    /// The event's text in `locale`, with the participants filled in.
+    pub fn render(&self, participants: &[Tribute], locale: &Locale) -> String {
+        let template = self.translations.get(locale.code()).unwrap_or(&self.text);
+        let mut text = template.clone();
+        for placeholder in placeholders(template) {
+            let value = match parse_placeholder(placeholder) {
+                Some((None, _)) => participants.first()
+                    .and_then(|t| t.area.clone())
+                    .map(|area| area.localized(locale)),
+                Some((Some(n), form)) => participants.get(n - 1).map(|tribute| {
+                    let pronouns = tribute.pronouns.clone().unwrap_or_default();
+                    match form {
+                        Some("subject") => pronouns.subject().to_string(),
+                        Some("object") => pronouns.object().to_string(),
+                        Some("possessive") => pronouns.possessive().to_string(),
+                        Some("reflexive") => pronouns.reflexive().to_string(),
+                        _ => tribute.name.clone(),
+                    }
+                }),
+                None => None,
+            };
+            if let Some(value) = value {
+                text = text.replacen(&format!("{{{}}}", placeholder), &value, 1);
+            }
+        }
+        text
+    }
+
+    /// Logs the event, applies its effects and saves every participant.
+    pub fn happens(&self, participants: &mut [Tribute]) {
+        let lead = participants[0].clone();
+        create_full_log(
+            lead.game_id.unwrap(),
+            GameMessage::CustomEvent(self.clone(), participants.to_vec()),
+            lead.area.as_ref().map(|a| a.id()),
+            lead.id,
+            participants.get(1).map(|_| "Tribute".to_string()),
+            participants.get(1).and_then(|t| t.id),
+        );
+
+        for effect in &self.effects {
+            match effect {
+                Effect::Damage { tribute, amount } => {
+                    let tribute = &mut participants[tribute - 1];
+                    tribute.takes_physical_damage(*amount);
+                    if tribute.health <= 0 && tribute.status != TributeStatus::RecentlyDead {
+                        tribute.killed_by = Some(self.name.clone());
+                        tribute.dies();
+                    }
+                }
+                Effect::Status { tribute, status } => {
+                    let tribute = &mut participants[tribute - 1];
+                    if tribute.is_alive() {
+                        tribute.status = TributeStatus::from_str(status).expect("Unknown status in event pack");
+                    }
+                }
+                Effect::TransferItem { from, to, item } => {
+                    let recipient = participants[to - 1].id;
+                    let items = participants[from - 1].items();
+                    let given = items.iter()
+                        .find(|i| item.as_ref().is_none_or(|name| item_matches(i, name)));
+                    if let Some(given) = given {
+                        let mut given = given.clone();
+                        given.tribute_id = recipient;
+                        given.save();
+                        passes_on_illness(participants, from - 1, to - 1);
+                    }
+                }
+                Effect::Death { tribute, killer } => {
+                    let victim = &mut participants[tribute - 1];
+                    if !victim.is_alive() {
+                        continue;
+                    }
+                    victim.health = 0;
+                    victim.dies();
+                    victim.killed_by = Some(self.name.clone());
+                    if let Some(killer) = killer.filter(|k| k != tribute) {
+                        participants[tribute - 1].killed_by = Some(participants[killer - 1].name.clone());
+                        let victim = participants[tribute - 1].clone();
+                        let killer = &mut participants[killer - 1];
+                        killer.kills = Some(killer.kills.unwrap_or(0) + 1);
+                        remember_killer(killer, &victim);
+                    }
+                }
+            }
+        }
+
+        for tribute in participants.iter() {
+            update_tribute(tribute.id.unwrap(), tribute.clone().into());
+        }
+    }
+}
+
+/// Whoever's handed something by a contagious tribute gets as close as allies do, and may catch it.
+fn passes_on_illness(participants: &mut [Tribute], giver: usize, receiver: usize) {
+    let disease = participants[giver].status.clone();
+    if !is_contagious(&disease) {
+        return;
+    }
+    let base = get_game_by_id(participants[giver].game_id.unwrap())
+        .map(|game| game.tuning().contagion_chance)
+        .unwrap_or(Tuning::global().contagion_chance);
+    let receiver = &mut participants[receiver];
+    let mut rng = rand::thread_rng();
+    if rng.gen_bool(spread_chance(base, proximity(receiver, true), receiver)) {
+        receiver.catches(disease, &mut rng);
+    }
+}
+
+fn item_matches(item: &Item, name: &str) -> bool {
+    match name.to_lowercase().as_str() {
+        "weapon" => item.is_weapon(),
+        "shield" => item.is_defensive(),
+        "consumable" => item.is_consumable(),
+        name => item.name.to_lowercase() == name,
+    }
+}
+
+/// The text between each pair of braces in `template`.
+fn placeholders(template: &str) -> Vec<&str> {
+    template.split('{').skip(1).filter_map(|part| part.split_once('}').map(|(p, _)| p)).collect()
+}
+
+/// `area` is `(None, None)`, `2.possessive` is `(Some(2), Some("possessive"))`.
+fn parse_placeholder(placeholder: &str) -> Option<(Option<usize>, Option<&str>)> {
+    if placeholder == "area" {
+        return Some((None, None));
+    }
+    let (number, form) = match placeholder.split_once('.') {
+        Some((number, form)) => (number, Some(form)),
+        None => (placeholder, None),
+    };
+    let number = number.parse::<usize>().ok().filter(|n| *n > 0)?;
+    match form {
+        None | Some("subject" | "object" | "possessive" | "reflexive") => Some((Some(number), form)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tributes::pronouns::Pronouns;
+
+    fn example_pack() -> EventPack {
+        serde_json::from_str(include_str!("../event_packs/examples/running-jokes.json")).unwrap()
+    }
+
+    #[test]
+    fn example_pack_is_valid() {
+        let pack = example_pack();
+        assert!(pack.validate().is_ok());
+        assert_eq!(pack.events[0].participants, 1);
+        assert_eq!(pack.events[0].phase, Some(Phase::Day));
+    }
+
+    #[test]
+    fn validation_finds_missing_participants() {
+        let mut pack = example_pack();
+        pack.events[1].text = "{3} and {1.nickname}".to_string();
+        pack.events[1].effects.push(Effect::Death { tribute: 1, killer: Some(4) });
+        let errors = pack.validate().unwrap_err();
+        assert_eq!(errors, vec![
+            "snare: effect on missing participant 4",
+            "snare: {3} is not a participant",
+            "snare: unknown placeholder {1.nickname}",
+        ]);
+    }
+
+    #[test]
+    fn validation_rejects_handing_items_to_yourself() {
+        let mut pack = example_pack();
+        pack.events[3].effects = vec![Effect::TransferItem { from: 2, to: 2, item: None }];
+        let errors = pack.validate().unwrap_err();
+        assert_eq!(errors, vec![format!("{}: participant 2 hands an item to themselves", pack.events[3].name)]);
+    }
+
+    #[test]
+    fn render_fills_in_participants() {
+        let event = example_pack().events[1].clone();
+        let mut katniss = Tribute::new("Katniss".to_string(), Some(12), None);
+        katniss.pronouns = Some(Pronouns::She);
+        let cato = Tribute::new("Cato".to_string(), Some(2), None);
+        let rendered = event.render(&[katniss.clone(), cato.clone()], &Locale::English);
+        assert_eq!(rendered, "🪤 Cato walks straight into Katniss's snare in The Cornucopia.");
+
+        let event = example_pack().events[3].clone();
+        assert_eq!(event.render(&[katniss, cato], &Locale::English), "🤝 Katniss hands Cato a snack from her pack.");
+    }
+}
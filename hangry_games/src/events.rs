@@ -1,9 +1,13 @@
 use crate::animals::Animal;
+use crate::i18n::{translate, translate_attribute, Locale};
+use crate::tributes::statuses::TributeStatus;
+use fluent_bundle::FluentArgs;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::str::FromStr;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum AreaEvent {
     Wildfire,
     Flood,
@@ -58,6 +62,21 @@ impl AreaEvent {
         }
     }
 
+    /// The event's name in `locale`.
+    pub fn localized(&self, locale: &Locale) -> String {
+        translate(locale, &format!("event-{}", self.as_str()), None)
+    }
+
+    /// The event with an indefinite article, e.g. "an earthquake".
+    pub fn localized_indefinite(&self, locale: &Locale) -> String {
+        translate_attribute(locale, &format!("event-{}", self.as_str()), "indefinite", None)
+    }
+
+    /// The event with a definite article, e.g. "the earthquake".
+    pub fn localized_definite(&self, locale: &Locale) -> String {
+        translate_attribute(locale, &format!("event-{}", self.as_str()), "definite", None)
+    }
+
     pub fn random() -> AreaEvent {
         let mut rng = rand::thread_rng();
         let events = vec![
@@ -72,9 +91,22 @@ impl AreaEvent {
         let index = rng.gen_range(0..events.len());
         events[index].clone()
     }
+
+    /// What the event leaves a tribute lucky enough to survive it with.
+    pub fn status(&self) -> TributeStatus {
+        match self {
+            AreaEvent::Wildfire => TributeStatus::Burned,
+            AreaEvent::Flood => TributeStatus::Drowned,
+            AreaEvent::Earthquake => TributeStatus::Buried,
+            AreaEvent::Avalanche => TributeStatus::Buried,
+            AreaEvent::Blizzard => TributeStatus::Frozen,
+            AreaEvent::Landslide => TributeStatus::Buried,
+            AreaEvent::Heatwave => TributeStatus::Overheated,
+        }
+    }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum TributeEvent {
     AnimalAttack(Animal),
     Dysentery,
@@ -159,11 +191,22 @@ impl TributeEvent {
         }
     }
 
-    pub fn random() -> TributeEvent {
-        let mut rng = rand::thread_rng();
-        let animal = Animal::random();
-        let events = vec![
-            TributeEvent::AnimalAttack(animal),
+    /// The event's name in `locale`.
+    pub fn localized(&self, locale: &Locale) -> String {
+        match self {
+            TributeEvent::AnimalAttack(animal) => {
+                let mut args = FluentArgs::new();
+                args.set("animal", animal.localized(locale, 1));
+                translate(locale, "event-animal-attack", Some(&args))
+            }
+            _ => translate(locale, &format!("event-{}", self.as_str().replace(' ', "-")), None),
+        }
+    }
+
+    /// One of each event, with a random animal for the attack.
+    pub fn all() -> Vec<TributeEvent> {
+        vec![
+            TributeEvent::AnimalAttack(Animal::random()),
             TributeEvent::Dysentery,
             TributeEvent::LightningStrike,
             TributeEvent::Hypothermia,
@@ -175,7 +218,12 @@ impl TributeEvent {
             TributeEvent::Infection,
             TributeEvent::Drowning,
             TributeEvent::Burn,
-        ];
+        ]
+    }
+
+    pub fn random() -> TributeEvent {
+        let mut rng = rand::thread_rng();
+        let events = TributeEvent::all();
         let index = rng.gen_range(0..events.len());
         events[index].clone()
     }
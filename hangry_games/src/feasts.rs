@@ -0,0 +1,132 @@
+use crate::tributes::actors::Tribute;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Who the gamemakers leave backpacks for at a feast.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Backpacks {
+    /// Just the usual restock of the Cornucopia.
+    #[default]
+    None,
+    /// A backpack labeled for each tribute.
+    PerTribute,
+    /// A backpack labeled for each district, for whichever district mate reaches it first.
+    PerDistrict,
+}
+
+impl Backpacks {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Backpacks::None => "none",
+            Backpacks::PerTribute => "tribute",
+            Backpacks::PerDistrict => "district",
+        }
+    }
+}
+
+impl FromStr for Backpacks {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace(['-', '_'], " ").as_str() {
+            "none" | "no" => Ok(Backpacks::None),
+            "tribute" | "tributes" | "per tribute" => Ok(Backpacks::PerTribute),
+            "district" | "districts" | "per district" => Ok(Backpacks::PerDistrict),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Backpacks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A feast at the Cornucopia on the morning of `day`, announced the night before.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Feast {
+    pub day: i32,
+    pub backpacks: Backpacks,
+}
+
+impl Feast {
+    pub fn new(day: i32, backpacks: Backpacks) -> Feast {
+        Feast { day, backpacks }
+    }
+}
+
+impl FromStr for Feast {
+    type Err = ();
+
+    /// A day, optionally followed by who gets backpacks, e.g. "5" or "5:district".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (day, backpacks) = s.split_once([':', '=']).unwrap_or((s, "none"));
+        let day = day.trim().parse().map_err(|_| ())?;
+        Ok(Feast::new(day, Backpacks::from_str(backpacks.trim())?))
+    }
+}
+
+impl Display for Feast {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.backpacks {
+            Backpacks::None => write!(f, "{}", self.day),
+            _ => write!(f, "{}:{}", self.day, self.backpacks),
+        }
+    }
+}
+
+/// What goes in a tribute's backpack: whatever they need most.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Supplies {
+    /// Treatment for their status, by name.
+    Remedy(String),
+    /// Something from home, for a mind close to breaking.
+    Memento,
+    Weapon,
+    Shield,
+}
+
+impl Supplies {
+    /// Treatment comes first, then sanity, then something to fight with, then something to fight behind.
+    pub fn packed_for(tribute: &Tribute, armed: bool) -> Supplies {
+        if let Some(remedy) = tribute.status.remedy() {
+            return Supplies::Remedy(remedy.to_string());
+        }
+        match (tribute.sanity, armed) {
+            (..=50, _) => Supplies::Memento,
+            (_, false) => Supplies::Weapon,
+            (_, true) => Supplies::Shield,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animals::Animal;
+    use crate::tributes::statuses::TributeStatus;
+
+    #[test]
+    fn parses_days_and_backpacks() {
+        assert_eq!(Feast::from_str("5"), Ok(Feast::new(5, Backpacks::None)));
+        assert_eq!(Feast::from_str("7:district"), Ok(Feast::new(7, Backpacks::PerDistrict)));
+        assert_eq!(Feast::from_str(&Feast::new(4, Backpacks::PerTribute).to_string()), Ok(Feast::new(4, Backpacks::PerTribute)));
+        assert_eq!(Feast::from_str("soon"), Err(()));
+        assert_eq!(Feast::from_str("5:everyone"), Err(()));
+    }
+
+    #[test]
+    fn backpacks_hold_what_the_tribute_needs() {
+        let mut tribute = Tribute::new("Katniss".to_string(), Some(12), None);
+        assert_eq!(Supplies::packed_for(&tribute, false), Supplies::Weapon);
+        assert_eq!(Supplies::packed_for(&tribute, true), Supplies::Shield);
+
+        tribute.sanity = 40;
+        assert_eq!(Supplies::packed_for(&tribute, true), Supplies::Memento);
+
+        tribute.status = TributeStatus::Mauled(Animal::Wolf);
+        assert_eq!(Supplies::packed_for(&tribute, true), Supplies::Remedy("bandages".to_string()));
+    }
+}
@@ -1,10 +1,20 @@
+use crate::animals::Animal;
 use crate::areas::Area;
+use crate::event_packs::CustomEvent;
 use crate::events::TributeEvent;
+use crate::feasts::{Backpacks, Feast, Supplies};
+use crate::i18n::Locale;
 use crate::items::{Attribute, Item};
 use crate::models::game::{get_game, Game as GameModel};
-use crate::models::{create_full_log, create_game, create_item, create_tribute, delete_game, delete_game_area_events, delete_game_items, delete_game_logs, delete_game_tribute_actions, delete_game_tributes, get_all_living_tributes, get_dead_tributes, get_recently_dead_tributes, update_tribute, NewItem};
+use crate::packs::Pack;
+use crate::rulesets::Ruleset;
+use crate::win_conditions::{RuleChange, WinCondition};
+use crate::models::{create_full_log, create_game, delete_game_bets, create_item, create_tribute, delete_game, delete_game_area_events, delete_game_items, delete_game_logs, delete_game_packs, delete_game_traps, delete_game_tribute_actions, delete_game_tribute_memories, delete_game_tributes, clear_game_spot_checks, get_all_living_tributes, get_dead_tributes, get_recently_dead_tributes, get_tribute_by_id, update_tribute, NewItem};
 use crate::tributes::actions::TributeAction;
-use crate::tributes::actors::Tribute;
+use crate::tributes::actors::{trauma_from_death, Tribute};
+use crate::tributes::brains::feast_appeal;
+use crate::tributes::contagion::{is_contagious, proximity, spread_chance, CONTAGIOUS, OUTBREAK_SIZE};
+use crate::tributes::point_buy::Preset;
 use crate::tributes::statuses::TributeStatus;
 use rand::prelude::SliceRandom;
 use rand::Rng;
@@ -20,18 +30,32 @@ pub struct Game {
     pub day: Option<i32>,
     pub closed_areas: Option<Vec<Area>>,
     pub status: GameStatus,
+    pub locale: Locale,
+    pub roster_size: i32,
+    pub districts: i32,
+    pub ruleset: Ruleset,
+    pub win_condition: WinCondition,
 }
 
+/// Tributes in a game unless it says otherwise, two from each district.
+pub const DEFAULT_ROSTER_SIZE: i32 = 24;
+pub const DEFAULT_DISTRICTS: i32 = 12;
+
 impl Game {
     pub fn new(game_name: &str) -> Game {
         Game::from(create_game(Some(game_name)))
     }
 
     pub fn delete(game_id: i32) {
+        delete_game_bets(game_id);
         delete_game_logs(game_id);
         delete_game_area_events(game_id);
         delete_game_items(game_id);
+        delete_game_packs(game_id);
+        delete_game_traps(game_id);
         delete_game_tribute_actions(game_id);
+        delete_game_tribute_memories(game_id);
+        clear_game_spot_checks(game_id);
         delete_game_tributes(game_id);
         delete_game(game_id);
     }
@@ -47,6 +71,11 @@ impl Game {
             day: Some(0),
             closed_areas: None,
             status: GameStatus::NotStarted,
+            locale: Locale::default(),
+            roster_size: DEFAULT_ROSTER_SIZE,
+            districts: DEFAULT_DISTRICTS,
+            ruleset: Ruleset::default(),
+            win_condition: WinCondition::default(),
         }
     }
 
@@ -55,8 +84,73 @@ impl Game {
         game.end();
     }
 
+    /// Narrate the rest of the game in `locale`. Earlier log entries keep their language.
+    pub fn set_locale(&mut self, locale: Locale) {
+        let game = get_game(self.name.as_str()).expect("Error loading game");
+        game.set_locale(&locale);
+        self.locale = locale;
+    }
+
+    pub fn set_roster_size(&mut self, roster_size: i32, districts: i32) -> Result<(), String> {
+        let game = get_game(self.name.as_str()).expect("Error loading game");
+        game.set_roster_size(roster_size, districts)?;
+        self.roster_size = roster_size;
+        self.districts = districts;
+        Ok(())
+    }
+
+    pub fn set_win_condition(&mut self, win_condition: WinCondition, alliances: &[Vec<i32>]) -> Result<(), String> {
+        let game = get_game(self.name.as_str()).expect("Error loading game");
+        game.set_win_condition(&win_condition, alliances)?;
+        self.win_condition = win_condition;
+        Ok(())
+    }
+
+    /// Announces a new rule to the arena. Errors if it's already in force.
+    pub fn announce_rule_change(&self, rule_change: RuleChange) -> Result<(), String> {
+        let mut game = get_game(self.name.as_str()).expect("Error loading game");
+        if game.ended_at.is_some() {
+            return Err(format!("{} is over", game.name));
+        }
+        let mut rule_changes = game.rule_changes();
+        if rule_changes.contains(&rule_change) {
+            return Err(format!("{} is already in force", rule_change));
+        }
+        rule_changes.push(rule_change.clone());
+        game.set_rule_changes(&rule_changes);
+        create_full_log(game.id, GameMessage::RuleChangeAnnounced(rule_change), None, None, None, None);
+        Ok(())
+    }
+
+    /// Takes back an earlier rule change. Errors if it isn't in force.
+    pub fn revoke_rule_change(&self, rule_change: RuleChange) -> Result<(), String> {
+        let mut game = get_game(self.name.as_str()).expect("Error loading game");
+        if game.ended_at.is_some() {
+            return Err(format!("{} is over", game.name));
+        }
+        let mut rule_changes = game.rule_changes();
+        if !rule_changes.contains(&rule_change) {
+            return Err(format!("{} isn't in force", rule_change));
+        }
+        rule_changes.retain(|change| *change != rule_change);
+        game.set_rule_changes(&rule_changes);
+        create_full_log(game.id, GameMessage::RuleChangeRevoked(rule_change), None, None, None, None);
+        Ok(())
+    }
+
+    pub fn set_ruleset(&mut self, ruleset: Ruleset) -> Result<(), String> {
+        let game = get_game(self.name.as_str()).expect("Error loading game");
+        game.set_ruleset(&ruleset)?;
+        let game = get_game(self.name.as_str()).expect("Error loading game");
+        self.roster_size = game.roster_size;
+        self.ruleset = ruleset;
+        Ok(())
+    }
+
     // Runs at the start of the game
     pub fn start(&self) {
+        self.pre_game();
+
         let game = get_game(self.name.as_str()).expect("Error loading game");
         let the_cornucopia = Area::from_str("cornucopia").expect("Error loading area");
         for _ in 0..10 {
@@ -71,6 +165,35 @@ impl Game {
                 None
             );
         }
+        for _ in 0..3 {
+            Item::new_random_camouflage(
+                Some(game.id),
+                Some(the_cornucopia.id()),
+                None
+            );
+        }
+    }
+
+    /// Training and interviews, logged on Day 0. Tributes who've already been through them sit this out.
+    pub fn pre_game(&self) {
+        let game = get_game(self.name.as_str()).expect("Error loading game");
+        let mut tributes = self.tributes().into_iter()
+            .filter(|t| t.training_score.is_none())
+            .collect::<Vec<Tribute>>();
+        if tributes.is_empty() {
+            return;
+        }
+
+        create_full_log(game.id, GameMessage::TrainingScoresStart, None, None, None, None);
+        for tribute in tributes.iter_mut() {
+            tribute.trains();
+        }
+
+        create_full_log(game.id, GameMessage::InterviewsStart, None, None, None, None);
+        for tribute in tributes.iter_mut() {
+            tribute.gives_interview();
+            tribute.update(tribute.clone().into());
+        }
     }
 
     pub fn tributes(&self) -> Vec<Tribute> {
@@ -88,27 +211,91 @@ impl Game {
         get_dead_tributes(&game).iter().map(|t| Tribute::from(t.clone())).collect()
     }
 
-    pub fn winner(&self) -> Option<Tribute> {
+    /// Whether everyone's dead or the survivors have won under the game's rules.
+    pub fn is_over(&self) -> bool {
+        let game = get_game(self.name.as_str()).expect("Error loading game");
+        let living_tributes = self.living_tributes();
+        living_tributes.is_empty() || game.is_won(&districts_of(&living_tributes))
+    }
+
+    /// Whoever has won, which may be more than one tribute under some rules.
+    pub fn winners(&self) -> Vec<Tribute> {
         let game = get_game(self.name.as_str()).expect("Error loading game");
-        let winner = get_all_living_tributes(&game);
-        if winner.len() == 1 {
-            Some(Tribute::from(winner[0].clone()))
+        let living_tributes = self.living_tributes();
+        if game.is_won(&districts_of(&living_tributes)) {
+            living_tributes
         } else {
-            None
+            vec![]
         }
     }
 
-    pub fn add_tribute(&self, name: String, avatar: Option<String>) -> Result<Tribute, ()> {
+    /// Adds a hand-built tribute whose stats follow `preset` within the game's budget.
+    pub fn add_tribute(&self, name: String, avatar: Option<String>, preset: Preset) -> Result<Tribute, String> {
         let game = get_game(self.name.as_str()).expect("Error loading game");
+        game.check_room()?;
         let mut tribute = create_tribute(name.as_str(), avatar);
         tribute.set_game(&game);
         tribute.game_id = Some(game.id);
 
-        Ok(Tribute::from(tribute))
+        let mut tribute = Tribute::from(tribute);
+        preset.apply(&mut tribute, &game.point_buy());
+        tribute.update(tribute.clone().into());
+        Ok(tribute)
     }
 
-    pub fn run_day_night_cycle(&mut self) {
+    /// Lets the gamemakers' own mutts loose in `area`, under a name and bite of their choosing.
+    pub fn release_mutts(&self, area: Area, count: i32, name: Option<String>, damage: Option<i32>) -> Result<Pack, String> {
         let game = get_game(self.name.as_str()).expect("Error loading game");
+        if game.ended_at.is_some() {
+            return Err(format!("{} is over", game.name));
+        }
+        if game.closed_areas().contains(&area) {
+            return Err(format!("{} is closed", area));
+        }
+        if !(1..=10).contains(&count) {
+            return Err(format!("A pack is 1 to 10 mutts, not {}", count));
+        }
+        if damage.is_some_and(|damage| damage < 1) {
+            return Err(format!("Mutts have to do at least 1 damage, not {}", damage.unwrap()));
+        }
+        if let Some(name) = name.as_ref().filter(|name| name.chars().count() > 64) {
+            return Err(format!("Mutt names can be at most 64 characters, {} is {}", name, name.chars().count()));
+        }
+
+        let pack = Pack::new(game.id, area, Animal::Mutt, count, name, damage);
+        create_full_log(game.id, GameMessage::MuttsReleased(pack.clone()), Some(pack.area.id()), None, None, None);
+        Ok(pack)
+    }
+
+    /// Seeds a contagious `disease` in `area`: everyone there catches it, to show once it's incubated.
+    /// Returns how many tributes caught it.
+    pub fn seed_epidemic(&self, area: Area, disease: TributeStatus) -> Result<i32, String> {
+        let game = get_game(self.name.as_str()).expect("Error loading game");
+        if game.ended_at.is_some() {
+            return Err(format!("{} is over", game.name));
+        }
+        if !is_contagious(&disease) {
+            return Err(format!("Being {} doesn't spread, try sick or infected", disease));
+        }
+
+        let mut rng = rand::thread_rng();
+        let mut exposed = 0;
+        for mut tribute in area.tributes(game.id) {
+            if tribute.catches(disease.clone(), &mut rng) {
+                update_tribute(tribute.id.unwrap(), tribute.into());
+                exposed += 1;
+            }
+        }
+        if exposed == 0 {
+            return Err(format!("There's no one in {} to catch it", area));
+        }
+        create_full_log(game.id, GameMessage::Outbreak(area.clone(), disease, exposed), Some(area.id()), None, None, None);
+        Ok(exposed)
+    }
+
+    pub fn run_day_night_cycle(&mut self) {
+        let mut game = get_game(self.name.as_str()).expect("Error loading game");
+        let tuning = game.tuning();
         self.day = Some(self.day.unwrap_or(0) + 1);
         game.set_day(self.day.unwrap());
 
@@ -116,37 +303,47 @@ impl Game {
         let living_tributes = get_all_living_tributes(&game);
 
         // See if we have a winner or a dud game
-        match living_tributes.len() {
-            0 => {
-                let message = GameMessage::NoOneWins;
-                create_full_log(game.id, message.to_string(), None, None, None, None);
-                game.end();
-                return;
-            }
-            1 => {
-                let winner = living_tributes[0].clone();
-                let message = GameMessage::TributeWins(Tribute::from(winner.clone()));
-                create_full_log(game.id, message.to_string(), None, Some(winner.id), None, None);
-                game.end();
-                return;
-            }
-            _ => {}
+        if living_tributes.is_empty() {
+            create_full_log(game.id, GameMessage::NoOneWins, None, None, None, None);
+            game.end();
+            return;
+        }
+        let districts = living_tributes.iter().map(|t| t.district).collect::<Vec<i32>>();
+        if game.is_won(&districts) {
+            let winner_id = match living_tributes.as_slice() {
+                [winner] => Some(winner.id),
+                _ => None,
+            };
+            let winners = living_tributes.into_iter().map(Tribute::from).collect();
+            create_full_log(game.id, GameMessage::TributeWins(winners), None, winner_id, None, None);
+            game.end();
+            return;
         }
 
-        // Make any announcements for the day
-        match self.day {
-            Some(1) => {
-                create_full_log(game.id, GameMessage::FirstDayStart.to_string(), None, None, None, None);
-            }
-            Some(3) => {
-                create_full_log(game.id, GameMessage::FeastDayStart.to_string(), None, None, None, None);
-            }
-            _ => {
-                create_full_log(game.id, GameMessage::GameDayStart(self.day.unwrap()).to_string(), None, None, None, None);
-            }
+        // Make any announcements for the day. Sudden death and a feast can fall on the same day.
+        let day = self.day.unwrap();
+        let sudden_death = game.win_condition().time_limit() == Some(day - 1);
+        let feast_day = game.feast_on(day).is_some();
+        if day == 1 {
+            create_full_log(game.id, GameMessage::FirstDayStart, None, None, None, None);
+        } else if !sudden_death && !feast_day {
+            create_full_log(game.id, GameMessage::GameDayStart(day), None, None, None, None);
+        }
+        if sudden_death {
+            create_full_log(game.id, GameMessage::SuddenDeath(day), None, None, None, None);
+        }
+        if feast_day {
+            create_full_log(game.id, GameMessage::FeastDayStart(day), None, None, None, None);
         }
 
-        create_full_log(game.id, GameMessage::TributesLeft(living_tributes.len() as i32).to_string(), None, None, None, None);
+        // Once the survivors are few enough, or the game has run long enough, force a showdown
+        let out_of_time = game.win_condition().time_limit().is_some_and(|limit| day > limit);
+        if !game.in_finale() && (tuning.finale_due(living_tributes.len(), day) || out_of_time) {
+            game.start_finale(day);
+            create_full_log(game.id, GameMessage::Finale, None, None, None, None);
+        }
+
+        create_full_log(game.id, GameMessage::TributesLeft(living_tributes.len() as i32), None, None, None, None);
 
         // Run the day
         self.do_day_night_cycle(true);
@@ -154,7 +351,12 @@ impl Game {
         // Clean up any deaths
         self.clean_up_recent_deaths();
 
-        create_full_log(game.id, GameMessage::GameNightStart(self.day.unwrap()).to_string(), None, None, None, None);
+        create_full_log(game.id, GameMessage::GameNightStart(self.day.unwrap()), None, None, None, None);
+
+        // Tell the arena about tomorrow's feast, so tributes can weigh whether to go
+        if let Some(feast) = game.feast_on(day + 1) {
+            create_full_log(game.id, GameMessage::FeastAnnounced(feast.day, feast.backpacks), None, None, None, None);
+        }
 
         // Run the night
         self.do_day_night_cycle(false);
@@ -165,66 +367,74 @@ impl Game {
 
     pub fn do_day_night_cycle(&mut self, day: bool) {
         let mut rng = rand::thread_rng();
-        let day_event_frequency = 1.0 / 4.0;
-        let night_event_frequency = 1.0 / 8.0;
         let game = get_game(self.name.as_str()).expect("Error loading game");
+        let tuning = game.tuning();
+
+        // Every phase is a fresh chance to spot hidden tributes
+        clear_game_spot_checks(game.id);
 
         // Clean up any deaths from the previous cycle's events
         Area::clean_up_area_events(self.id.unwrap());
 
         // Trigger any events for this cycle
         if self.day > Some(3) || !day {
-            if rng.gen_bool(if day { day_event_frequency } else { night_event_frequency }) {
-                Area::do_area_event(self.id.unwrap());
+            if rng.gen_bool(if day { tuning.day_event_frequency } else { tuning.night_event_frequency }) {
+                // The finale's showdown at the Cornucopia is left alone
+                if game.in_finale() {
+                    Area::close_outer_area(self.id.unwrap());
+                } else {
+                    Area::do_area_event(self.id.unwrap());
+                }
             }
         }
+        if !day && game.ruleset().has_night_hazards() {
+            Area::do_arena_hazard(self.id.unwrap());
+        }
 
-        if self.day == Some(3) && day {
-            // Add goodies to the Cornucopia
-            let cornucopia = Area::from_str("cornucopia").expect("Error loading area");
-            let items = cornucopia.available_items(game.id);
-            if items.len() <= 12 {
-                let count = (12 - items.len()) / 3;
-                for _ in 0..count {
-                    Item::new_generic_consumable(
-                        self.id,
-                        Some(cornucopia.id()),
-                        None
-                    );
-                    Item::new_random_weapon(
-                        self.id,
-                        Some(cornucopia.id()),
-                        None
-                    );
-                    Item::new_random_shield(
-                        self.id,
-                        Some(cornucopia.id()),
-                        None
-                    );
-                }
-            }
+        if let Some(feast) = game.feast_on(self.day.unwrap()).filter(|_| day) {
+            self.lay_out_feast(&game, &feast);
         }
 
         // Get all the remaining tributes to run their appropriate actions
         let mut living_tributes = get_all_living_tributes(&game);
 
-        // If there are too few, but not just one, tribute left, close an area or two
-        if living_tributes.len() > 1 && living_tributes.len() < 7 {
+        // Today's feast, or tonight's announced one, draws tributes in
+        let upcoming_feast = game.feast_on(if day { self.day.unwrap() } else { self.day.unwrap() + 1 });
+
+        // A finale that's stalled gets the mutts sent into the Cornucopia as well
+        let stalled = game.finale_stalled(self.day.unwrap(), tuning.finale_standoff_days);
+
+        // The finale closes the outer areas one by one; before that, once there are
+        // too few, but not just one, tribute left, close an area or two
+        if game.in_finale() {
+            Area::close_outer_area(self.id.unwrap());
+        } else if living_tributes.len() > 1 && living_tributes.len() < tuning.area_closing_threshold {
             Area::do_area_event(self.id.unwrap());
 
-            if rng.gen_bool(living_tributes.len() as f64 / 24.0) {
+            if rng.gen_bool(living_tributes.len() as f64 / game.roster_size as f64) {
                 Area::do_area_event(self.id.unwrap());
             }
         }
 
+        let game_events = CustomEvent::for_game(game.id);
         living_tributes.shuffle(&mut rng);
         for tribute in living_tributes {
-            let mut tribute = Tribute::from(tribute.clone());
+            // Events earlier in the phase may have involved this tribute, so reload them
+            let mut tribute = Tribute::from(get_tribute_by_id(tribute.id));
 
             // Use luck to decide if the tribute is caught by an event
-            if !rng.gen_bool(tribute.luck.unwrap_or(0) as f64 / 100.0) {
-                let event = TributeEvent::random();
-                tribute.handle_event(event);
+            if tribute.is_alive() && !rng.gen_bool(tribute.luck.unwrap_or(0) as f64 / 100.0) {
+                // Custom events from loaded packs, and this game's own, compete with the built-in ones
+                match CustomEvent::draw(&tribute, day, TributeEvent::all().len(), &game_events) {
+                    Some((event, mut participants)) => {
+                        event.happens(&mut participants);
+                        tribute = participants.swap_remove(0);
+                    }
+                    None => {
+                        let event = TributeEvent::random();
+                        tribute.handle_event(event);
+                    }
+                }
             }
 
             // If the event killed the tribute, move on
@@ -233,6 +443,30 @@ impl Game {
                 continue;
             };
 
+            // Holdouts in the finale are hunted down
+            let holdout = game.in_finale() && tribute.area != Some(Area::Cornucopia);
+            if (holdout || stalled) && rng.gen_bool(tuning.mutt_chance) {
+                tribute.status = TributeStatus::Mauled(Animal::Mutt);
+                create_full_log(
+                    game.id,
+                    GameMessage::TributeHuntedByMutts(tribute.clone(), tribute.area.clone().unwrap()),
+                    tribute.area.as_ref().map(|area| area.id()),
+                    tribute.id,
+                    None,
+                    None,
+                );
+
+                // The mutts maul them once and leave them bleeding
+                tribute.process_status(&tuning);
+                if tribute.is_alive() {
+                    tribute.status = TributeStatus::Wounded;
+                }
+                update_tribute(tribute.id.unwrap(), tribute.clone().into());
+                if !tribute.is_alive() {
+                    continue;
+                }
+            }
+
             match (self.day, day) {
                 (Some(1), true) => {
                     tribute = tribute.do_day_night(
@@ -241,13 +475,24 @@ impl Game {
                         day
                     );
                 }
-                (Some(3), true) => {
-                    // Feast day
-
-                    // Encourage tributes to move to the Cornucopia
+                _ if holdout => {
+                    // Finale: everyone is driven toward the Cornucopia
+                    tribute = tribute.do_day_night(
+                        Some(TributeAction::Move(Some(Area::Cornucopia.to_string()))),
+                        Some(tuning.finale_pull),
+                        day,
+                    );
+                }
+                _ if upcoming_feast.is_some() && tribute.area != Some(Area::Cornucopia) => {
+                    // Encourage tributes to move to the Cornucopia, as far as they think it's worth it
+                    let backpack = upcoming_feast.as_ref().is_some_and(|feast| feast.backpacks != Backpacks::None);
+                    let rivals = get_all_living_tributes(&game).iter()
+                        .filter(|t| !game.are_allies(t.district, tribute.district))
+                        .count();
+                    let appeal = feast_appeal(&tribute, backpack, !tribute.weapons().is_empty(), rivals);
                     tribute = tribute.do_day_night(
                         Some(TributeAction::Move(Some(Area::Cornucopia.to_string()))),
-                        Some(0.75),
+                        Some(appeal),
                         day,
                     );
                 }
@@ -257,15 +502,124 @@ impl Game {
             };
             update_tribute(tribute.id.unwrap(), tribute.into());
         }
+
+        self.spread_disease(&game, &mut rng);
+        self.run_packs(&game, &mut rng);
+    }
+
+    /// Anyone ill with something contagious can pass it on to the others in their area.
+    /// Enough new cases of a disease in one area at once make for an outbreak.
+    /// Anything handed over by an event can carry it too, see `Effect::TransferItem`.
+    fn spread_disease(&self, game: &GameModel, rng: &mut impl Rng) {
+        let contagion_chance = game.tuning().contagion_chance;
+        for area in [Area::Cornucopia].into_iter().chain(Area::Cornucopia.neighbors()) {
+            let tributes = area.tributes(game.id).into_iter()
+                .filter(|t| t.is_alive())
+                .collect::<Vec<Tribute>>();
+            let carriers = tributes.iter()
+                .filter(|t| is_contagious(&t.status))
+                .cloned()
+                .collect::<Vec<Tribute>>();
+
+            let mut cases = vec![];
+            for mut tribute in tributes.into_iter().filter(|t| !is_contagious(&t.status)) {
+                for carrier in &carriers {
+                    let allies = game.are_allies(carrier.district, tribute.district);
+                    let chance = spread_chance(contagion_chance, proximity(&tribute, allies), &tribute);
+                    if rng.gen_bool(chance) && tribute.catches(carrier.status.clone(), rng) {
+                        update_tribute(tribute.id.unwrap(), tribute.clone().into());
+                        cases.push(carrier.status.clone());
+                        break;
+                    }
+                }
+            }
+            for disease in CONTAGIOUS {
+                let count = cases.iter().filter(|case| **case == disease).count();
+                if count >= OUTBREAK_SIZE {
+                    create_full_log(game.id, GameMessage::Outbreak(area.clone(), disease, count as i32), Some(area.id()), None, None, None);
+                }
+            }
+        }
     }
+
+    /// Wild animals wander in, packs roam the arena, and any that find a tribute they can see go for them.
+    fn run_packs(&self, game: &GameModel, rng: &mut impl Rng) {
+        let tuning = game.tuning();
+        let closed_areas = game.closed_areas();
+        if rng.gen_bool(tuning.pack_spawn_chance) {
+            let pack = Pack::wild(game.id, Area::random_open_area(closed_areas.clone()), rng);
+            create_full_log(game.id, GameMessage::PackSighted(pack.clone()), Some(pack.area.id()), None, None, None);
+        }
+
+        for mut pack in Pack::all(game.id) {
+            let living_tributes = get_all_living_tributes(game).into_iter()
+                .map(Tribute::from)
+                .collect::<Vec<Tribute>>();
+            let tributes_in = |area: &Area| living_tributes.iter().filter(|t| t.area.as_ref() == Some(area)).count();
+            pack.area = pack.roam(&closed_areas, tributes_in, rng);
+
+            let prey = living_tributes.iter()
+                .filter(|t| t.area == Some(pack.area.clone()) && !t.is_hidden.unwrap_or(false))
+                .collect::<Vec<&Tribute>>();
+            if !prey.is_empty() && rng.gen_bool(tuning.pack_attack_chance) {
+                let tribute = (*prey.choose(rng).unwrap()).clone();
+                pack_attacks(game, &mut pack, tribute);
+            }
+            pack.save();
+        }
+    }
+
+    /// Restocks the Cornucopia for a feast and leaves out any backpacks.
+    fn lay_out_feast(&self, game: &GameModel, feast: &Feast) {
+        let tuning = game.tuning();
+        let cornucopia = Area::Cornucopia;
+        let items = cornucopia.available_items(game.id);
+        if items.len() <= tuning.feast_items {
+            let count = (tuning.feast_items - items.len()) / 3;
+            for _ in 0..count {
+                Item::new_generic_consumable(self.id, Some(cornucopia.id()), None);
+                Item::new_random_weapon(self.id, Some(cornucopia.id()), None);
+                Item::new_random_shield(self.id, Some(cornucopia.id()), None);
+            }
+        }
+
+        let living_tributes = get_all_living_tributes(game).into_iter()
+            .map(Tribute::from)
+            .collect::<Vec<Tribute>>();
+        match feast.backpacks {
+            Backpacks::None => {}
+            Backpacks::PerTribute => {
+                for tribute in &living_tributes {
+                    let supplies = Supplies::packed_for(tribute, !tribute.weapons().is_empty());
+                    Item::new_backpack(&supplies, self.id, Some(cornucopia.id()), tribute.id, None);
+                }
+            }
+            Backpacks::PerDistrict => {
+                // Each district's backpack is packed for whichever of its tributes is worst off
+                let mut districts = districts_of(&living_tributes);
+                districts.sort();
+                districts.dedup();
+                for district in districts {
+                    let neediest = living_tributes.iter()
+                        .filter(|t| t.district == district)
+                        .min_by_key(|t| t.health)
+                        .unwrap();
+                    let supplies = Supplies::packed_for(neediest, !neediest.weapons().is_empty());
+                    Item::new_backpack(&supplies, self.id, Some(cornucopia.id()), None, Some(district));
+                }
+            }
+        }
+    }
+
     pub fn clean_up_recent_deaths(&self) {
         let game = get_game(self.name.as_str()).expect("Error loading game");
         let dead_tributes = get_recently_dead_tributes(&game);
 
-        create_full_log(game.id, GameMessage::DailyDeathAnnouncement(dead_tributes.len() as i32).to_string(), None, None, None, None);
+        create_full_log(game.id, GameMessage::DailyDeathAnnouncement(dead_tributes.len() as i32), None, None, None, None);
 
         for tribute in dead_tributes {
-            create_full_log(game.id, GameMessage::DeathAnnouncement(Tribute::from(tribute.clone())).to_string(), None, Some(tribute.id), None, None);
+            create_full_log(game.id, GameMessage::DeathAnnouncement(Tribute::from(tribute.clone())), None, Some(tribute.id), None, None);
+            trauma_from_death(&Tribute::from(tribute.clone()));
             tribute.dies();
         }
     }
@@ -302,6 +656,11 @@ impl From<GameModel> for Game {
             day: Some(game.day.unwrap_or(0)),
             closed_areas: Some(game.closed_areas()),
             status,
+            locale: Locale::from_str(&game.locale).unwrap_or_default(),
+            roster_size: game.roster_size,
+            districts: game.districts,
+            ruleset: game.ruleset(),
+            win_condition: game.win_condition(),
         }
     }
 }
@@ -342,3 +701,91 @@ impl FromStr for GameStatus {
         }
     }
 }
+
+/// The pack goes for `tribute`, who fights back with whatever they've got.
+/// Bringing any of them down wins the crowd over, and wild game makes a meal.
+fn pack_attacks(game: &GameModel, pack: &mut Pack, mut tribute: Tribute) {
+    let damage = pack.bite(&game.tuning()) * pack.count;
+    tribute.takes_physical_damage(damage);
+    create_full_log(
+        game.id,
+        GameMessage::TributeMauled(tribute.clone(), pack.count, pack.animal.clone(), damage),
+        Some(pack.area.id()),
+        tribute.id,
+        None,
+        None,
+    );
+
+    if tribute.health == 0 {
+        tribute.status = TributeStatus::Mauled(pack.animal.clone());
+        create_full_log(
+            game.id,
+            GameMessage::TributeDiesFromStatus(tribute.clone(), tribute.status.clone()),
+            Some(pack.area.id()),
+            tribute.id,
+            None,
+            None,
+        );
+        tribute.killed_by = Some(tribute.status.to_string());
+        tribute.status = TributeStatus::RecentlyDead;
+    } else {
+        let weapon = tribute.weapons().iter().map(|w| w.effect).max().unwrap_or(0);
+        let killed = pack.takes_damage(tribute.strength.unwrap_or(0) + weapon);
+        if killed > 0 {
+            create_full_log(
+                game.id,
+                GameMessage::TributeKillsAnimals(tribute.clone(), pack.clone(), killed),
+                Some(pack.area.id()),
+                tribute.id,
+                None,
+                None,
+            );
+            tribute.likability = tribute.likability.map(|likability| std::cmp::min(100, likability + 2 * killed));
+            if !pack.is_mutts() {
+                let effect = pack.animal.damage().clamp(2, 10);
+                Item::create("meat".to_string(), Consumable.to_string(), killed, Attribute::Health.to_string(), effect, tribute.game_id, None, tribute.id);
+            }
+        }
+    }
+    update_tribute(tribute.id.unwrap(), tribute.into());
+}
+
+fn districts_of(tributes: &[Tribute]) -> Vec<i32> {
+    tributes.iter().map(|t| t.district).collect()
+}
+
+/// The lowest district still short of its share of the roster, so districts fill up
+/// in turn. Once they all have their share, the emptiest one takes the extra tribute.
+pub fn next_district(taken: &[i32], roster_size: i32, districts: i32) -> i32 {
+    let districts = districts.max(1);
+    let share = (roster_size + districts - 1) / districts;
+    let count = |district: &i32| taken.iter().filter(|d| *d == district).count() as i32;
+    (1..=districts)
+        .find(|district| count(district) < share)
+        .or_else(|| (1..=districts).min_by_key(count))
+        .unwrap_or(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn districts_fill_in_pairs() {
+        let mut taken = vec![];
+        for _ in 0..DEFAULT_ROSTER_SIZE {
+            taken.push(next_district(&taken, DEFAULT_ROSTER_SIZE, DEFAULT_DISTRICTS));
+        }
+        assert_eq!(&taken[..6], &[1, 1, 2, 2, 3, 3]);
+        assert_eq!(taken.last(), Some(&12));
+    }
+
+    #[test]
+    fn gaps_are_filled_first() {
+        assert_eq!(next_district(&[1, 1, 2, 3, 3], 8, 4), 2);
+        assert_eq!(next_district(&[1, 1, 1, 1, 2, 2, 2, 2], 48, 12), 3);
+        // Uneven rosters give the odd tribute to the earliest districts
+        assert_eq!(next_district(&[1, 1, 2, 2], 5, 2), 1);
+        assert_eq!(next_district(&[1, 1, 1, 2, 2], 5, 2), 2);
+    }
+}
@@ -1,5 +1,9 @@
 use dioxus::prelude::*;
-use crate::games::Game;
+use crate::i18n::{gui_locale, t, Locale};
+use std::str::FromStr;
+use strum::IntoEnumIterator;
+use crate::games::{Game, DEFAULT_DISTRICTS, DEFAULT_ROSTER_SIZE};
+use crate::rulesets::Ruleset;
 use crate::gui::states::HGState;
 use crate::gui::router::Routes;
 use crate::gui::components::button::Button;
@@ -8,6 +12,7 @@ use crate::gui::components::button::Button;
 pub fn CreateGame() -> Element {
     let mut state = use_context::<Signal<HGState>>();
     let mut game_name = use_signal(String::new);
+    let mut form_errors = use_signal(Vec::<String>::new);
     let nav = navigator();
 
     rsx! {
@@ -18,19 +23,45 @@ pub fn CreateGame() -> Element {
                 onsubmit: move |event| {
                     let data = event.data.values();
                     let game_name = data.get("game-name").unwrap().first().unwrap();
-                    let game = Game::new(game_name);
+                    let locale = data.get("game-locale")
+                        .and_then(|values| values.first())
+                        .and_then(|code| Locale::from_str(code).ok())
+                        .unwrap_or_default();
+                    let number = |field: &str, default: i32| data.get(field)
+                        .and_then(|values| values.first())
+                        .and_then(|value| value.parse::<i32>().ok())
+                        .unwrap_or(default);
+                    let mut game = Game::new(game_name);
+                    game.set_locale(locale);
+                    let roster_size = number("game-roster-size", DEFAULT_ROSTER_SIZE);
+                    let districts = number("game-districts", DEFAULT_DISTRICTS);
+                    // A game that can't be set up as asked isn't kept
+                    if let Err(error) = game.set_roster_size(roster_size, districts) {
+                        Game::delete(game.id.unwrap());
+                        form_errors.set(vec![error]);
+                        return;
+                    }
+                    let ruleset = data.get("game-ruleset")
+                        .and_then(|values| values.first())
+                        .and_then(|name| Ruleset::from_str(name).ok())
+                        .unwrap_or_default();
+                    if let Err(error) = game.set_ruleset(ruleset) {
+                        Game::delete(game.id.unwrap());
+                        form_errors.set(vec![error]);
+                        return;
+                    }
                     state.write().games.push(game.clone());
                     nav.push(Routes::GameDetail { id: game.id.unwrap() });
                 },
                 label {
                     r#for: "game-name",
                     class: "sr-only",
-                    "Game Name",
+                    {t("gui-game-name")}
                 }
                 input {
                     class: "block w-half px-2 py-1 text-gray-900 border border-orange-700 rounded-md bg-yellow-200 focus:outline-none placeholder-gray-900",
                     r#type: "text",
-                    placeholder: "Game Name",
+                    placeholder: t("gui-game-name"),
                     id: "game-name",
                     name: "game-name",
                     value: "{game_name}",
@@ -41,7 +72,79 @@ pub fn CreateGame() -> Element {
                         }
                     }
                 }
-                Button { text: "Create Game" }
+                label {
+                    r#for: "game-locale",
+                    class: "sr-only",
+                    {t("gui-language")}
+                }
+                select {
+                    class: "block px-2 py-1 text-gray-900 border border-orange-700 rounded-md bg-yellow-200 focus:outline-none",
+                    id: "game-locale",
+                    name: "game-locale",
+                    for locale in Locale::iter() {
+                        option {
+                            value: "{locale.code()}",
+                            selected: locale == gui_locale(),
+                            "{locale.native_name()}"
+                        }
+                    }
+                }
+                label {
+                    r#for: "game-roster-size",
+                    class: "sr-only",
+                    {t("gui-roster-size")}
+                }
+                input {
+                    class: "block w-16 px-2 py-1 text-gray-900 border border-orange-700 rounded-md bg-yellow-200 focus:outline-none",
+                    r#type: "number",
+                    id: "game-roster-size",
+                    name: "game-roster-size",
+                    title: t("gui-roster-size"),
+                    min: "8",
+                    max: "48",
+                    value: "{DEFAULT_ROSTER_SIZE}",
+                }
+                label {
+                    r#for: "game-districts",
+                    class: "sr-only",
+                    {t("gui-districts")}
+                }
+                input {
+                    class: "block w-16 px-2 py-1 text-gray-900 border border-orange-700 rounded-md bg-yellow-200 focus:outline-none",
+                    r#type: "number",
+                    id: "game-districts",
+                    name: "game-districts",
+                    title: t("gui-districts"),
+                    min: "1",
+                    max: "24",
+                    value: "{DEFAULT_DISTRICTS}",
+                }
+                label {
+                    r#for: "game-ruleset",
+                    class: "sr-only",
+                    {t("gui-ruleset")}
+                }
+                select {
+                    class: "block px-2 py-1 text-gray-900 border border-orange-700 rounded-md bg-yellow-200 focus:outline-none",
+                    id: "game-ruleset",
+                    name: "game-ruleset",
+                    title: t("gui-ruleset"),
+                    for ruleset in Ruleset::iter() {
+                        option {
+                            value: "{ruleset.as_str()}",
+                            {ruleset.localized(&gui_locale())}
+                        }
+                    }
+                }
+                Button { text: t("gui-create-game") }
+            }
+            if !form_errors.read().is_empty() {
+                ul {
+                    class: "mt-2 text-sm text-center text-red-700 dark:text-red-400",
+                    for error in form_errors.read().iter() {
+                        li { "{error}" }
+                    }
+                }
             }
         }
     }
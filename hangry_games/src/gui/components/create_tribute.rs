@@ -1,11 +1,15 @@
 use std::path::Path;
+use std::str::FromStr;
 use std::sync::Arc;
 use dioxus::prelude::*;
+use strum::IntoEnumIterator;
+use crate::i18n::t;
 use dioxus::{prelude::dioxus_elements::FileEngine};
 use crate::games::Game;
 use crate::gui::components::UploadedFile;
 use crate::models::get_game_by_id;
 use crate::tributes::actors::Tribute;
+use crate::tributes::point_buy::Preset;
 use crate::gui::components::button::Button;
 
 
@@ -55,7 +59,11 @@ pub fn CreateTribute(signal: Signal<Vec<Tribute>>, game_id: i32) -> Element {
                     std::fs::write(format!("{}{}", save_path, filename), &image.contents).expect("Unable to write file");
                     new_avatar_path = Some(format!("{}{}", avatar_path, filename));
                 }
-                let tribute = game.add_tribute(name.clone(), new_avatar_path);
+                let preset = data.get("tribute_preset")
+                    .and_then(|values| values.first())
+                    .and_then(|value| Preset::from_str(value).ok())
+                    .unwrap_or_default();
+                let tribute = game.add_tribute(name.clone(), new_avatar_path, preset);
 
                 signal.write().push(tribute.expect("Error creating tribute"));
                 tribute_name.set(String::from(""));
@@ -65,7 +73,7 @@ pub fn CreateTribute(signal: Signal<Vec<Tribute>>, game_id: i32) -> Element {
                 class: "w-full rounded-md border border-orange-700 bg-yellow-200 px-2 py-1 text-gray-900 placeholder-gray-900 focus:outline-none",
                 id: "tribute_name",
                 name: "tribute_name",
-                placeholder: "Name",
+                placeholder: t("gui-name"),
                 value: "{tribute_name}",
                 oninput: move |event| tribute_name.set(event.value().clone()),
                 onkeypress: move |event| {
@@ -78,14 +86,27 @@ pub fn CreateTribute(signal: Signal<Vec<Tribute>>, game_id: i32) -> Element {
                 class: "w-full cursor-pointer rounded-md border border-orange-700 bg-yellow-200 px-2 py-1 text-gray-900 focus:outline-none",
                 id: "file_input",
                 r#type: "file",
-                placeholder: "Upload Image",
+                placeholder: t("gui-upload-image"),
                 accept: "image/png,image/gif,image/jpg",
                 multiple: false,
 
                 onchange: upload_files
             }
 
-            Button { text: "Add Tribute" }
+            select {
+                class: "block px-2 py-1 text-gray-900 border border-orange-700 rounded-md bg-yellow-200 focus:outline-none",
+                id: "tribute_preset",
+                name: "tribute_preset",
+                title: t("gui-preset"),
+                for preset in Preset::iter() {
+                    option {
+                        value: "{preset}",
+                        {t(&format!("gui-preset-{}", preset))}
+                    }
+                }
+            }
+
+            Button { text: t("gui-add-tribute") }
         }
     }
 }
@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+use crate::i18n::t;
 use crate::gui::components::ShowModal;
 use crate::gui::components::button::Button;
 
@@ -8,7 +9,7 @@ pub fn FillTributesButton() -> Element {
 
     rsx! {
         Button {
-            text: "Fill game",
+            text: t("gui-fill-game"),
             onclick: move |_| {
                 state.write().show = true;
             },
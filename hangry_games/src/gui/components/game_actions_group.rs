@@ -60,7 +60,7 @@ fn GameDetailsButton(game: Game) -> Element {
 fn GamePlayButton(game: Game) -> Element {
     let nav = navigator();
     let mut classes = "inline-block p-2 text-sm font-normal text-slate-800 hover:text-green-700 focus:relative w-full".to_string();
-    if game.tributes().len() < 24 || game.status == GameStatus::Finished {
+    if game.tributes().len() < game.roster_size as usize || game.status == GameStatus::Finished {
         classes += " hidden";
     }
     rsx! {
@@ -84,7 +84,7 @@ fn GamePlayButton(game: Game) -> Element {
 fn GameLogButton(game: Game) -> Element {
     let nav = navigator();
     let mut classes = "inline-block p-2 text-sm font-normal text-slate-800 hover:text-green-700 focus:relative w-full".to_string();
-    if game.tributes().len() != 24 || game.status == GameStatus::NotStarted {
+    if game.tributes().len() != game.roster_size as usize || game.status == GameStatus::NotStarted {
         classes += " hidden";
     }
     rsx! {
@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+use crate::i18n::t;
 use crate::games::Game;
 use crate::gui::router::Routes;
 use crate::models::{get_game_by_id, get_logs_for_game_day};
@@ -11,29 +12,29 @@ pub fn GameDayLog(id: i32, day: i32) -> Element {
         Link {
             to: Routes::Home {},
             class: "flex flex-row items-center gap-2 justify-center",
-            "Home"
+            {t("gui-home")}
         }
         div {
             class: "flex flex-row items-center gap-2 justify-center text-yellow-900 dark:text-yellow-500 divide-x divide-yellow-900 dark:divide-yellow-500 mb-4 underline",
             Link {
                 to: Routes::Home {},
-                "Home"
+                {t("gui-home")}
             }
             Link {
                 to: Routes::GameDetail { id: game.id.unwrap() },
                 class: "pl-2",
-                "Back to game"
+                {t("gui-back-to-game")}
             }
             Link {
                 to: Routes::GameLog { id: game.id.unwrap() },
                 class: "pl-2",
-                "Back to log"
+                {t("gui-back-to-log")}
             }
         }
         div {
             h2 {
                 class: "text-2xl font-bold text-yellow-900 dark:text-yellow-700 orbitron-font tracking-wider",
-                "Game ",
+                {format!("{} ", t("gui-game"))},
                 span {
                     class: "font-normal text-red-700 dark:text-yellow-500 tracking-normal",
                     "{game.name}"
@@ -42,7 +43,7 @@ pub fn GameDayLog(id: i32, day: i32) -> Element {
             div {
                 h2 {
                     class: "text-xl font-bold orbitron-font tracking-wider text-yellow-800",
-                    "Day "
+                    {format!("{} ", t("gui-day"))}
                     span {
                         class: "font-normal text-red-800 dark:text-yellow-500 tracking-normal",
                         "{day}"
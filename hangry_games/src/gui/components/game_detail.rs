@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+use crate::i18n::{gui_locale, t, t_with};
 use crate::games::Game;
 use crate::models::{fill_tributes, get_game_by_id};
 use crate::gui::components::ShowModal;
@@ -7,6 +8,7 @@ use crate::gui::components::button::Button;
 use crate::gui::components::create_tribute::CreateTribute;
 use crate::gui::components::tribute_list::TributeList;
 use crate::gui::components::fill_tributes_button::FillTributesButton;
+use crate::gui::components::roster_upload::RosterUpload;
 use crate::tributes::actors::Tribute;
 
 #[component]
@@ -19,22 +21,22 @@ pub fn GameDetail(id: i32) -> Element {
         Link {
             to: Routes::Home {},
             class: "flex flex-row items-center gap-2 justify-center text-yellow-900 dark:text-yellow-500 underline",
-            "Home"
+            {t("gui-home")}
         }
         div {
             class: "flex justify-between items-center",
             h2 {
                 class: "text-2xl font-bold text-yellow-800 dark:text-yellow-800 orbitron-font tracking-wider",
-                "Game ",
+                {format!("{} ", t("gui-game"))},
                 span {
                     class: "font-normal text-red-700 dark:text-yellow-500 tracking-normal",
                     "{game.name}"
                 },
             }
             if game.status == crate::games::GameStatus::InProgress ||
-                (game.status == crate::games::GameStatus::NotStarted && tributes.read().len() == 24) {
+                (game.status == crate::games::GameStatus::NotStarted && tributes.read().len() == game.roster_size as usize) {
                     Button {
-                        text: "Play next day",
+                        text: t("gui-play-next-day"),
                         onclick: move |_| {
                             let nav = navigator();
                             nav.push(Routes::GamePlay { id: game.id.unwrap() });
@@ -42,12 +44,16 @@ pub fn GameDetail(id: i32) -> Element {
                     }
             }
         }
+        p {
+            class: "text-sm text-yellow-800 dark:text-yellow-500",
+            {format!("{}: {} · {}", t("gui-ruleset"), game.ruleset.localized(&gui_locale()), game.win_condition.localized(&gui_locale()))}
+        }
         if game.day.unwrap_or(0) > 0 {
             div {
                 class: "flex justify-between items-center",
                 h4 {
                     class: "text-md text-yellow-800 dark:text-yellow-800 orbitron-font",
-                    "Day Log"
+                    {t("gui-day-log")}
                 }
                 for day in 1..=game.day.unwrap_or(0) {
                     Link {
@@ -59,7 +65,7 @@ pub fn GameDetail(id: i32) -> Element {
                 Link {
                     class: "underline text-yellow-800 dark:text-yellow-500",
                     to: Routes::GameLog { id: game.id.unwrap() },
-                    "Full Log"
+                    {t("gui-full-log")}
                 }
             }
         }
@@ -67,23 +73,28 @@ pub fn GameDetail(id: i32) -> Element {
         if game.status == crate::games::GameStatus::Finished {
             h4 {
                 class: "text-xl text-red-800 dark:text-yellow-500 orbitron-font text-center mt-4",
-                if game.winner().is_some() {
-                    "{game.winner().unwrap().name} wins!"
+                if game.winners().is_empty() {
+                    {t("gui-no-one-wins")}
                 } else {
-                    "No one wins!"
+                    {t_with("gui-wins", &[("name", game.winners().iter().map(|w| w.name.clone()).collect::<Vec<String>>().join(", "))])}
                 }
             }
         }
 
-        if tributes.read().len() < 24 {
+        if tributes.read().len() < game.roster_size as usize {
             div {
                 class: "items-justify mt-4 flex flex-row justify-start gap-2",
                 CreateTribute {signal: tributes.clone(), game_id: game.id.unwrap()}
                 span {
                     class: "leading-9 text-sm dark:text-slate-200 w-min",
-                    "or"
+                    {t("gui-or")}
                 }
                 FillTributesButton { }
+                span {
+                    class: "leading-9 text-sm dark:text-slate-200 w-min",
+                    {t("gui-or")}
+                }
+                RosterUpload { tributes, game_id: game.id.unwrap() }
             }
             ConfirmFillModal { id: game.id.unwrap(), tributes }
         }
@@ -118,25 +129,25 @@ fn ConfirmFillModal(id: i32, mut tributes: Signal<Vec<Tribute>>) -> Element {
                                 class: "flex-1",
                                 strong {
                                     class: "block font-medium text-gray-900 dark:text-gray-50",
-                                    "Fill with tributes?"
+                                    {t("gui-fill-with-tributes")}
                                 }
                                 p {
                                     class: "mt-1 text-sm text-gray-700 dark:text-gray-300",
-                                    {format!("Are you sure you want to fill {} with tributes?", game.name)}
+                                    {t_with("gui-confirm-fill", &[("name", game.name.clone())])}
                                 }
                             }
                             div {
                                 class: "flex justify-end gap-4 mt-4",
                                 Button {
-                                    text: "Yes",
+                                    text: t("gui-yes"),
                                     onclick: move |_| {
-                                        fill_tributes(&game);
+                                        fill_tributes(&game, false);
                                         tributes.set(Game::from(game.clone()).tributes());
                                         state.write().show = false;
                                     }
                                 }
                                 Button {
-                                    text: "No",
+                                    text: t("gui-no"),
                                     extra_css_classes: "bg-gray-200 dark:bg-gray-500 bg-none".to_string(),
                                     onclick: move |_| {
                                         state.write().show = false;
@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+use crate::i18n::{t, t_with};
 use crate::games::Game;
 use crate::gui::router::Routes;
 use crate::models::{get_game_by_id, get_logs_for_game_day, LogEntry};
@@ -11,24 +12,24 @@ pub fn GameLog(id: i32) -> Element {
         Link {
             to: Routes::Home {},
             class: "flex flex-row items-center gap-2 justify-center",
-            "Home"
+            {t("gui-home")}
         }
         div {
             class: "flex flex-row items-center gap-2 justify-center text-yellow-900 dark:text-yellow-500 divide-x divide-yellow-900 dark:divide-yellow-500 mb-4 underline",
             Link {
                 to: Routes::Home {},
-                "Home"
+                {t("gui-home")}
             }
             Link {
                 to: Routes::GameDetail { id: game.id.unwrap() },
                 class: "pl-2",
-                "Back to game"
+                {t("gui-back-to-game")}
             }
         }
         div {
             h2 {
                 class: "text-2xl font-bold text-yellow-800 orbitron-font tracking-wider",
-                "Game ",
+                {format!("{} ", t("gui-game"))},
                 span {
                     class: "font-normal text-red-800 dark:text-yellow-500 tracking-normal",
                     "{game.name}"
@@ -42,7 +43,7 @@ pub fn GameLog(id: i32) -> Element {
                             h2 {
                                 class: "text-xl font-bold orbitron-font tracking-wider text-yellow-800",
                                 id: "day-{day}",
-                                "Day "
+                                {format!("{} ", t("gui-day"))}
                                 span {
                                     class: "font-normal text-red-800 dark:text-yellow-500 tracking-normal",
                                     "{day}"
@@ -60,7 +61,7 @@ pub fn GameLog(id: i32) -> Element {
                 div {
                     h2 {
                         class: "text-lg orbitron-font font-bold tracking-wider text-yellow-800",
-                        "Days"
+                        {t("gui-days")}
                     }
                     ol {
                         for day in 1..=game.day.unwrap() {
@@ -68,7 +69,7 @@ pub fn GameLog(id: i32) -> Element {
                                 class: "text-yellow-900 dark:text-yellow-500 underline",
                                 Link {
                                     to: Routes::GameDayLog { id: game.id.unwrap(), day },
-                                    "Day {day}"
+                                    {t_with("gui-day-number", &[("day", day.to_string())])}
                                 }
                             }
                         }
@@ -90,7 +91,7 @@ pub fn LogListItem(log: LogEntry) -> Element {
         rsx! {
             li {
                 class: classes,
-                "{log.message} by {tribute.name}"
+                {t_with("gui-log-by", &[("message", log.message.clone()), ("tribute", tribute.name.clone())])}
             }
         }
     } else {
@@ -9,7 +9,7 @@ pub fn GamePlay(id: i32) -> Element {
     let nav = navigator();
     game.run_day_night_cycle();
 
-    if game.status == GameStatus::InProgress && game.living_tributes().len() <= 1 {
+    if game.status == GameStatus::InProgress && game.is_over() {
         game.status = GameStatus::Finished;
         game.end();
     }
@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+use crate::i18n::{t, t_with};
 use crate::games::Game;
 use crate::gui::states::HGState;
 use crate::gui::components::game_table_row::GameTableRow;
@@ -22,23 +23,23 @@ pub fn GameTable() -> Element {
                         class: "",
                         th {
                             class: "whitespace-nowrap font-semibold pl-4",
-                            "Game Name"
+                            {t("gui-game-name")}
                         }
                         th {
                             class: "font-semibold",
-                            "Day"
+                            {t("gui-day")}
                         }
                         th {
                             class: "whitespace-nowrap font-semibold",
-                            "Living Tributes"
+                            {t("gui-living-tributes")}
                         }
                         th {
                             class: "font-semibold",
-                            "Winner"
+                            {t("gui-winner")}
                         }
                         th {
                             class: "font-semibold text-right pr-4",
-                            "Actions"
+                            {t("gui-actions")}
                         }
                     }
                 }
@@ -81,17 +82,17 @@ fn ConfirmDeleteGameModal() -> Element {
                                 class: "flex-1",
                                 strong {
                                     class: "block font-medium text-gray-900 dark:text-gray-50",
-                                    "Delete game?"
+                                    {t("gui-delete-game")}
                                 }
                                 p {
                                     class: "mt-1 text-sm text-gray-700 dark:text-gray-300",
-                                    {format!("Are you sure you want to delete {}?", game.name)}
+                                    {t_with("gui-confirm-delete", &[("name", game.name.clone())])}
                                 }
                             }
                             div {
                                 class: "flex justify-end gap-4 mt-4",
                                 Button {
-                                    text: "Yes",
+                                    text: t("gui-yes"),
                                     onclick: move |_| {
                                         Game::delete(selected_game.read().id);
                                         state.write().games.retain(|g| g.id.unwrap() != selected_game.read().id);
@@ -100,7 +101,7 @@ fn ConfirmDeleteGameModal() -> Element {
                                     }
                                 }
                                 Button {
-                                    text: "No",
+                                    text: t("gui-no"),
                                     extra_css_classes: "bg-none bg-gray-200 dark:bg-gray-500".to_string(),
                                     onclick: move |_| {
                                         selected_game.write().id = -1;
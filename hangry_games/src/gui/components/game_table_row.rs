@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+use crate::i18n::t;
 use crate::games::{Game, GameStatus};
 use crate::gui::components::game_actions_group::GameActionsGroup;
 
@@ -15,18 +16,15 @@ pub fn GameTableRow(game: Game) -> Element {
                 "{game.day.unwrap_or(0)}"
             }
             td {
-                "{game.living_tributes().len()}/24"
+                "{game.living_tributes().len()}/{game.roster_size}"
             }
             td {
                 {
-                    match game.winner() {
-                        Some(winner) => winner.name,
-                        None => {
-                            match game.status {
-                                GameStatus::Finished => "No winner".to_string(),
-                                _ => "".to_string(),
-                            }
-                        }
+                    let winners = game.winners();
+                    match (winners.is_empty(), &game.status) {
+                        (false, _) => winners.iter().map(|w| w.name.clone()).collect::<Vec<String>>().join(", "),
+                        (true, GameStatus::Finished) => t("gui-no-winner"),
+                        (true, _) => "".to_string(),
                     }
                 }
             }
@@ -1,4 +1,6 @@
 use dioxus::prelude::*;
+use crate::i18n::t;
+use crate::gui::router::Routes;
 use crate::gui::components::{
     game_table::GameTable,
     create_game::CreateGame,
@@ -8,6 +10,11 @@ use crate::gui::components::{
 pub fn Home() -> Element {
     rsx! {
         CreateGame {}
+        Link {
+            to: Routes::Standings {},
+            class: "flex flex-row items-center gap-2 justify-center text-yellow-900 dark:text-yellow-500 underline",
+            {t("gui-standings")}
+        }
         GameTable {}
     }
 }
@@ -13,6 +13,8 @@ pub mod header;
 pub mod home;
 pub mod input;
 pub mod input_with_label;
+pub mod roster_upload;
+pub mod standings;
 pub mod tribute_actions_group;
 pub mod tribute_box;
 pub mod tribute_boxes;
@@ -0,0 +1,58 @@
+use std::path::Path;
+use dioxus::prelude::*;
+use crate::i18n::t;
+use crate::models::get_game_by_id;
+use crate::tributes::actors::Tribute;
+use crate::tributes::rosters::{import_roster, read_roster, RosterFormat};
+
+#[component]
+pub fn RosterUpload(mut tributes: Signal<Vec<Tribute>>, game_id: i32) -> Element {
+    let mut errors = use_signal(Vec::<String>::new);
+
+    let upload_roster = move |evt: FormEvent| async move {
+        let Some(file_engine) = evt.files() else { return };
+        for file_name in file_engine.files() {
+            let Some(format) = RosterFormat::from_path(Path::new(&file_name)) else {
+                errors.set(vec![t("gui-roster-format")]);
+                continue;
+            };
+            let Some(contents) = file_engine.read_file_to_string(&file_name).await else { continue };
+            let game = get_game_by_id(game_id).unwrap();
+            match read_roster(&contents, format, game.districts).and_then(|rows| import_roster(&game, &rows)) {
+                Ok(imported) => {
+                    tributes.write().extend(imported);
+                    errors.set(vec![]);
+                }
+                Err(row_errors) => errors.set(row_errors.iter().map(|e| e.to_string()).collect()),
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1",
+            label {
+                class: "leading-9 text-sm dark:text-slate-200 cursor-pointer",
+                r#for: "roster_input",
+                {t("gui-import-roster")}
+            }
+            input {
+                class: "w-full cursor-pointer rounded-md border border-orange-700 bg-yellow-200 px-2 py-1 text-gray-900 focus:outline-none",
+                id: "roster_input",
+                r#type: "file",
+                accept: ".csv,.json",
+                multiple: false,
+
+                onchange: upload_roster
+            }
+            if !errors.read().is_empty() {
+                ul {
+                    class: "text-sm text-red-700 dark:text-red-400",
+                    for error in errors.read().iter() {
+                        li { "{error}" }
+                    }
+                }
+            }
+        }
+    }
+}
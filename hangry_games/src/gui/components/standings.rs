@@ -0,0 +1,60 @@
+use dioxus::prelude::*;
+use crate::i18n::t;
+use crate::gui::router::Routes;
+use crate::models::get_standings;
+
+#[component]
+pub fn Standings() -> Element {
+    let spectators = get_standings();
+
+    rsx! {
+        Link {
+            to: Routes::Home {},
+            class: "flex flex-row items-center gap-2 justify-center text-yellow-900 dark:text-yellow-500 underline",
+            {t("gui-home")}
+        }
+        h2 {
+            class: "text-2xl font-bold text-yellow-800 dark:text-yellow-800 orbitron-font tracking-wider",
+            {t("gui-standings")}
+        }
+        if spectators.is_empty() {
+            p {
+                class: "text-yellow-700",
+                {t("gui-no-spectators")}
+            }
+        }
+        table {
+            class: "min-w-full mt-4 divide-y dark:divide-yellow-200 divide-yellow-500",
+            thead {
+                class: "text-sm text-left dark:text-yellow-500 text-yellow-900 tracking-wide font-semibold",
+                tr {
+                    th { class: "font-semibold pl-4", "#" }
+                    th { class: "font-semibold", {t("gui-name")} }
+                    th { class: "font-semibold", {t("gui-balance")} }
+                    th { class: "font-semibold", {t("gui-open-bets")} }
+                    th { class: "font-semibold text-right pr-4", {t("gui-bets-won")} }
+                }
+            }
+            tbody {
+                for (place, spectator) in spectators.iter().enumerate() {
+                    {
+                        let bets = spectator.bets();
+                        let open = bets.iter().filter(|b| !b.is_settled()).count();
+                        let settled = bets.iter().filter(|b| b.is_settled()).count();
+                        let won = bets.iter().filter(|b| b.payout.unwrap_or(0) > 0).count();
+                        rsx! {
+                            tr {
+                                class: "text-yellow-700 dark:text-yellow-700",
+                                td { class: "pl-4", "{place + 1}" }
+                                td { "{spectator.name}" }
+                                td { "{spectator.balance}" }
+                                td { "{open}" }
+                                td { class: "text-right pr-4", "{won}/{settled}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+use crate::i18n::t_with;
 use crate::gui::components::tribute_actions_group::TributeActionsGroup;
 use crate::tributes::actors::Tribute;
 
@@ -23,7 +24,7 @@ pub fn TributeBox(tribute: Tribute, signal: Signal<Vec<Tribute>>) -> Element {
                 class: "relative p-4 sm:p-6 lg:p-8",
                 p {
                     class: "text-sm font-medium uppercase tracking-widest text-orange-500",
-                    "District {tribute.district}"
+                    {t_with("gui-district-number", &[("district", tribute.district.to_string())])}
                 }
                 p {
                     class: "text-xl font-bold text-white sm:text-2xl drop-shadow",
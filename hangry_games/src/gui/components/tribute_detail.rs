@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+use crate::i18n::t;
 use crate::models::{get_game_by_id, get_tribute_by_id};
 use crate::tributes::actors::Tribute;
 use crate::gui::router::Routes;
@@ -13,18 +14,18 @@ pub fn TributeDetail(id: i32) -> Element {
         Link {
             to: Routes::Home {},
             class: "flex flex-row items-center gap-2 justify-center",
-            "Home"
+            {t("gui-home")}
         }
         div {
             class: "flex flex-row items-center gap-2 justify-center text-yellow-900 dark:text-yellow-500 divide-x divide-yellow-900 dark:divide-yellow-500 mb-4 underline",
             Link {
                 to: Routes::Home {},
-                "Home"
+                {t("gui-home")}
             }
             Link {
                 to: Routes::GameDetail { id: tribute.read().game_id.unwrap() },
                 class: "pl-2",
-                "Back to game"
+                {t("gui-back-to-game")}
             }
         }
         div {
@@ -41,7 +42,7 @@ pub fn TributeDetail(id: i32) -> Element {
                 }
                 h2 {
                     class: "text-xl orbitron-font font-bold tracking-wider dark:text-yellow-800",
-                    "District ",
+                    {format!("{} ", t("gui-district"))},
                     span {
                         class: "font-normal dark:text-yellow-500 tracking-normal",
                         "{tribute.read().district}"
@@ -49,7 +50,7 @@ pub fn TributeDetail(id: i32) -> Element {
                 }
                 h3 {
                     class: "text-large font-bold orbitron-font tracking-wider dark:text-yellow-800",
-                    "Game ",
+                    {format!("{} ", t("gui-game"))},
                     span {
                         class: "font-normal dark:text-yellow-500 tracking-normal",
                         "{game.name}"
@@ -59,7 +60,7 @@ pub fn TributeDetail(id: i32) -> Element {
                     class: "mt-4 grid grid-cols-3 gap-1",
                     dt {
                         class: "font-medium text-right pr-4",
-                        "Status"
+                        {t("gui-status")}
                     }
                     dd {
                         class: "col-span-2",
@@ -67,80 +68,80 @@ pub fn TributeDetail(id: i32) -> Element {
                     }
                     dt {
                         class: "font-medium text-right pr-4",
-                        "Attributes"
+                        {t("gui-attributes")}
                     }
                     dd {
                         class: "col-span-2",
                         dl {
                             class: "grid grid-cols-2 gap-1",
                             dt {
-                                "Health"
+                                {t("gui-health")}
                             }
                             dd {
                                 "{tribute.read().health}/100"
                             }
                             dt {
-                                "Sanity"
+                                {t("gui-sanity")}
                             }
                             dd {
                                 "{tribute.read().sanity}/100"
                             }
                             dt {
-                                "Movement"
+                                {t("gui-movement")}
                             }
                             dd {
                                 "{tribute.read().movement}/100"
                             }
                             dt {
-                                "Strength"
+                                {t("gui-strength")}
                             }
                             dd {
                                 "{tribute.read().strength.unwrap()}/50"
                             }
                             dt {
-                                "Defense"
+                                {t("gui-defense")}
                             }
                             dd {
                                 "{tribute.read().defense.unwrap()}/50"
                             }
                             dt {
-                                "Bravery"
+                                {t("gui-bravery")}
                             }
                             dd {
                                 "{tribute.read().bravery.unwrap()}/100"
                             }
                             dt {
-                                "Loyalty"
+                                {t("gui-loyalty")}
                             }
                             dd {
                                 "{tribute.read().loyalty.unwrap()}/100"
                             }
                             dt {
-                                "Speed"
+                                {t("gui-speed")}
                             }
                             dd {
                                 "{tribute.read().speed.unwrap()}/100"
                             }
                             dt {
-                                "Intelligence"
+                                {t("gui-intelligence")}
                             }
                             dd {
                                 "{tribute.read().intelligence.unwrap()}/100"
                             }
                             dt {
-                                "Persuasion"
+                                {t("gui-persuasion")}
                             }
                             dd {
                                 "{tribute.read().persuasion.unwrap()}/100"
                             }
                             dt {
-                                "Luck"
+                                {t("gui-luck")}
                             }
                             dd {
                                 "{tribute.read().luck.unwrap()}/100"
                             }
                             dt {
-                                "Dexterity"
+                                {t("gui-dexterity")}
                             }
                             dd {
                                 "{tribute.read().dexterity.unwrap()}/100"
@@ -149,60 +150,76 @@ pub fn TributeDetail(id: i32) -> Element {
                     }
                     dt {
                         class: "font-medium text-right pr-4",
-                        "Statistics"
+                        {t("gui-statistics")}
                     }
                     dd {
                         class: "col-span-2",
                         dl {
                             class: "grid grid-cols-2 gap-1",
                             dt {
-                                "Kills"
+                                {t("gui-kills")}
                             }
                             dd {
                                 "{tribute.read().kills.unwrap_or(0)}"
                             }
                             dt {
-                                "Wins"
+                                {t("gui-wins-count")}
                             }
                             dd {
                                 "{tribute.read().wins.unwrap_or(0)}"
                             }
                             dt {
-                                "Defeats"
+                                {t("gui-defeats")}
                             }
                             dd {
                                 "{tribute.read().defeats.unwrap_or(0)}"
                             }
                             dt {
-                                "Draws"
+                                {t("gui-draws")}
                             }
                             dd {
                                 "{tribute.read().draws.unwrap_or(0)}"
                             }
                             dt {
-                                "Games"
+                                {t("gui-games")}
                             }
                             dd {
                                 "{tribute.read().games.unwrap_or(0)}"
                             }
+                            if let Some(score) = tribute.read().training_score {
+                                dt {
+                                    {t("gui-training-score")}
+                                }
+                                dd {
+                                    "{score}/12"
+                                }
+                            }
+                            if let Some(likability) = tribute.read().likability {
+                                dt {
+                                    {t("gui-likability")}
+                                }
+                                dd {
+                                    "{likability}/100"
+                                }
+                            }
                         }
                     }
                     if !tribute.read().is_alive() {
                         dt {
                             class: "font-medium text-right pr-4",
-                            "Death"
+                            {t("gui-death")}
                         }
                         dd {
                             dl {
                                 class: "grid grid-cols-2 gap-1",
                                 dt {
-                                    "Day Killed"
+                                    {t("gui-day-killed")}
                                 }
                                 dd {
                                     "{tribute.read().day_killed.unwrap_or(0)}"
                                 }
                                 dt {
-                                    "Killed By"
+                                    {t("gui-killed-by")}
                                 }
                                 dd {
                                     {
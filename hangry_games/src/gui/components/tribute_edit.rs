@@ -1,21 +1,31 @@
 use std::path::Path;
 use std::rc::Rc;
+use std::str::FromStr;
 use std::sync::Arc;
 use dioxus::html::FileEngine;
 use dioxus::prelude::*;
+use crate::i18n::{t, t_with};
 use crate::gui::components::UploadedFile;
 use crate::gui::components::button::Button;
 use crate::gui::components::input_with_label::InputWithLabel;
 use crate::gui::router::Routes;
-use crate::models::{get_tribute_by_id, UpdateTribute};
+use crate::models::{get_game_by_id, get_tribute_by_id, UpdateTribute};
 use crate::tributes::actors::Tribute;
+use crate::tributes::point_buy::Preset;
+use crate::tributes::pronouns::Pronouns;
+use strum::IntoEnumIterator;
 
 #[component]
 pub fn TributeEdit(id: i32) -> Element {
     let nav = navigator();
-    let mut tribute = use_signal(|| Tribute::from(get_tribute_by_id(id)));
+    let saved = use_signal(|| Tribute::from(get_tribute_by_id(id)));
+    let mut tribute = use_signal(|| saved.read().clone());
     let mut tribute_name = use_signal(|| tribute.read().name.clone());
     let mut files_uploaded = use_signal(|| Vec::new() as Vec<crate::gui::components::UploadedFile>);
+    let mut stat_errors = use_signal(Vec::<String>::new);
+    let point_buy = get_game_by_id(tribute.read().game_id.unwrap()).unwrap().point_buy();
+    let preset_point_buy = point_buy.clone();
+    let submit_point_buy = point_buy.clone();
 
     let read_files = move |file_engine: Arc<dyn FileEngine>| async move {
         let files = file_engine.files();
@@ -40,12 +50,12 @@ pub fn TributeEdit(id: i32) -> Element {
             class: "flex flex-row items-center gap-2 justify-center text-yellow-900 dark:text-yellow-500 divide-x divide-yellow-900 dark:divide-yellow-500 mb-4 underline",
             Link {
                 to: Routes::Home {},
-                "Home"
+                {t("gui-home")}
             }
             Link {
                 to: Routes::GameDetail { id: tribute.read().game_id.unwrap() },
                 class: "pl-2",
-                "Back to game"
+                {t("gui-back-to-game")}
             }
         }
         div {
@@ -53,6 +63,12 @@ pub fn TributeEdit(id: i32) -> Element {
                 class: "grid grid-cols-2 justify-items-center gap-x-4 gap-y-2",
                 enctype: "multipart/form-data",
                 onsubmit: move |event| {
+                    // Hand-built stats have to fit the game's budget
+                    if let Err(problems) = submit_point_buy.check_edit(&saved.read(), &tribute.read()) {
+                        stat_errors.set(problems);
+                        return;
+                    }
+
                     let data = event.data.values();
                     let name = data.get("tribute-name").unwrap().first().unwrap();
                     let image = files_uploaded.read();
@@ -99,6 +115,14 @@ pub fn TributeEdit(id: i32) -> Element {
                         status: tribute.read().status.to_string(),
                         avatar: if new_avatar_path.is_some() { Some(new_avatar_path.unwrap()) } else { tribute.read().avatar.clone() },
                         real_name: tribute.read().real_name.clone(),
+                        destination_id: Some(tribute.read().destination.as_ref().map(|a| a.id())),
+                        stealth: tribute.read().stealth,
+                        breakdown: Some(tribute.read().breakdown.as_ref().map(|b| b.to_string())),
+                        pronouns: Some(tribute.read().pronouns.as_ref().map(|p| p.to_string())),
+                        training_score: tribute.read().training_score,
+                        likability: tribute.read().likability,
+                        incubating: Some(tribute.read().incubating.as_ref().map(|s| s.to_string())),
+                        incubation: Some(tribute.read().incubation),
                     };
                     Tribute::update(&tribute.read(), update);
 
@@ -116,7 +140,7 @@ pub fn TributeEdit(id: i32) -> Element {
                         class: "w-64 cursor-pointer rounded-md border border-orange-700 bg-yellow-200 px-2 py-1 text-gray-900 focus:outline-none mt-2",
                         id: "file_input",
                         r#type: "file",
-                        placeholder: "Upload Image",
+                        placeholder: t("gui-upload-image"),
                         accept: "image/png,image/gif,image/jpg",
                         multiple: false,
 
@@ -128,122 +152,178 @@ pub fn TributeEdit(id: i32) -> Element {
                     div {
                         class: "flex flex-row flex-nowrap gap-2 w-full mb-2",
                         InputWithLabel {
-                            label: "Name".to_string(),
+                            label: t("gui-name"),
                             value: tribute.read().name.clone(),
                             name: "tribute-name".to_string(),
-                            placeholder: "Name".to_string(),
+                            placeholder: t("gui-name"),
                             oninput: move |evt: Rc<FormData>| tribute.write().name = evt.value().clone(),
                             extra_css_classes: Some("w-72".to_string())
                         }
                         InputWithLabel {
-                            label: "District".to_string(),
+                            label: t("gui-district"),
                             value: tribute.read().district.clone(),
                             name: "tribute-district".to_string(),
-                            placeholder: "District".to_string(),
-                            oninput: move |evt: Rc<FormData>| tribute.write().district = evt.value().parse::<i32>().unwrap()
+                            placeholder: t("gui-district"),
+                            oninput: move |evt: Rc<FormData>| if let Ok(value) = evt.value().parse::<i32>() { tribute.write().district = value }
                         }
                     }
                     div {
                         class: "w-full mb-2",
                         InputWithLabel {
-                            label: "Real name".to_string(),
+                            label: t("gui-real-name"),
                             value: tribute.read().real_name.clone().unwrap_or("".to_string()),
                             name: "tribute-real-name".to_string(),
-                            placeholder: "Real name".to_string(),
+                            placeholder: t("gui-real-name"),
                             oninput: move |evt: Rc<FormData>| tribute.write().real_name = Some(evt.value().clone()),
                             extra_css_classes: Some("w-72".to_string())
                         }
+                        InputWithLabel {
+                            label: t("gui-pronouns"),
+                            value: tribute.read().pronouns.as_ref().map(|p| p.to_string()).unwrap_or("".to_string()),
+                            name: "tribute-pronouns".to_string(),
+                            placeholder: "they/them".to_string(),
+                            // Blank clears the set, anything unparseable keeps the last good one
+                            oninput: move |evt: Rc<FormData>| {
+                                let value = evt.value();
+                                if value.trim().is_empty() {
+                                    tribute.write().pronouns = None;
+                                } else if let Ok(pronouns) = Pronouns::from_str(&value) {
+                                    tribute.write().pronouns = Some(pronouns);
+                                }
+                            },
+                            extra_css_classes: Some("w-72".to_string())
+                        }
                     }
                     div {
                         class: "grid grid-row gap-2 grid-cols-4 w-full",
                         InputWithLabel {
-                            label: "Health".to_string(),
+                            label: t("gui-health"),
                             value: tribute.read().health.clone(),
                             name: "tribute-health".to_string(),
-                            placeholder: "Health".to_string(),
-                            oninput: move |evt: Rc<FormData>| tribute.write().health = evt.value().parse::<i32>().unwrap()
+                            placeholder: t("gui-health"),
+                            oninput: move |evt: Rc<FormData>| if let Ok(value) = evt.value().parse::<i32>() { tribute.write().health = value }
                         }
                         InputWithLabel {
-                            label: "Sanity".to_string(),
+                            label: t("gui-sanity"),
                             value: tribute.read().sanity.clone(),
                             name: "tribute-sanity".to_string(),
-                            placeholder: "Sanity".to_string(),
-                            oninput: move |evt: Rc<FormData>| tribute.write().sanity = evt.value().parse::<i32>().unwrap()
+                            placeholder: t("gui-sanity"),
+                            oninput: move |evt: Rc<FormData>| if let Ok(value) = evt.value().parse::<i32>() { tribute.write().sanity = value }
                         }
                         InputWithLabel {
-                            label: "Movement".to_string(),
+                            label: t("gui-movement"),
                             value: tribute.read().movement.clone(),
                             name: "tribute-movement".to_string(),
-                            placeholder: "Movement".to_string(),
-                            oninput: move |evt: Rc<FormData>| tribute.write().movement = evt.value().parse::<i32>().unwrap()
+                            placeholder: t("gui-movement"),
+                            oninput: move |evt: Rc<FormData>| if let Ok(value) = evt.value().parse::<i32>() { tribute.write().movement = value }
                         }
                         InputWithLabel {
-                            label: "Bravery".to_string(),
+                            label: t("gui-bravery"),
                             value: tribute.read().bravery.clone().unwrap_or(0).to_string(),
                             name: "tribute-bravery".to_string(),
-                            placeholder: "Bravery".to_string(),
-                            oninput: move |evt: Rc<FormData>| tribute.write().bravery = Some(evt.value().parse::<i32>().unwrap())
+                            placeholder: t("gui-bravery"),
+                            oninput: move |evt: Rc<FormData>| if let Ok(value) = evt.value().parse::<i32>() { tribute.write().bravery = Some(value) }
                         }
                         InputWithLabel {
-                            label: "Loyalty".to_string(),
+                            label: t("gui-loyalty"),
                             value: tribute.read().loyalty.clone().unwrap_or(0).to_string(),
                             name: "tribute-loyalty".to_string(),
-                            placeholder: "Loyalty".to_string(),
-                            oninput: move |evt: Rc<FormData>| tribute.write().loyalty = Some(evt.value().parse::<i32>().unwrap())
+                            placeholder: t("gui-loyalty"),
+                            oninput: move |evt: Rc<FormData>| if let Ok(value) = evt.value().parse::<i32>() { tribute.write().loyalty = Some(value) }
                         }
                         InputWithLabel {
-                            label: "Speed".to_string(),
+                            label: t("gui-speed"),
                             value: tribute.read().speed.clone().unwrap_or(0).to_string(),
                             name: "tribute-speed".to_string(),
-                            placeholder: "Speed".to_string(),
-                            oninput: move |evt: Rc<FormData>| tribute.write().speed = Some(evt.value().parse::<i32>().unwrap())
+                            placeholder: t("gui-speed"),
+                            oninput: move |evt: Rc<FormData>| if let Ok(value) = evt.value().parse::<i32>() { tribute.write().speed = Some(value) }
                         }
                         InputWithLabel {
-                            label: "Intelligence".to_string(),
+                            label: t("gui-intelligence"),
                             value: tribute.read().intelligence.clone().unwrap_or(0).to_string(),
                             name: "tribute-intelligence".to_string(),
-                            placeholder: "Intelligence".to_string(),
-                            oninput: move |evt: Rc<FormData>| tribute.write().intelligence = Some(evt.value().parse::<i32>().unwrap())
+                            placeholder: t("gui-intelligence"),
+                            oninput: move |evt: Rc<FormData>| if let Ok(value) = evt.value().parse::<i32>() { tribute.write().intelligence = Some(value) }
                         }
                         InputWithLabel {
-                            label: "Persuasion".to_string(),
+                            label: t("gui-persuasion"),
                             value: tribute.read().persuasion.clone().unwrap_or(0).to_string(),
                             name: "tribute-persuasion".to_string(),
-                            placeholder: "Persuasion".to_string(),
-                            oninput: move |evt: Rc<FormData>| tribute.write().persuasion = Some(evt.value().parse::<i32>().unwrap())
+                            placeholder: t("gui-persuasion"),
+                            oninput: move |evt: Rc<FormData>| if let Ok(value) = evt.value().parse::<i32>() { tribute.write().persuasion = Some(value) }
                         }
                         InputWithLabel {
-                            label: "Luck".to_string(),
+                            label: t("gui-luck"),
                             value: tribute.read().luck.clone().unwrap_or(0).to_string(),
                             name: "tribute-luck".to_string(),
-                            placeholder: "Luck".to_string(),
-                            oninput: move |evt: Rc<FormData>| tribute.write().luck = Some(evt.value().parse::<i32>().unwrap())
+                            placeholder: t("gui-luck"),
+                            oninput: move |evt: Rc<FormData>| if let Ok(value) = evt.value().parse::<i32>() { tribute.write().luck = Some(value) }
                         }
                         InputWithLabel {
-                            label: "strength".to_string(),
+                            label: t("gui-strength"),
                             value: tribute.read().strength.clone().unwrap_or(0).to_string(),
                             name: "tribute-strength".to_string(),
-                            placeholder: "strength".to_string(),
-                            oninput: move |evt: Rc<FormData>| tribute.write().strength = Some(evt.value().parse::<i32>().unwrap())
+                            placeholder: t("gui-strength"),
+                            oninput: move |evt: Rc<FormData>| if let Ok(value) = evt.value().parse::<i32>() { tribute.write().strength = Some(value) }
                         }
                         InputWithLabel {
-                            label: "defense".to_string(),
+                            label: t("gui-defense"),
                             value: tribute.read().defense.clone().unwrap_or(0).to_string(),
                             name: "tribute-defense".to_string(),
-                            placeholder: "defense".to_string(),
-                            oninput: move |evt: Rc<FormData>| tribute.write().defense = Some(evt.value().parse::<i32>().unwrap())
+                            placeholder: t("gui-defense"),
+                            oninput: move |evt: Rc<FormData>| if let Ok(value) = evt.value().parse::<i32>() { tribute.write().defense = Some(value) }
                         }
                         InputWithLabel {
-                            label: "dexterity".to_string(),
+                            label: t("gui-dexterity"),
                             value: tribute.read().dexterity.clone().unwrap_or(0).to_string(),
                             name: "tribute-dexterity".to_string(),
-                            placeholder: "dexterity".to_string(),
-                            oninput: move |evt: Rc<FormData>| tribute.write().dexterity = Some(evt.value().parse::<i32>().unwrap())
+                            placeholder: t("gui-dexterity"),
+                            oninput: move |evt: Rc<FormData>| if let Ok(value) = evt.value().parse::<i32>() { tribute.write().dexterity = Some(value) }
+                        }
+                    }
+                    div {
+                        class: "flex flex-row gap-2 w-full items-center",
+                        label {
+                            class: "text-sm",
+                            r#for: "tribute-preset",
+                            {t("gui-preset")}
+                        }
+                        select {
+                            class: "block px-2 py-1 text-gray-900 border border-orange-700 rounded-md bg-yellow-200 focus:outline-none",
+                            id: "tribute-preset",
+                            name: "tribute-preset",
+                            onchange: move |evt: Event<FormData>| {
+                                if let Ok(preset) = Preset::from_str(&evt.value()) {
+                                    preset.apply(&mut tribute.write(), &preset_point_buy);
+                                }
+                            },
+                            option { value: "", {t("gui-custom-build")} }
+                            for preset in Preset::iter() {
+                                option {
+                                    value: "{preset}",
+                                    {t(&format!("gui-preset-{}", preset))}
+                                }
+                            }
+                        }
+                        span {
+                            class: "text-sm",
+                            {t_with("gui-points-spent", &[
+                                ("spent", point_buy.spent(&tribute.read()).to_string()),
+                                ("budget", point_buy.budget.to_string()),
+                            ])}
+                        }
+                    }
+                    if !stat_errors.read().is_empty() {
+                        ul {
+                            class: "text-sm text-red-700 dark:text-red-400",
+                            for error in stat_errors.read().iter() {
+                                li { "{error}" }
+                            }
                         }
                     }
                 }
                 Button {
-                    text: "Update Tribute",
+                    text: t("gui-update-tribute"),
                     extra_css_classes: "justify-self-end mr-20 w-min flex-grow"
                 }
             }
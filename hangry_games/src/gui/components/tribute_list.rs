@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+use crate::i18n::{t, t_with};
 use crate::games::Game;
 use crate::gui::components::tribute_list_item::TributeListItem;
 use crate::tributes::actors::Tribute;
@@ -20,7 +21,7 @@ pub fn TributeList(tributes: Signal<Vec<Tribute>>, game: Game) -> Element {
                     span {
                         class:"flex items-center col-span-2 mb-2",
                         span { class:"h-px flex-1 bg-red-800 dark:bg-yellow-500" }
-                        span { class:"shrink-0 px-6 text-red-800 dark:text-yellow-500", "District {tribute_pair[0].district}" }
+                        span { class:"shrink-0 px-6 text-red-800 dark:text-yellow-500", {t_with("gui-district-number", &[("district", tribute_pair[0].district.to_string())])} }
                         span { class:"h-px flex-1 bg-red-800 dark:bg-yellow-500" }
                     }
 
@@ -61,17 +62,17 @@ fn ConfirmDeleteTributeModal(id: i32, mut tributes: Signal<Vec<Tribute>>) -> Ele
                                 class: "flex-1",
                                 strong {
                                     class: "block font-medium text-gray-900 dark:text-gray-50",
-                                    "Delete tribute?"
+                                    {t("gui-delete-tribute")}
                                 }
                                 p {
                                     class: "mt-1 text-sm text-gray-700 dark:text-gray-300",
-                                    {format!("Are you sure you want to delete {}?", tribute.name)}
+                                    {t_with("gui-confirm-delete", &[("name", tribute.name.clone())])}
                                 }
                             }
                             div {
                                 class: "flex justify-end gap-4 mt-4",
                                 Button {
-                                    text: "Yes",
+                                    text: t("gui-yes"),
                                     onclick: move |_| {
                                         Tribute::delete(selected_tribute.read().id);
                                         tributes.write().retain(|t| t.id.unwrap() != selected_tribute.read().id);
@@ -80,7 +81,7 @@ fn ConfirmDeleteTributeModal(id: i32, mut tributes: Signal<Vec<Tribute>>) -> Ele
                                     }
                                 }
                                 Button {
-                                    text: "No",
+                                    text: t("gui-no"),
                                     extra_css_classes: "bg-gray-500 bg-none".to_string(),
                                     onclick: move |_| {
                                         selected_tribute.write().id = -1;
@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+use crate::i18n::t;
 use crate::tributes::actors::Tribute;
 use crate::gui::components::tribute_actions_group::TributeActionsGroup;
 
@@ -12,19 +13,19 @@ pub fn TributeTable(tributes: Signal<Vec<Tribute>>) -> Element {
                 tr {
                     th {
                         class: "whitespace-nowrap px-4 py-2 font-medium text-slate-900",
-                        "Name"
+                        {t("gui-name")}
                     }
                     th {
                         class: "whitespace-nowrap px-4 py-2 font-medium text-slate-900",
-                        "District"
+                        {t("gui-district")}
                     }
                     th {
                         class: "whitespace-nowrap px-4 py-2 font-medium text-slate-900",
-                        "Status"
+                        {t("gui-status")}
                     }
                     th {
                         class: "px-4 py-2",
-                        "Actions"
+                        {t("gui-actions")}
                     }
                 }
             }
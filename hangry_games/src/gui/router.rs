@@ -5,6 +5,7 @@ use crate::gui::components::{
     game_day_log::GameDayLog,
     game_log::GameLog,
     game_play::GamePlay,
+    standings::Standings,
     tribute_detail::TributeDetail,
     tribute_edit::TributeEdit,
 };
@@ -22,6 +23,8 @@ pub enum Routes {
     GameLog { id: i32 },
     #[route("/game/:id/log/day/:day")]
     GameDayLog { id: i32, day: i32 },
+    #[route("/standings")]
+    Standings {},
     #[route("/tribute/:id")]
     TributeDetail { id: i32 },
     #[route("/tribute/:id/edit")]
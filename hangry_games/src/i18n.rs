@@ -0,0 +1,178 @@
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use strum::{EnumIter, IntoEnumIterator};
+use unic_langid::LanguageIdentifier;
+
+/// Languages the game can narrate in. Catalogs live in `locales/<code>/*.ftl`.
+#[derive(Clone, Debug, Default, EnumIter, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum Locale {
+    #[default]
+    English,
+    Spanish,
+    German,
+}
+
+impl Locale {
+    pub fn code(&self) -> &str {
+        match self {
+            Locale::English => "en",
+            Locale::Spanish => "es",
+            Locale::German => "de",
+        }
+    }
+
+    /// The language's name in itself, for pickers.
+    pub fn native_name(&self) -> &str {
+        match self {
+            Locale::English => "English",
+            Locale::Spanish => "Español",
+            Locale::German => "Deutsch",
+        }
+    }
+
+    fn catalogs(&self) -> Vec<&'static str> {
+        match self {
+            Locale::English => vec![
+                include_str!("../locales/en/game.ftl"),
+                include_str!("../locales/en/gui.ftl"),
+            ],
+            Locale::Spanish => vec![
+                include_str!("../locales/es/game.ftl"),
+                include_str!("../locales/es/gui.ftl"),
+            ],
+            Locale::German => vec![
+                include_str!("../locales/de/game.ftl"),
+                include_str!("../locales/de/gui.ftl"),
+            ],
+        }
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl FromStr for Locale {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "en" | "english" => Ok(Locale::English),
+            "es" | "spanish" | "español" | "espanol" => Ok(Locale::Spanish),
+            "de" | "german" | "deutsch" => Ok(Locale::German),
+            _ => Err(()),
+        }
+    }
+}
+
+fn bundles() -> &'static HashMap<Locale, FluentBundle<FluentResource>> {
+    static BUNDLES: OnceLock<HashMap<Locale, FluentBundle<FluentResource>>> = OnceLock::new();
+    BUNDLES.get_or_init(|| {
+        Locale::iter().map(|locale| {
+            let language: LanguageIdentifier = locale.code().parse().expect("Invalid language identifier");
+            let mut bundle = FluentBundle::new_concurrent(vec![language]);
+            // Unicode isolation marks end up in the terminal and the database, leave them out
+            bundle.set_use_isolating(false);
+            for catalog in locale.catalogs() {
+                let resource = FluentResource::try_new(catalog.to_string())
+                    .unwrap_or_else(|_| panic!("Error parsing {} catalog", locale));
+                bundle.add_resource(resource)
+                    .unwrap_or_else(|_| panic!("Duplicate message in {} catalog", locale));
+            }
+            (locale, bundle)
+        }).collect()
+    })
+}
+
+fn format(locale: &Locale, key: &str, attribute: Option<&str>, args: Option<&FluentArgs>) -> Option<String> {
+    let bundle = bundles().get(locale)?;
+    let message = bundle.get_message(key)?;
+    let pattern = match attribute {
+        Some(attribute) => message.get_attribute(attribute)?.value(),
+        None => message.value()?,
+    };
+    let mut errors = vec![];
+    Some(bundle.format_pattern(pattern, args, &mut errors).to_string())
+}
+
+/// Look up `key` in the locale's catalog, falling back to English and then to the key itself.
+pub fn translate(locale: &Locale, key: &str, args: Option<&FluentArgs>) -> String {
+    format(locale, key, None, args)
+        .or_else(|| format(&Locale::English, key, None, args))
+        .unwrap_or_else(|| key.to_string())
+}
+
+/// Like `translate`, for a message attribute such as `area-northeast.location`.
+pub fn translate_attribute(locale: &Locale, key: &str, attribute: &str, args: Option<&FluentArgs>) -> String {
+    format(locale, key, Some(attribute), args)
+        .or_else(|| format(&Locale::English, key, Some(attribute), args))
+        .unwrap_or_else(|| format!("{}.{}", key, attribute))
+}
+
+static GUI_LOCALE: OnceLock<Locale> = OnceLock::new();
+
+/// Pick the language for GUI labels. Only the first call wins.
+pub fn set_gui_locale(locale: Locale) {
+    let _ = GUI_LOCALE.set(locale);
+}
+
+pub fn gui_locale() -> Locale {
+    GUI_LOCALE.get().cloned().unwrap_or_default()
+}
+
+/// A GUI label in the current GUI language.
+pub fn t(key: &str) -> String {
+    translate(&gui_locale(), key, None)
+}
+
+/// A GUI label with arguments, e.g. `t_with("gui-wins", &[("name", winner.name)])`.
+pub fn t_with(key: &str, args: &[(&str, String)]) -> String {
+    let mut fluent_args = FluentArgs::new();
+    for (name, value) in args {
+        fluent_args.set(*name, value.clone());
+    }
+    translate(&gui_locale(), key, Some(&fluent_args))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_from_str() {
+        assert_eq!(Locale::from_str("es"), Ok(Locale::Spanish));
+        assert_eq!(Locale::from_str("Deutsch"), Ok(Locale::German));
+        assert_eq!(Locale::from_str("klingon"), Err(()));
+        for locale in Locale::iter() {
+            assert_eq!(Locale::from_str(locale.code()), Ok(locale));
+        }
+    }
+
+    #[test]
+    fn missing_keys_fall_back() {
+        assert_eq!(translate(&Locale::German, "no-such-key", None), "no-such-key");
+    }
+
+    #[test]
+    fn catalogs_have_every_english_key() {
+        let keys = Locale::English.catalogs().iter()
+            .flat_map(|catalog| catalog.lines())
+            .filter_map(|line| line.split_once(" =").map(|(key, _)| key))
+            .filter(|key| key.starts_with(|c: char| c.is_ascii_lowercase()))
+            .collect::<Vec<&str>>();
+        assert!(!keys.is_empty());
+        for locale in [Locale::Spanish, Locale::German] {
+            let bundle = bundles().get(&locale).unwrap();
+            for key in &keys {
+                assert!(bundle.has_message(key), "{} is missing {}", locale, key);
+            }
+        }
+    }
+}
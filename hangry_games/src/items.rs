@@ -1,13 +1,19 @@
 use crate::areas::Area;
+use crate::feasts::Supplies;
 use crate::games::Game;
+use crate::i18n::{translate, Locale};
 use crate::models::item::{create_item, Item as ItemModel, NewItem};
 use crate::models::{get_area_by_id, get_game_by_id, update_item, UpdateItem};
+use crate::tributes::actors::Tribute;
+use crate::tributes::statuses::TributeStatus;
+use crate::tributes::survival::FOODS;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::str::FromStr;
 use crate::item_name_generator::{generate_shield_name, generate_weapon_name};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Item {
     pub id: Option<i32>,
     pub name: String,
@@ -18,6 +24,11 @@ pub struct Item {
     pub quantity: i32,
     pub attribute: Attribute,
     pub effect: i32,
+    /// Backpacks left at a feast are labeled for one tribute, or for one district.
+    pub for_tribute_id: Option<i32>,
+    pub for_district: Option<i32>,
+    /// What it was crafted from, if it was made rather than found.
+    pub components: Vec<String>,
 }
 
 impl Item {
@@ -52,6 +63,9 @@ impl Item {
             quantity,
             attribute,
             effect,
+            for_tribute_id: None,
+            for_district: None,
+            components: None,
         };
         let item = create_item(new_item);
         Item::from(item)
@@ -78,14 +92,13 @@ impl Item {
     }
 
     pub fn new_weapon(name: String, game_id: Option<i32>, area_id: Option<i32>, tribute_id: Option<i32>) -> Item {
-        let mut rng = rand::thread_rng();
-
-        let item_type = ItemType::Weapon;
-        let quantity = rng.gen_range(1..=2);
-        let attribute = Attribute::Strength;
-        let effect = rng.gen_range(1..=5);
+        let weapon = Item::rolled_weapon(name, &mut rand::thread_rng());
+        Item::create(weapon.name, weapon.item_type.to_string(), weapon.quantity, weapon.attribute.to_string(), weapon.effect, game_id, area_id, tribute_id)
+    }
 
-        Item::create(name, item_type.to_string(), quantity, attribute.to_string(), effect, game_id, area_id, tribute_id)
+    /// A weapon with freshly rolled uses and damage, not yet saved anywhere.
+    pub fn rolled_weapon(name: String, rng: &mut impl Rng) -> Item {
+        Item::unsaved(name, ItemType::Weapon, rng.gen_range(1..=2), Attribute::Strength, rng.gen_range(1..=5))
     }
 
     pub fn new_random_weapon(game_id: Option<i32>, area_id: Option<i32>, tribute_id: Option<i32>) -> Item {
@@ -135,20 +148,43 @@ impl Item {
                 // take hits better
                 item.name = "bear spray".to_string();
             }
+            Attribute::Stealth => unreachable!("Consumables never roll stealth"),
         }
         item.save();
         item
     }
 
     pub fn new_shield(name: String, game_id: Option<i32>, area_id: Option<i32>, tribute_id: Option<i32>) -> Item {
-        let mut rng = rand::thread_rng();
+        let shield = Item::rolled_shield(name, &mut rand::thread_rng());
+        Item::create(shield.name, shield.item_type.to_string(), shield.quantity, shield.attribute.to_string(), shield.effect, game_id, area_id, tribute_id)
+    }
 
-        let item_type = ItemType::Weapon;
-        let quantity = rng.gen_range(1..=3);
-        let attribute = Attribute::Defense;
-        let effect = rng.gen_range(1..=7);
+    /// A shield with freshly rolled uses and protection, not yet saved anywhere.
+    pub fn rolled_shield(name: String, rng: &mut impl Rng) -> Item {
+        Item::unsaved(name, ItemType::Weapon, rng.gen_range(1..=3), Attribute::Defense, rng.gen_range(1..=7))
+    }
 
-        Item::create(name, item_type.to_string(), quantity, attribute.to_string(), effect, game_id, area_id, tribute_id)
+    fn unsaved(name: String, item_type: ItemType, quantity: i32, attribute: Attribute, effect: i32) -> Item {
+        Item {
+            id: None,
+            name,
+            item_type,
+            game_id: None,
+            area_id: None,
+            tribute_id: None,
+            quantity,
+            attribute,
+            effect,
+            for_tribute_id: None,
+            for_district: None,
+            components: vec![],
+        }
+    }
+
+    /// Uses up one of the item's uses. True once there are none left.
+    pub fn wears(&mut self) -> bool {
+        self.quantity -= 1;
+        self.quantity <= 0
     }
 
     pub fn new_random_shield(game_id: Option<i32>, area_id: Option<i32>, tribute_id: Option<i32>) -> Item {
@@ -156,6 +192,71 @@ impl Item {
         Item::new_shield(name, game_id, area_id, tribute_id)
     }
 
+    pub fn new_camouflage(name: String, game_id: Option<i32>, area_id: Option<i32>, tribute_id: Option<i32>) -> Item {
+        let mut rng = rand::thread_rng();
+
+        let item_type = ItemType::Weapon;
+        let quantity = 1;
+        let attribute = Attribute::Stealth;
+        let effect = rng.gen_range(5..=20);
+
+        Item::create(name, item_type.to_string(), quantity, attribute.to_string(), effect, game_id, area_id, tribute_id)
+    }
+
+    pub fn new_random_camouflage(game_id: Option<i32>, area_id: Option<i32>, tribute_id: Option<i32>) -> Item {
+        let mut rng = rand::thread_rng();
+        let name = ["ghillie suit", "leaf cloak", "mud-stained tarp", "mottled poncho"][rng.gen_range(0..4)];
+        Item::new_camouflage(name.to_string(), game_id, area_id, tribute_id)
+    }
+
+    /// A feast backpack packed with `supplies`, labeled for a tribute or a district.
+    pub fn new_backpack(
+        supplies: &Supplies,
+        game_id: Option<i32>,
+        area_id: Option<i32>,
+        for_tribute_id: Option<i32>,
+        for_district: Option<i32>,
+    ) -> Item {
+        let mut rng = rand::thread_rng();
+        let mut item = match supplies {
+            Supplies::Remedy(name) => {
+                let effect = rng.gen_range(10..=20);
+                Item::create(name.clone(), ItemType::Consumable.to_string(), 1, Attribute::Health.to_string(), effect, game_id, area_id, None)
+            }
+            Supplies::Memento => {
+                let effect = rng.gen_range(10..=20);
+                Item::create("memento".to_string(), ItemType::Consumable.to_string(), 1, Attribute::Sanity.to_string(), effect, game_id, area_id, None)
+            }
+            Supplies::Weapon => Item::new_random_weapon(game_id, area_id, None),
+            Supplies::Shield => Item::new_random_shield(game_id, area_id, None),
+        };
+        item.for_tribute_id = for_tribute_id;
+        item.for_district = for_district;
+        item.save();
+        item
+    }
+
+    pub fn is_backpack(&self) -> bool {
+        self.for_tribute_id.is_some() || self.for_district.is_some()
+    }
+
+    /// Whether the tribute can take the item: anything unlabeled, or a backpack meant for them.
+    pub fn is_for(&self, tribute: &Tribute) -> bool {
+        self.for_tribute_id.is_none_or(|id| Some(id) == tribute.id)
+            && self.for_district.is_none_or(|district| district == tribute.district)
+    }
+
+    /// Whether using the item treats the status as well as healing.
+    pub fn cures(&self, status: &TributeStatus) -> bool {
+        let name = if self.is_food() { "food" } else { self.name.as_str() };
+        self.attribute == Attribute::Health && status.remedy() == Some(name)
+    }
+
+    /// Whether it's something to eat, whether it came from the Cornucopia or the woods.
+    pub fn is_food(&self) -> bool {
+        FOODS.contains(&self.name.as_str())
+    }
+
     pub fn is_weapon(&self) -> bool {
         self.item_type == ItemType::Weapon && self.attribute == Attribute::Strength
     }
@@ -164,6 +265,16 @@ impl Item {
         self.item_type == ItemType::Weapon && self.attribute == Attribute::Defense
     }
 
+    /// Gear that helps its owner hide, rather than something used up.
+    pub fn is_camouflage(&self) -> bool {
+        self.item_type == ItemType::Weapon && self.attribute == Attribute::Stealth
+    }
+
+    /// Something to leave set for another tribute. Snares, however well made, are the only kind so far.
+    pub fn is_trap(&self) -> bool {
+        self.item_type == ItemType::Material && self.name.ends_with("snare")
+    }
+
     pub fn is_consumable(&self) -> bool {
         self.item_type == ItemType::Consumable &&
         self.attribute != Attribute::Strength &&
@@ -183,6 +294,11 @@ impl From<ItemModel> for Item {
             quantity: item.quantity,
             attribute: Attribute::from_str(item.attribute.as_str()).unwrap(),
             effect: item.effect,
+            for_tribute_id: item.for_tribute_id,
+            for_district: item.for_district,
+            components: item.components
+                .and_then(|components| serde_json::from_value(components).ok())
+                .unwrap_or_default(),
         }
     }
 }
@@ -202,10 +318,12 @@ impl FromStr for Item {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum ItemType {
     Consumable,
     Weapon,
+    /// Crafting materials, and what's made from them that isn't for fighting with.
+    Material,
 }
 
 impl ItemType {
@@ -221,6 +339,7 @@ impl ItemType {
         match i {
             0 => ItemType::Consumable,
             1 => ItemType::Weapon,
+            2 => ItemType::Material,
             _ => panic!("Invalid item type"),
         }
     }
@@ -229,6 +348,7 @@ impl ItemType {
         match self {
             ItemType::Consumable => 0,
             ItemType::Weapon => 1,
+            ItemType::Material => 2,
         }
     }
 }
@@ -238,6 +358,7 @@ impl Display for ItemType {
         match self {
             ItemType::Consumable => write!(f, "Consumable"),
             ItemType::Weapon => write!(f, "Weapon"),
+            ItemType::Material => write!(f, "Material"),
         }
     }
 }
@@ -249,12 +370,13 @@ impl FromStr for ItemType {
         match s.to_lowercase().as_str() {
             "consumable" => Ok(ItemType::Consumable),
             "weapon" => Ok(ItemType::Weapon),
+            "material" => Ok(ItemType::Material),
             _ => Err("Invalid item type"),
         }
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Attribute {
     Health, // Heals health
     Sanity, // Heals sanity
@@ -263,9 +385,11 @@ pub enum Attribute {
     Speed, // Increases speed
     Strength, // Increases damage done, i.e. weapon
     Defense, // Reduces damage taken
+    Stealth, // Hides better
 }
 
 impl Attribute {
+    /// Any attribute but stealth, which only comes on gear (see `Item::new_camouflage`).
     pub fn random() -> Attribute {
         let mut rng = rand::thread_rng();
         match rng.gen_range(0..7) {
@@ -279,6 +403,11 @@ impl Attribute {
             _ => panic!("Invalid attribute"),
         }
     }
+
+    /// The attribute's name in `locale`.
+    pub fn localized(&self, locale: &Locale) -> String {
+        translate(locale, &format!("attribute-{}", self.to_string().to_lowercase()), None)
+    }
 }
 
 impl Display for Attribute {
@@ -291,6 +420,7 @@ impl Display for Attribute {
             Attribute::Speed => write!(f, "Speed"),
             Attribute::Strength => write!(f, "Strength"),
             Attribute::Defense => write!(f, "Defense"),
+            Attribute::Stealth => write!(f, "Stealth"),
         }
     }
 }
@@ -307,6 +437,7 @@ impl FromStr for Attribute {
             "speed" => Ok(Attribute::Speed),
             "strength" => Ok(Attribute::Strength),
             "defense" => Ok(Attribute::Defense),
+            "stealth" => Ok(Attribute::Stealth),
             _ => Err("Invalid attribute"),
         }
     }
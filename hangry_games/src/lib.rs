@@ -1,15 +1,27 @@
 pub mod animals;
 pub mod areas;
+pub mod betting;
+pub mod casts;
 pub mod cli;
+pub mod crafting;
 pub mod db;
 pub mod events;
+pub mod event_packs;
+pub mod feasts;
 pub mod items;
 pub mod games;
 pub mod gui;
+pub mod i18n;
 pub mod models;
+pub mod packs;
+pub mod rulesets;
 pub mod messages;
 pub mod schema;
+pub mod simulation;
+pub mod traps;
 pub mod tributes;
+pub mod tuning;
+pub mod win_conditions;
 pub mod item_name_generator;
 
 pub use db::establish_connection;
\ No newline at end of file
@@ -1,33 +1,80 @@
 use std::fmt::{Display, Formatter};
 use crate::animals::Animal;
 use crate::areas::Area;
+use crate::event_packs::CustomEvent;
 use crate::events::{AreaEvent, TributeEvent};
+use crate::feasts::Backpacks;
+use crate::i18n::{translate, Locale};
 use crate::items::Item;
+use crate::packs::Pack;
+use crate::traps::Trap;
 use crate::tributes::actors::Tribute;
+use crate::tributes::breakdowns::Breakdown;
 use crate::tributes::statuses::TributeStatus;
+use crate::win_conditions::RuleChange;
+use fluent_bundle::FluentArgs;
+use serde::{Deserialize, Deserializer, Serialize};
+use strum::AsRefStr;
 
 // Collection on strings to be used as output for the game
+#[derive(AsRefStr, Serialize, Deserialize)]
 pub enum GameMessage {
     GameDayStart(i32),
     FirstDayStart,
-    FeastDayStart,
+    FeastDayStart(i32),
+    /// The night before a feast, the arena is told when it is and who's getting backpacks.
+    FeastAnnounced(i32, Backpacks),
+    SuddenDeath(i32),
+    /// The gamemakers start driving the survivors to the Cornucopia.
+    Finale,
+    /// Wild animals wander into an area.
+    PackSighted(Pack),
+    /// The gamemakers let their own mutts loose.
+    MuttsReleased(Pack),
+    /// Enough tributes in an area have caught something contagious, all at once, to make the news.
+    Outbreak(Area, TributeStatus, i32),
     TributesLeft(i32),
     GameNightStart(i32),
     DailyDeathAnnouncement(i32),
     DeathAnnouncement(Tribute),
     NoOneWins,
-    TributeWins(Tribute),
+    /// Everyone who won, usually just one tribute.
+    TributeWins(#[serde(deserialize_with = "one_or_more_tributes")] Vec<Tribute>),
+    RuleChangeAnnounced(RuleChange),
+    RuleChangeRevoked(RuleChange),
+    TrainingScoresStart,
+    TributeTrainingScore(Tribute, i32),
+    InterviewsStart,
+    TributeInterview(Tribute, i32),
     TributeRest(Tribute),
     TributeLongRest(Tribute),
     TributeHide(Tribute),
+    TributeSpotted(Tribute, Tribute),
+    TributeUnspotted(Tribute, Tribute),
+    TributeHidingSpotLost(Tribute),
     TributeTravel(Tribute, Area, Area),
     TributeTakeItem(Tribute, Item),
+    TributeTakeBackpack(Tribute, Item),
+    /// A tribute forages, hunts, fishes or finds water, by the action's key, and what they bring back.
+    TributeGathers(Tribute, String, Item),
+    TributeGathersNothing(Tribute, String),
+    TributeGatheringMishap(Tribute, String),
+    TributeCrafts(Tribute, Item),
+    TributeSetsTrap(Tribute, Trap),
+    /// A tribute springs someone else's trap, whose owner is passed along with it, and takes this much damage.
+    TributeCaughtInTrap(Tribute, Tribute, Trap, i32),
+    TributeDiesInTrap(Tribute, Tribute, Trap),
+    TributeDisarmsTrap(Tribute, Trap),
+    TributeStealsTrap(Tribute, Trap),
     TributeCannotUseItem(Tribute, Item),
     TributeUseItem(Tribute, Item),
     TributeTravelTooTired(Tribute, Area),
     TributeTravelAlreadyThere(Tribute, Area),
     TributeTravelFollow(Tribute, Area),
     TributeTravelStay(Tribute, Area),
+    TributeTravelHunt(Tribute, Tribute, Area),
+    TributeTravelScavenge(Tribute, Area),
+    TributeTravelFlee(Tribute, Area),
     TributeBleeds(Tribute),
     TributeSick(Tribute),
     TributeElectrocuted(Tribute),
@@ -40,10 +87,22 @@ pub enum GameMessage {
     TributeBrokenLeg(Tribute),
     TributeInfected(Tribute),
     TributeDrowned(Tribute),
+    /// Something contagious a tribute caught earlier finally shows.
+    TributeFallsIll(Tribute, TributeStatus),
     TributeMauled(Tribute, i32, Animal, i32),
+    TributeHuntedByMutts(Tribute, Area),
+    /// A tribute fights off a pack, killing this many of them.
+    TributeKillsAnimals(Tribute, Pack, i32),
     TributeBurned(Tribute),
     TributeHorrified(Tribute, i32),
     TributeSuffer(Tribute),
+    TributeWitnessDeath(Tribute, Tribute, i32),
+    TributeMournDistrictMate(Tribute, Tribute, i32),
+    TributeShaken(Tribute, AreaEvent, i32),
+    TributeBreakdown(Tribute, Breakdown),
+    TributeRecovers(Tribute),
+    TributeCatatonic(Tribute),
+    TributeComforted(Tribute, Tribute),
     TributeSelfHarm(Tribute),
     TributeSuicide(Tribute),
     TributeAttackWin(Tribute, Tribute),
@@ -55,6 +114,7 @@ pub enum GameMessage {
     TributeAttackDied(Tribute, Tribute),
     TributeAttackSuccessKill(Tribute, Tribute),
     TributeAttackHidden(Tribute, Tribute),
+    TributeRevenge(Tribute, Tribute),
     TributeDiesFromStatus(Tribute, TributeStatus),
     TributeDiesFromAreaEvent(Tribute, AreaEvent), // Died in area
     TributeDiesFromTributeEvent(Tribute, TributeEvent),
@@ -64,200 +124,423 @@ pub enum GameMessage {
     ShieldBreak(Tribute, Item),
     SponsorGift(Tribute, Item),
     AreaEvent(AreaEvent, Area),
+    ArenaHazard(AreaEvent),
     AreaClose(Area),
     AreaOpen(Area),
     TrappedInArea(Tribute, Area),
     DiedInArea(Tribute, Area),
+    /// An event from a user's event pack, which brings its own text.
+    CustomEvent(CustomEvent, Vec<Tribute>),
 }
 
-impl Display for GameMessage {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl GameMessage {
+    /// The variant name, e.g. "TributeAttackSuccessKill", stored alongside each log entry.
+    pub fn kind(&self) -> &str {
+        self.as_ref()
+    }
+
+    /// The catalog key for this message, e.g. "tribute-attack-success-kill".
+    fn catalog_key(&self) -> String {
+        let mut key = String::new();
+        for (i, c) in self.kind().char_indices() {
+            if c.is_uppercase() && i > 0 {
+                key.push('-');
+            }
+            key.push(c.to_ascii_lowercase());
+        }
+        key
+    }
+
+    /// The message as narration in `locale`.
+    pub fn localized(&self, locale: &Locale) -> String {
+        let mut args = FluentArgs::new();
         match &self {
-            GameMessage::GameDayStart(day_number) => {
-                write!(f, "{}", format!("=== ☀️ Day {} begins! ===", day_number))
-            }
-            GameMessage::FirstDayStart => {
-                write!(f, "=== 🎉 The Hunger Games begin! 🎉 ===")
-            }
-            GameMessage::FeastDayStart => {
-                write!(f, "=== 😋 Day 3: Feast Day ===")
-            }
-            GameMessage::TributesLeft(tribute_count) => {
-                write!(f, "{}", format!("=== 📌 Tributes alive: {} ===", tribute_count))
-            }
-            GameMessage::GameNightStart(day_number) => {
-                write!(f, "{}", format!("=== 🌙 Night {} begins ===", day_number))
-            }
-            GameMessage::DailyDeathAnnouncement(death_count) => {
-                write!(f, "{}", format!("=== 💀 Tributes dead: {} ===", death_count))
-            }
-            GameMessage::DeathAnnouncement(tribute) => {
-                write!(f, "{}", format!("=== 🪦 {} has died ===", tribute.name))
-            }
-            GameMessage::NoOneWins => {
-                write!(f, "=== 🎭 No one wins! ===")
-            }
-            GameMessage::TributeWins(tribute) => {
-                write!(f, "{}", format!("=== 🏆 The winner is {} ===", tribute.name))
-            }
-            GameMessage::TributeRest(tribute) => {
-                write!(f, "{}", format!("😪 {} rests", tribute.name))
-            }
-            GameMessage::TributeLongRest(tribute) => {
-                write!(f, "{}", format!("💤 {} rests and recovers a little health and sanity", tribute.name))
-            }
-            GameMessage::TributeHide(tribute) => {
-                write!(f, "{}", format!("🫥 {} tries to hide", tribute.name))
+            GameMessage::FirstDayStart
+            | GameMessage::Finale
+            | GameMessage::NoOneWins
+            | GameMessage::TrainingScoresStart
+            | GameMessage::InterviewsStart => {}
+            GameMessage::GameDayStart(day_number)
+            | GameMessage::FeastDayStart(day_number)
+            | GameMessage::SuddenDeath(day_number)
+            | GameMessage::GameNightStart(day_number) => {
+                args.set("day", *day_number);
+            }
+            GameMessage::FeastAnnounced(day_number, backpacks) => {
+                args.set("day", *day_number);
+                args.set("backpacks", backpacks.as_str());
+            }
+            GameMessage::TributesLeft(count) | GameMessage::DailyDeathAnnouncement(count) => {
+                args.set("count", *count);
+            }
+            GameMessage::DeathAnnouncement(tribute)
+            | GameMessage::TributeRest(tribute)
+            | GameMessage::TributeLongRest(tribute)
+            | GameMessage::TributeHide(tribute)
+            | GameMessage::TributeHidingSpotLost(tribute)
+            | GameMessage::TributeBleeds(tribute)
+            | GameMessage::TributeSick(tribute)
+            | GameMessage::TributeElectrocuted(tribute)
+            | GameMessage::TributeFrozen(tribute)
+            | GameMessage::TributeOverheated(tribute)
+            | GameMessage::TributeDehydrated(tribute)
+            | GameMessage::TributeStarving(tribute)
+            | GameMessage::TributePoisoned(tribute)
+            | GameMessage::TributeBrokenArm(tribute)
+            | GameMessage::TributeBrokenLeg(tribute)
+            | GameMessage::TributeInfected(tribute)
+            | GameMessage::TributeDrowned(tribute)
+            | GameMessage::TributeBurned(tribute)
+            | GameMessage::TributeSuffer(tribute)
+            | GameMessage::TributeRecovers(tribute)
+            | GameMessage::TributeCatatonic(tribute)
+            | GameMessage::TributeSelfHarm(tribute)
+            | GameMessage::TributeSuicide(tribute)
+            | GameMessage::TributeAlreadyDead(tribute)
+            | GameMessage::TributeDead(tribute) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+            }
+            GameMessage::TributeWins(tributes) => match tributes.as_slice() {
+                [tribute] => set_tribute_args(&mut args, "tribute", tribute),
+                _ => {
+                    args.set("tribute", tributes.iter().map(|t| t.name.clone()).collect::<Vec<String>>().join(" & "));
+                    args.set("tribute-pronouns", "plural");
+                }
+            },
+            GameMessage::RuleChangeAnnounced(rule_change) => {
+                args.set("rule", rule_change.localized(locale, false));
+            }
+            GameMessage::RuleChangeRevoked(rule_change) => {
+                args.set("rule", rule_change.localized(locale, true));
+            }
+            GameMessage::TributeTrainingScore(tribute, score) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                args.set("score", *score);
+            }
+            GameMessage::TributeInterview(tribute, likability) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                args.set("likability", *likability);
+                args.set("reception", match likability {
+                    80.. => "adored",
+                    50..=79 => "liked",
+                    25..=49 => "flat",
+                    _ => "disliked",
+                });
+            }
+            GameMessage::TributeSpotted(spotter, hider) | GameMessage::TributeUnspotted(spotter, hider) => {
+                set_tribute_args(&mut args, "spotter", spotter);
+                set_tribute_args(&mut args, "hider", hider);
             }
             GameMessage::TributeTravel(tribute, area_a, area_b) => {
-                write!(f, "{}", format!("🚶 {} moves from {} to {}", tribute.name, area_a.to_string(), area_b.to_string()))
-            }
-            GameMessage::TributeTakeItem(tribute, item) => {
-                write!(f, "{}", format!("🔨 {} takes a(n) {}", tribute.name, item.name))
-            }
-            GameMessage::TributeCannotUseItem(tribute, item) => {
-                write!(f, "{}", format!("❌ {} cannot use a(n) {}", tribute.name, item.name))
-            }
-            GameMessage::TributeUseItem(tribute, item) => {
-                write!(f, "{}", format!("💊 {} uses a(n) {}, gains {} {}", tribute.name, item.name, item.effect, item.attribute))
-            }
-            GameMessage::TributeTravelTooTired(tribute, area) => {
-                write!(f, "{}", format!("😴 {} is too tired to move from {}, rests instead", tribute.name, area.to_string()))
-            }
-            GameMessage::TributeTravelAlreadyThere(tribute, area) => {
-                write!(f, "{}", format!("🤔 {} is already in the {}, stays put", tribute.name, area.to_string()))
-            }
-            GameMessage::TributeTravelFollow(tribute, area) => {
-                write!(f, "{}", format!("🫡 {} follows their district mate to {}", tribute.name, area.to_string()))
-            }
-            GameMessage::TributeTravelStay(tribute, area) => {
-                write!(f, "{}", format!("🪑 {} stays in {}", tribute.name, area.to_string()))
-            }
-            GameMessage::TributeBleeds(tribute) => {
-                write!(f, "{}", format!("🩸 {} bleeds from their wounds.", tribute.name))
-            }
-            GameMessage::TributeSick(tribute) => {
-                write!(f, "{}", format!("🤒 {} contracts dysentery, loses strength and speed", tribute.name))
-            }
-            GameMessage::TributeElectrocuted(tribute) => {
-                write!(f, "{}", format!("🌩️ {} is struck by lightning, loses health", tribute.name))
-            }
-            GameMessage::TributeFrozen(tribute) => {
-                write!(f, "{}", format!("🥶 {} suffers from hypothermia, loses speed.", tribute.name))
-            }
-            GameMessage::TributeOverheated(tribute) => {
-                write!(f, "{}", format!("🥵 {} suffers from heat stroke, loses speed.", tribute.name))
-            }
-            GameMessage::TributeDehydrated(tribute) => {
-                write!(f, "{}", format!("🌵 {} is severely dehydrated, loses strength", tribute.name))
-            }
-            GameMessage::TributeStarving(tribute) => {
-                write!(f, "{}", format!("🍴 {} is ravenously hungry, loses strength", tribute.name))
-            }
-            GameMessage::TributePoisoned(tribute) => {
-                write!(f, "{}", format!("🧪 {} eats something poisonous, loses sanity", tribute.name))
-            }
-            GameMessage::TributeBrokenArm(tribute) => {
-                write!(f, "{}", format!("🦴 {} injures their arm, loses strength.", tribute.name))
-            }
-            GameMessage::TributeBrokenLeg(tribute) => {
-                write!(f, "{}", format!("🦴 {} injures their leg, loses speed.", tribute.name))
-            }
-            GameMessage::TributeInfected(tribute) => {
-                write!(f, "{}", format!("🤢 {} gets an infection, loses health and sanity", tribute.name))
-            }
-            GameMessage::TributeDrowned(tribute) => {
-                write!(f, "{}", format!("🏊 {} partially drowns, loses health and sanity", tribute.name))
+                set_tribute_args(&mut args, "tribute", tribute);
+                args.set("from", area_a.localized(locale));
+                args.set("to", area_b.localized(locale));
+            }
+            GameMessage::TributeTakeItem(tribute, item)
+            | GameMessage::TributeCannotUseItem(tribute, item)
+            | GameMessage::WeaponBreak(tribute, item)
+            | GameMessage::ShieldBreak(tribute, item) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                args.set("item", item.name.clone());
+            }
+            GameMessage::TributeCrafts(tribute, item) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                args.set("item", item.name.clone());
+                args.set("effect", item.effect);
+                args.set("attribute", item.attribute.localized(locale));
+            }
+            GameMessage::TributeSetsTrap(tribute, trap)
+            | GameMessage::TributeDisarmsTrap(tribute, trap)
+            | GameMessage::TributeStealsTrap(tribute, trap) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                args.set("trap", trap.name.clone());
+                set_area_args(&mut args, &trap.area, locale);
+            }
+            GameMessage::TributeCaughtInTrap(tribute, owner, trap, damage) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                set_tribute_args(&mut args, "owner", owner);
+                args.set("trap", trap.name.clone());
+                args.set("damage", *damage);
+            }
+            GameMessage::TributeDiesInTrap(tribute, owner, trap) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                set_tribute_args(&mut args, "owner", owner);
+                args.set("trap", trap.name.clone());
+            }
+            GameMessage::TributeGathers(tribute, action, item) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                args.set("action", action.clone());
+                args.set("item", item.name.clone());
+            }
+            GameMessage::TributeGathersNothing(tribute, action) | GameMessage::TributeGatheringMishap(tribute, action) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                args.set("action", action.clone());
+            }
+            GameMessage::TributeTakeBackpack(tribute, item) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                args.set("item", item.name.clone());
+                args.set("district", item.for_district.unwrap_or(tribute.district));
+                args.set("label", if item.for_district.is_some() { "district" } else { "tribute" });
+            }
+            GameMessage::TributeUseItem(tribute, item) | GameMessage::SponsorGift(tribute, item) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                args.set("item", item.name.clone());
+                args.set("quantity", item.quantity);
+                args.set("attribute", item.attribute.localized(locale));
+                args.set("effect", item.effect);
+            }
+            GameMessage::TributeTravelTooTired(tribute, area)
+            | GameMessage::TributeTravelAlreadyThere(tribute, area)
+            | GameMessage::TributeTravelFollow(tribute, area)
+            | GameMessage::TributeTravelStay(tribute, area)
+            | GameMessage::TributeTravelScavenge(tribute, area)
+            | GameMessage::TributeTravelFlee(tribute, area)
+            | GameMessage::TrappedInArea(tribute, area)
+            | GameMessage::DiedInArea(tribute, area)
+            | GameMessage::TributeHuntedByMutts(tribute, area) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                set_area_args(&mut args, area, locale);
+            }
+            GameMessage::TributeTravelHunt(tribute, target, area) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                set_tribute_args(&mut args, "target", target);
+                set_area_args(&mut args, area, locale);
+            }
+            GameMessage::PackSighted(pack) | GameMessage::MuttsReleased(pack) => {
+                args.set("count", pack.count);
+                args.set("animal", pack.label(locale));
+                set_area_args(&mut args, &pack.area, locale);
+            }
+            GameMessage::TributeKillsAnimals(tribute, pack, killed) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                args.set("count", *killed);
+                args.set("animal", pack.name.clone().unwrap_or(pack.animal.localized(locale, *killed)));
             }
             GameMessage::TributeMauled(tribute, count, animal, damage) => {
-                write!(f, "{}", format!("🐾 {} is attacked by {} {}, takes {} damage!", tribute.name, count, animal.plural(), damage))
-            }
-            GameMessage::TributeBurned(tribute) => {
-                write!(f, "{}", format!("🔥 {} gets burned, loses health", tribute.name))
+                set_tribute_args(&mut args, "tribute", tribute);
+                args.set("count", *count);
+                args.set("animal", animal.localized(locale, *count));
+                args.set("damage", *damage);
             }
             GameMessage::TributeHorrified(tribute, damage) => {
-                write!(f, "{}", format!("😱 {} is horrified by the violence, loses {} sanity.", tribute.name, damage))
-            }
-            GameMessage::TributeSuffer(tribute) => {
-                write!(f, "{}", format!("😭 {} suffers from loneliness and terror.", tribute.name))
-            }
-            GameMessage::TributeSelfHarm(tribute) => {
-                write!(f, "{}", format!("🤦 {} tries to attack themself!", tribute.name))
-            }
-            GameMessage::TributeSuicide(tribute) => {
-                write!(f, "{}", format!("🪒 {} attempts suicide.", tribute.name))
-            }
-            GameMessage::TributeAttackWin(tribute, target) => {
-                write!(f, "{}", format!("🔪 {} attacks {}, and wins!", tribute.name, target.name))
-            }
-            GameMessage::TributeAttackWinExtra(tribute, target) => {
-                write!(f, "{}", format!("🔪 {} attacks {}, and wins decisively!", tribute.name, target.name))
-            }
-            GameMessage::TributeAttackWound(tribute, target) => {
-                write!(f, "{}", format!("🤕 {} wounds {}", tribute.name, target.name))
-            }
-            GameMessage::TributeAttackLose(tribute, target) => {
-                write!(f, "{}", format!("🤣 {} attacks {}, but loses!", tribute.name, target.name))
-            }
-            GameMessage::TributeAttackLoseExtra(tribute, target) => {
-                write!(f, "{}", format!("🤣 {} attacks {}, but loses decisively!", tribute.name, target.name))
-            }
-            GameMessage::TributeAttackMiss(tribute, target) => {
-                write!(f, "{}", format!("😰 {} attacks {}, but misses!", tribute.name, target.name))
-            }
-            GameMessage::TributeAttackDied(tribute, target) => {
-                write!(f, "{}", format!("☠️ {} is killed by {}", tribute.name, target.name))
-            }
-            GameMessage::TributeAttackSuccessKill(tribute, target) => {
-                write!(f, "{}", format!("☠️ {} successfully kills {}", tribute.name, target.name))
-            }
-            GameMessage::TributeAttackHidden(tribute, target) => {
-                write!(f, "{}", format!("🤔 {} can't attack {}, they're hidden", tribute.name, target.name))
-            }
-            GameMessage::TributeDiesFromStatus(tribute, status) => {
-                write!(f, "{}", format!("💀 {} dies from {}", tribute.name, status.to_string()))
+                set_tribute_args(&mut args, "tribute", tribute);
+                args.set("damage", *damage);
+            }
+            GameMessage::TributeWitnessDeath(tribute, victim, damage)
+            | GameMessage::TributeMournDistrictMate(tribute, victim, damage) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                set_tribute_args(&mut args, "victim", victim);
+                args.set("damage", *damage);
+            }
+            GameMessage::TributeShaken(tribute, area_event, damage) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                set_area_event_args(&mut args, area_event, locale);
+                args.set("damage", *damage);
+            }
+            GameMessage::TributeBreakdown(tribute, breakdown) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                args.set("breakdown", breakdown.to_string());
+            }
+            GameMessage::TributeComforted(tribute, ally) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                set_tribute_args(&mut args, "ally", ally);
+            }
+            GameMessage::TributeAttackWin(tribute, target)
+            | GameMessage::TributeAttackWinExtra(tribute, target)
+            | GameMessage::TributeAttackWound(tribute, target)
+            | GameMessage::TributeAttackLose(tribute, target)
+            | GameMessage::TributeAttackLoseExtra(tribute, target)
+            | GameMessage::TributeAttackMiss(tribute, target)
+            | GameMessage::TributeAttackDied(tribute, target)
+            | GameMessage::TributeAttackSuccessKill(tribute, target)
+            | GameMessage::TributeAttackHidden(tribute, target)
+            | GameMessage::TributeRevenge(tribute, target) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                set_tribute_args(&mut args, "target", target);
+            }
+            GameMessage::Outbreak(area, status, count) => {
+                set_area_args(&mut args, area, locale);
+                args.set("status", status.localized(locale));
+                args.set("count", *count);
+            }
+            GameMessage::TributeDiesFromStatus(tribute, status) | GameMessage::TributeFallsIll(tribute, status) => {
+                set_tribute_args(&mut args, "tribute", tribute);
+                args.set("status", status.localized(locale));
             }
             GameMessage::TributeDiesFromAreaEvent(tribute, area_event) => {
-                write!(f, "{}", format!("🪦 {} died in the {}.", tribute.name, area_event.to_string()))
+                set_tribute_args(&mut args, "tribute", tribute);
+                set_area_event_args(&mut args, area_event, locale);
             }
             GameMessage::TributeDiesFromTributeEvent(tribute, tribute_event) => {
-                write!(f, "{}", format!("💀 {} dies by {}", tribute.name, tribute_event.to_string()))
-            }
-            GameMessage::TributeAlreadyDead(tribute) => {
-                write!(f, "{}", format!("‼️ {} is already dead!", tribute.name))
-            }
-            GameMessage::TributeDead(tribute) => {
-                write!(f, "{}", format!("❗️ {} is dead!", tribute.name))
-            }
-            GameMessage::WeaponBreak(tribute, weapon) => {
-                write!(f, "{}", format!("🗡️ {} breaks their {}", tribute.name, weapon.name))
-            }
-            GameMessage::ShieldBreak(tribute, shield) => {
-                write!(f, "{}", format!("🛡️ {} breaks their {}", tribute.name, shield.name))
-            }
-            GameMessage::SponsorGift(tribute, item) => {
-                write!(f, "{}", format!("🎁 {} receives a(n) {} ({}x {} +{})", tribute.name, item.name, item.quantity, item.attribute, item.effect))
+                set_tribute_args(&mut args, "tribute", tribute);
+                args.set("event", tribute_event.localized(locale));
             }
             GameMessage::AreaEvent(area_event, area) => {
-                let area_name = area.to_string().replace("The ", "");
-                write!(f, "{}", format!("=== ⚠️ A(n) {} has occurred in the {} ===", area_event.to_string(), area_name))
-            }
-            GameMessage::AreaClose(area) => {
-                let area_name = area.to_string().replace("The ", "");
-                write!(f, "{}", format!("=== 🔔 The {} is uninhabitable ===", area_name))
+                set_area_event_args(&mut args, area_event, locale);
+                set_area_args(&mut args, area, locale);
             }
-            GameMessage::AreaOpen(area) => {
-                let area_name = area.to_string().replace("The ", "");
-                write!(f, "{}", format!("=== 🔔 The {} is habitable again ===", area_name))
+            GameMessage::ArenaHazard(area_event) => {
+                set_area_event_args(&mut args, area_event, locale);
             }
-            GameMessage::TrappedInArea(tribute, area) => {
-                let area_name = area.to_string().replace("The ", "");
-                write!(f, "{}", format!("💥 {} is trapped in the {}.", tribute.name, area_name))
+            GameMessage::AreaClose(area) | GameMessage::AreaOpen(area) => {
+                set_area_args(&mut args, area, locale);
             }
-            GameMessage::DiedInArea(tribute, area) => {
-                let area_name = area.to_string().replace("The ", "");
-                write!(f, "{}", format!("💥 {} died in the {}.", tribute.name, area_name))
+            GameMessage::CustomEvent(event, participants) => {
+                return event.render(participants, locale);
             }
         }
+        translate(locale, &self.catalog_key(), Some(&args))
+    }
+}
+
+/// Winners used to be logged one at a time, so older payloads hold a lone tribute.
+fn one_or_more_tributes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Tribute>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMore {
+        One(Box<Tribute>),
+        More(Vec<Tribute>),
+    }
+
+    Ok(match OneOrMore::deserialize(deserializer)? {
+        OneOrMore::One(tribute) => vec![*tribute],
+        OneOrMore::More(tributes) => tributes,
+    })
+}
+
+/// Tributes are passed by name, plus `$<role>-pronouns` ("she", "he", "they" or "custom")
+/// and English `-subject`, `-object`, `-possessive` and `-reflexive` forms.
+fn set_tribute_args(args: &mut FluentArgs, role: &str, tribute: &Tribute) {
+    let pronouns = tribute.pronouns.clone().unwrap_or_default();
+    args.set(role.to_string(), tribute.name.clone());
+    args.set(format!("{}-pronouns", role), pronouns.selector().to_string());
+    args.set(format!("{}-subject", role), pronouns.subject().to_string());
+    args.set(format!("{}-object", role), pronouns.object().to_string());
+    args.set(format!("{}-possessive", role), pronouns.possessive().to_string());
+    args.set(format!("{}-reflexive", role), pronouns.reflexive().to_string());
+}
+
+/// Areas are passed as `$area`, `$location` and `$subject` so each language can pick its own form.
+fn set_area_args(args: &mut FluentArgs, area: &Area, locale: &Locale) {
+    args.set("area", area.localized(locale));
+    args.set("location", area.localized_location(locale));
+    args.set("subject", area.localized_subject(locale));
+}
+
+/// Area events are passed as `$event`, `$indefinite` and `$definite`.
+fn set_area_event_args(args: &mut FluentArgs, area_event: &AreaEvent, locale: &Locale) {
+    args.set("event", area_event.localized(locale));
+    args.set("indefinite", area_event.localized_indefinite(locale));
+    args.set("definite", area_event.localized_definite(locale));
+}
+
+impl Display for GameMessage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.localized(&Locale::English))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tributes::pronouns::Pronouns;
+
+    #[test]
+    fn game_message_round_trip() {
+        let tribute = Tribute::new("Katniss".to_string(), Some(12), None);
+        let message = GameMessage::TributeTravel(tribute, Area::Northeast, Area::Cornucopia);
+        assert_eq!(message.kind(), "TributeTravel");
+
+        let payload = serde_json::to_value(&message).unwrap();
+        let restored: GameMessage = serde_json::from_value(payload).unwrap();
+        assert_eq!(restored.kind(), "TributeTravel");
+        assert_eq!(restored.to_string(), message.to_string());
+    }
+
+    #[test]
+    fn unit_message_kind() {
+        assert_eq!(GameMessage::FirstDayStart.kind(), "FirstDayStart");
+    }
+
+    #[test]
+    fn catalog_key() {
+        assert_eq!(GameMessage::FirstDayStart.catalog_key(), "first-day-start");
+        assert_eq!(GameMessage::AreaClose(Area::Northeast).catalog_key(), "area-close");
+    }
+
+    #[test]
+    fn co_victors_share_the_announcement() {
+        let katniss = Tribute::new("Katniss".to_string(), Some(12), None);
+        let peeta = Tribute::new("Peeta".to_string(), Some(12), None);
+        assert_eq!(GameMessage::TributeWins(vec![katniss.clone()]).to_string(), "=== 🏆 The winner is Katniss ===");
+        let both = GameMessage::TributeWins(vec![katniss, peeta]);
+        assert_eq!(both.to_string(), "=== 🏆 The winners are Katniss & Peeta ===");
+        assert_eq!(both.localized(&Locale::Spanish), "=== 🏆 Los ganadores son Katniss & Peeta ===");
+
+        let restored: GameMessage = serde_json::from_value(serde_json::to_value(&both).unwrap()).unwrap();
+        assert_eq!(restored.to_string(), both.to_string());
+    }
+
+    #[test]
+    fn lone_winners_from_older_logs_still_load() {
+        let katniss = Tribute::new("Katniss".to_string(), Some(12), None);
+        let payload = serde_json::json!({ "TributeWins": serde_json::to_value(&katniss).unwrap() });
+        let restored: GameMessage = serde_json::from_value(payload).unwrap();
+        assert_eq!(restored.to_string(), "=== 🏆 The winner is Katniss ===");
+    }
+
+    #[test]
+    fn localized_plurals() {
+        let tribute = Tribute::new("Katniss".to_string(), Some(12), None);
+        let one = GameMessage::TributeMauled(tribute.clone(), 1, Animal::Wolf, 5);
+        let many = GameMessage::TributeMauled(tribute, 3, Animal::Wolf, 5);
+        assert_eq!(one.to_string(), "🐾 Katniss is attacked by 1 wolf, takes 5 damage!");
+        assert_eq!(many.to_string(), "🐾 Katniss is attacked by 3 wolves, takes 5 damage!");
+        assert_eq!(one.localized(&Locale::Spanish), "🐾 A Katniss le ataca 1 lobo, ¡recibe 5 de daño!");
+        assert_eq!(many.localized(&Locale::Spanish), "🐾 A Katniss le atacan 3 lobos, ¡recibe 5 de daño!");
+        assert_eq!(many.localized(&Locale::German), "🐾 Katniss wird von 3 Wölfen angegriffen und erleidet 5 Schaden!");
+    }
+
+    #[test]
+    fn localized_areas() {
+        let message = GameMessage::AreaClose(Area::Northeast);
+        assert_eq!(message.to_string(), "=== 🔔 The Northeast is uninhabitable ===");
+        assert_eq!(message.localized(&Locale::Spanish), "=== 🔔 El Noreste es inhabitable ===");
+        assert_eq!(message.localized(&Locale::German), "=== 🔔 Der Nordosten ist unbewohnbar ===");
+
+        let message = GameMessage::AreaEvent(AreaEvent::Flood, Area::Cornucopia);
+        assert_eq!(message.to_string(), "=== ⚠️ A(n) flood has occurred in the Cornucopia ===");
+        assert_eq!(message.localized(&Locale::Spanish), "=== ⚠️ Se ha producido una inundación en la Cornucopia ===");
+    }
+
+    #[test]
+    fn localized_interviews() {
+        let mut tribute = Tribute::new("Peeta".to_string(), Some(12), None);
+        tribute.pronouns = Some(Pronouns::He);
+        assert_eq!(
+            GameMessage::TributeInterview(tribute.clone(), 92).to_string(),
+            "🌟 Peeta has the crowd eating out of his hand (likability 92)"
+        );
+        assert_eq!(
+            GameMessage::TributeInterview(tribute, 12).localized(&Locale::Spanish),
+            "🙄 Peeta se gana la antipatía del público (simpatía 12)"
+        );
+    }
+
+    #[test]
+    fn localized_pronouns() {
+        let mut katniss = Tribute::new("Katniss".to_string(), Some(12), None);
+        let mut peeta = Tribute::new("Peeta".to_string(), Some(12), None);
+        assert_eq!(GameMessage::TributeBleeds(katniss.clone()).to_string(), "🩸 Katniss bleeds from their wounds.");
+
+        katniss.pronouns = Some(Pronouns::She);
+        peeta.pronouns = Some(Pronouns::He);
+        assert_eq!(GameMessage::TributeBleeds(katniss.clone()).to_string(), "🩸 Katniss bleeds from her wounds.");
+        assert_eq!(
+            GameMessage::TributeAttackHidden(katniss.clone(), peeta.clone()).to_string(),
+            "🤔 Katniss can't attack Peeta, he is hidden"
+        );
+        assert_eq!(GameMessage::TributeDead(katniss).localized(&Locale::Spanish), "❗️ ¡Katniss está muerta!");
+        assert_eq!(GameMessage::TributeDead(peeta).localized(&Locale::Spanish), "❗️ ¡Peeta está muerto!");
     }
 }
@@ -0,0 +1,111 @@
+use crate::establish_connection;
+use crate::models::{get_spectator_by_id, Game, Tribute};
+use crate::schema::bet;
+use diesel::prelude::*;
+
+#[derive(Queryable, Selectable, Debug, Associations, Clone, PartialEq)]
+#[diesel(table_name = bet)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(belongs_to(Game, foreign_key = game_id))]
+#[diesel(belongs_to(Tribute, foreign_key = tribute_id))]
+pub struct Bet {
+    pub id: i32,
+    pub game_id: i32,
+    pub spectator_id: i32,
+    pub tribute_id: i32,
+    pub kind: String,
+    pub kills: Option<i32>, // Only for "kills" bets
+    pub stake: i32,
+    pub odds: f64,
+    pub day: i32,
+    pub payout: Option<i32>, // Empty until the game is over
+    pub created_at: chrono::NaiveDateTime,
+}
+
+#[derive(Insertable, Debug)]
+#[diesel(table_name = bet)]
+pub struct NewBet {
+    pub game_id: i32,
+    pub spectator_id: i32,
+    pub tribute_id: i32,
+    pub kind: String,
+    pub kills: Option<i32>,
+    pub stake: i32,
+    pub odds: f64,
+    pub day: i32,
+}
+
+impl Bet {
+    pub fn create(new_bet: NewBet) -> Bet {
+        let connection = &mut establish_connection();
+        diesel::insert_into(bet::table)
+            .values(&new_bet)
+            .returning(Bet::as_returning())
+            .get_result(connection)
+            .expect("Error saving new bet")
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.payout.is_some()
+    }
+
+    /// Records the payout and credits it to the spectator.
+    pub fn settle(&mut self, payout: i32) {
+        let connection = &mut establish_connection();
+        diesel::update(bet::table.find(self.id))
+            .set(bet::payout.eq(Some(payout)))
+            .execute(connection)
+            .expect("Error updating bet");
+        self.payout = Some(payout);
+        get_spectator_by_id(self.spectator_id).adjust_balance(payout);
+    }
+
+    /// Every bet on a game, in the order they were placed.
+    pub fn get_all_for_game(game_id: i32) -> Vec<Bet> {
+        let connection = &mut establish_connection();
+        bet::table
+            .filter(bet::game_id.eq(game_id))
+            .order_by(bet::id)
+            .select(Bet::as_select())
+            .load(connection)
+            .expect("Error loading bets")
+    }
+
+    pub fn get_all_for_spectator(spectator_id: i32) -> Vec<Bet> {
+        let connection = &mut establish_connection();
+        bet::table
+            .filter(bet::spectator_id.eq(spectator_id))
+            .order_by(bet::id.desc())
+            .select(Bet::as_select())
+            .load(connection)
+            .expect("Error loading bets")
+    }
+}
+
+/// Hands back the stakes of any bets still open on the tribute, then deletes every bet on them.
+pub fn delete_tribute_bets(tribute_id: i32) {
+    let connection = &mut establish_connection();
+    let open = bet::table
+        .filter(bet::tribute_id.eq(tribute_id))
+        .filter(bet::payout.is_null())
+        .select(Bet::as_select())
+        .load(connection)
+        .expect("Error loading bets");
+    for bet in open {
+        get_spectator_by_id(bet.spectator_id).adjust_balance(bet.stake);
+    }
+    diesel::delete(bet::table.filter(bet::tribute_id.eq(tribute_id)))
+        .execute(connection)
+        .expect("Error deleting bets");
+}
+
+/// Hands back the stakes of any bets still open, then deletes every bet on the game.
+pub fn delete_game_bets(game_id: i32) {
+    for bet in Bet::get_all_for_game(game_id).into_iter().filter(|b| !b.is_settled()) {
+        get_spectator_by_id(bet.spectator_id).adjust_balance(bet.stake);
+    }
+    let connection = &mut establish_connection();
+    diesel::delete(bet::table.filter(bet::game_id.eq(game_id)))
+        .execute(connection)
+        .expect("Error deleting bets");
+}
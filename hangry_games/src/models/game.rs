@@ -1,13 +1,23 @@
 use crate::areas::Area;
+use crate::betting;
+use crate::feasts::{Backpacks, Feast};
 use crate::games::Game as GameActor;
+use crate::i18n::Locale;
 use crate::models::{get_area_by_id, Tribute};
+use crate::rulesets::Ruleset;
 use crate::schema::game;
+use crate::tributes::point_buy::PointBuy;
+use crate::tributes::pronouns::Pronouns;
+use crate::tributes::rosters::reap_victors;
 use crate::tributes::statuses::TributeStatus;
+use crate::tuning::Tuning;
+use crate::win_conditions::{RuleChange, WinCondition};
 use crate::{establish_connection, models};
 use diesel::prelude::*;
 use fake::faker::name::raw::Name;
 use fake::locales::EN;
 use fake::Fake;
+use std::str::FromStr;
 
 #[derive(Queryable, Selectable, Clone, Debug)]
 #[diesel(table_name = game)]
@@ -19,6 +29,17 @@ pub struct Game {
     pub day: Option<i32>,
     pub closed_areas: Option<Vec<Option<i32>>>,
     pub ended_at: Option<chrono::NaiveDateTime>,
+    pub locale: String,
+    pub stat_budget: i32,
+    pub tuning: Option<serde_json::Value>,
+    pub roster_size: i32,
+    pub districts: i32,
+    pub ruleset: String,
+    pub win_condition: String,
+    pub alliances: Option<serde_json::Value>,
+    pub rule_changes: Option<serde_json::Value>,
+    pub finale_day: Option<i32>,
+    pub feasts: Option<serde_json::Value>,
 }
 
 impl Game {
@@ -61,6 +82,265 @@ impl Game {
             .set(game::ended_at.eq(ended_at))
             .execute(connection)
             .expect("Error updating game");
+
+        betting::settle_bets(self);
+    }
+
+    pub fn set_locale(&self, locale: &Locale) {
+        let connection = &mut establish_connection();
+        diesel::update(game::table.find(self.id))
+            .set(game::locale.eq(locale.code()))
+            .execute(connection)
+            .expect("Error updating game");
+    }
+
+    pub fn set_stat_budget(&self, points: i32) {
+        let connection = &mut establish_connection();
+        diesel::update(game::table.find(self.id))
+            .set(game::stat_budget.eq(points))
+            .execute(connection)
+            .expect("Error updating game");
+    }
+
+    /// Sets how many tributes the game takes and how many districts they're reaped from.
+    pub fn set_roster_size(&self, roster_size: i32, districts: i32) -> Result<(), String> {
+        if self.day.unwrap_or(0) > 0 {
+            return Err(format!("{} has already started", self.name));
+        }
+        if roster_size < 2 {
+            return Err(format!("A game needs at least 2 tributes, got {}", roster_size));
+        }
+        if !(1..=roster_size).contains(&districts) {
+            return Err(format!("districts must be between 1 and {}, got {}", roster_size, districts));
+        }
+        let count = self.tributes().len() as i32;
+        if count > roster_size {
+            return Err(format!("{} already has {} tributes", self.name, count));
+        }
+
+        let connection = &mut establish_connection();
+        diesel::update(game::table.find(self.id))
+            .set((game::roster_size.eq(roster_size), game::districts.eq(districts)))
+            .execute(connection)
+            .expect("Error updating game");
+        Ok(())
+    }
+
+    /// The house rules the game is played under.
+    pub fn ruleset(&self) -> Ruleset {
+        Ruleset::from_str(&self.ruleset).unwrap_or_default()
+    }
+
+    /// Switches the game's rules before it starts. Rules that reap more or fewer tributes
+    /// from each district resize the roster to match.
+    pub fn set_ruleset(&self, ruleset: &Ruleset) -> Result<(), String> {
+        if self.day.unwrap_or(0) > 0 {
+            return Err(format!("{} has already started", self.name));
+        }
+        let roster_size = self.roster_size / self.ruleset().reaping_multiplier() * ruleset.reaping_multiplier();
+        if roster_size != self.roster_size {
+            self.set_roster_size(roster_size, self.districts)?;
+        }
+
+        let connection = &mut establish_connection();
+        diesel::update(game::table.find(self.id))
+            .set(game::ruleset.eq(ruleset.to_string()))
+            .execute(connection)
+            .expect("Error updating game");
+        Ok(())
+    }
+
+    pub fn win_condition(&self) -> WinCondition {
+        WinCondition::from_str(&self.win_condition).unwrap_or_default()
+    }
+
+    /// Groups of districts that win together under `WinCondition::LastAlliance`.
+    pub fn alliances(&self) -> Vec<Vec<i32>> {
+        self.alliances.clone()
+            .and_then(|alliances| serde_json::from_value(alliances).ok())
+            .unwrap_or_default()
+    }
+
+    /// Sets how the game is won before it starts, and which districts stand together.
+    pub fn set_win_condition(&self, win_condition: &WinCondition, alliances: &[Vec<i32>]) -> Result<(), String> {
+        if self.day.unwrap_or(0) > 0 {
+            return Err(format!("{} has already started", self.name));
+        }
+        if let Some(days) = win_condition.time_limit().filter(|days| *days < 2) {
+            return Err(format!("The time limit must be at least 2 days, got {}", days));
+        }
+        let mut seen = vec![];
+        for district in alliances.iter().flatten() {
+            if !(1..=self.districts).contains(district) {
+                return Err(format!("There's no District {} in {}", district, self.name));
+            }
+            if seen.contains(district) {
+                return Err(format!("District {} can only be in one alliance", district));
+            }
+            seen.push(*district);
+        }
+
+        let alliances = (!alliances.is_empty())
+            .then(|| serde_json::to_value(alliances).expect("Error serializing alliances"));
+        let connection = &mut establish_connection();
+        diesel::update(game::table.find(self.id))
+            .set((game::win_condition.eq(win_condition.to_string()), game::alliances.eq(alliances)))
+            .execute(connection)
+            .expect("Error updating game");
+        Ok(())
+    }
+
+    /// Rule changes announced during the game and not yet revoked.
+    pub fn rule_changes(&self) -> Vec<RuleChange> {
+        self.rule_changes.clone()
+            .and_then(|changes| serde_json::from_value(changes).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn set_rule_changes(&mut self, rule_changes: &[RuleChange]) {
+        let connection = &mut establish_connection();
+        let rule_changes = serde_json::to_value(rule_changes).expect("Error serializing rule changes");
+        diesel::update(game::table.find(self.id))
+            .set(game::rule_changes.eq(Some(&rule_changes)))
+            .execute(connection)
+            .expect("Error updating game");
+        self.rule_changes = Some(rule_changes);
+    }
+
+    /// Whether district mates can win together, by the ruleset or a rule change.
+    pub fn allows_co_victors(&self) -> bool {
+        self.ruleset().allows_co_victors() || self.rule_changes().contains(&RuleChange::DistrictCoVictors)
+    }
+
+    /// Whether tributes from these two districts stand together: district mates always do,
+    /// and so do alliance members when the game is won by alliance.
+    pub fn are_allies(&self, district: i32, other: i32) -> bool {
+        district == other || (self.win_condition() == WinCondition::LastAlliance
+            && self.alliances().iter().any(|a| a.contains(&district) && a.contains(&other)))
+    }
+
+    /// Whether the tributes still alive, given by district, have won the game between them.
+    pub fn is_won(&self, living_districts: &[i32]) -> bool {
+        self.win_condition().is_won(living_districts, &self.alliances(), self.allows_co_victors())
+    }
+
+    /// Errors if the game already has every tribute it takes.
+    pub fn check_room(&self) -> Result<(), String> {
+        if self.tributes().len() as i32 >= self.roster_size {
+            return Err(format!("{} is full at {} tributes", self.name, self.roster_size));
+        }
+        Ok(())
+    }
+
+    /// The district the next tribute to join is reaped from.
+    pub fn next_district(&self) -> i32 {
+        let taken = self.tributes().iter().map(|t| t.district).collect::<Vec<i32>>();
+        crate::games::next_district(&taken, self.roster_size, self.districts)
+    }
+
+    /// The rules hand-built tributes in this game are held to.
+    pub fn point_buy(&self) -> PointBuy {
+        PointBuy::new(self.stat_budget)
+    }
+
+    /// Stores balance settings for this game alone, or goes back to the global ones with `None`.
+    /// Settings that don't validate aren't saved.
+    pub fn set_tuning(&self, tuning: Option<&Tuning>) -> Result<(), Vec<String>> {
+        if let Some(tuning) = tuning {
+            tuning.validate()?;
+        }
+        let connection = &mut establish_connection();
+        let tuning = tuning.map(|t| serde_json::to_value(t).expect("Error serializing tuning"));
+        diesel::update(game::table.find(self.id))
+            .set(game::tuning.eq(tuning))
+            .execute(connection)
+            .expect("Error updating game");
+        Ok(())
+    }
+
+    /// The balance settings this game plays with, or the global ones if the saved
+    /// tuning no longer reads.
+    pub fn tuning(&self) -> Tuning {
+        self.tuning.clone()
+            .and_then(|tuning| Tuning::from_value(tuning).ok())
+            .unwrap_or_else(|| Tuning::global().clone())
+    }
+
+    /// The feasts on this game's schedule, soonest first. Games that never scheduled any
+    /// have one on the tuning's feast day.
+    pub fn feasts(&self) -> Vec<Feast> {
+        self.feasts.clone()
+            .and_then(|feasts| serde_json::from_value(feasts).ok())
+            .unwrap_or_else(|| vec![Feast::new(self.tuning().feast_day, Backpacks::None)])
+    }
+
+    pub fn feast_on(&self, day_number: i32) -> Option<Feast> {
+        self.feasts().into_iter().find(|feast| feast.day == day_number)
+    }
+
+    /// Adds a feast to the schedule. It has to be far enough off to announce the night before.
+    pub fn schedule_feast(&mut self, feast: Feast) -> Result<(), String> {
+        if self.ended_at.is_some() {
+            return Err(format!("{} is over", self.name));
+        }
+        let earliest = self.day.unwrap_or(0) + 2;
+        if feast.day < earliest {
+            return Err(format!("Feasts are announced the night before, so the earliest one {} can have is on day {}", self.name, earliest));
+        }
+        let mut feasts = self.feasts();
+        if feasts.iter().any(|f| f.day == feast.day) {
+            return Err(format!("There's already a feast on day {}", feast.day));
+        }
+        feasts.push(feast);
+        feasts.sort_by_key(|f| f.day);
+        self.set_feasts(&feasts);
+        Ok(())
+    }
+
+    pub fn cancel_feast(&mut self, day_number: i32) -> Result<(), String> {
+        if self.ended_at.is_some() {
+            return Err(format!("{} is over", self.name));
+        }
+        if day_number <= self.day.unwrap_or(0) {
+            return Err(format!("The feast on day {} has already happened", day_number));
+        }
+        let mut feasts = self.feasts();
+        if !feasts.iter().any(|f| f.day == day_number) {
+            return Err(format!("There's no feast on day {}", day_number));
+        }
+        feasts.retain(|f| f.day != day_number);
+        self.set_feasts(&feasts);
+        Ok(())
+    }
+
+    fn set_feasts(&mut self, feasts: &[Feast]) {
+        let connection = &mut establish_connection();
+        let feasts = serde_json::to_value(feasts).expect("Error serializing feasts");
+        diesel::update(game::table.find(self.id))
+            .set(game::feasts.eq(Some(&feasts)))
+            .execute(connection)
+            .expect("Error updating game");
+        self.feasts = Some(feasts);
+    }
+
+    pub fn in_finale(&self) -> bool {
+        self.finale_day.is_some()
+    }
+
+    /// Whether the finale has gone `days` days without anyone dying.
+    pub fn finale_stalled(&self, day_number: i32, days: i32) -> bool {
+        let Some(finale_day) = self.finale_day else { return false };
+        let last_death = get_dead_tributes(self).iter().filter_map(|t| t.day_killed).max().unwrap_or(0);
+        day_number - last_death.max(finale_day) >= days
+    }
+
+    pub fn start_finale(&mut self, day_number: i32) {
+        let connection = &mut establish_connection();
+        diesel::update(game::table.find(self.id))
+            .set(game::finale_day.eq(Some(day_number)))
+            .execute(connection)
+            .expect("Error updating game");
+        self.finale_day = Some(day_number);
     }
 
     pub fn set_day(&self, day_number: i32) {
@@ -113,6 +393,11 @@ impl Game {
     pub fn logs(&self) -> Vec<models::LogEntry> {
         models::log::get_logs_for_game(self.id)
     }
+
+    /// Logs of the given kinds only, e.g. `&["TributeAttackSuccessKill"]`.
+    pub fn logs_by_kind(&self, kinds: &[&str]) -> Vec<models::LogEntry> {
+        models::log::get_logs_for_game_by_kind(self.id, kinds)
+    }
 }
 
 #[derive(Insertable, Debug)]
@@ -233,19 +518,36 @@ pub fn get_recently_dead_tributes(game: &Game) -> Vec<Tribute> {
         .expect("Error loading recently dead tributes")
 }
 
-/// Fill the tribute table with up to 24 tributes.
-/// Return the number of tributes created.
-pub fn fill_tributes(game: &Game) -> usize {
+/// Top the game up to its roster size and return the number of tributes created.
+/// With `pair_districts`, each new tribute gets whichever of she/he their district
+/// mate doesn't have, like the books' reaping. Under victors-only rules, past victors
+/// are reaped first and new faces make up any shortfall.
+pub fn fill_tributes(game: &Game, pair_districts: bool) -> usize {
     let tributes = get_game_tributes(game);
     let count = tributes.len();
-    if count < 24 {
-        for _ in count..24 {
-            let name: String = Name(EN).fake();
-            let mut tribute = models::create_tribute(&name, None);
-            tribute.set_game(&game)
+    let roster_size = game.roster_size.max(0) as usize;
+    let mut reaped = count;
+    if game.ruleset().reaps_victors() {
+        reaped += reap_victors(game, roster_size.saturating_sub(count)).len();
+    }
+    for _ in reaped..roster_size {
+        let name: String = Name(EN).fake();
+        let mut tribute = models::create_tribute(&name, None);
+        tribute.set_game(game);
+        if pair_districts {
+            let pronouns = district_pair_pronouns(game, &tribute);
+            tribute.set_pronouns(Some(&pronouns));
         }
     }
-    24 - count
+    roster_size.saturating_sub(count)
+}
+
+fn district_pair_pronouns(game: &Game, tribute: &Tribute) -> Pronouns {
+    get_game_tributes(game).iter()
+        .filter(|t| t.district == tribute.district && t.id != tribute.id)
+        .filter_map(|t| t.pronouns.as_ref().and_then(|p| Pronouns::from_str(p).ok()))
+        .find_map(|p| p.counterpart())
+        .unwrap_or_else(Pronouns::random_binary)
 }
 
 pub fn delete_game_tributes(game_id: i32) {
@@ -19,6 +19,10 @@ pub struct Item {
     pub quantity: i32,
     pub attribute: String,
     pub effect: i32,
+    pub for_tribute_id: Option<i32>,
+    pub for_district: Option<i32>,
+    /// Names of what it was crafted from, if anything.
+    pub components: Option<serde_json::Value>,
 }
 
 impl Item {
@@ -85,6 +89,9 @@ impl From<crate::items::Item> for Item {
             quantity: item.quantity,
             attribute: item.attribute.to_string(),
             effect: item.effect,
+            for_tribute_id: item.for_tribute_id,
+            for_district: item.for_district,
+            components: components_value(&item.components),
         }
     }
 }
@@ -100,6 +107,9 @@ pub struct NewItem {
     pub quantity: i32,
     pub attribute: String,
     pub effect: i32,
+    pub for_tribute_id: Option<i32>,
+    pub for_district: Option<i32>,
+    pub components: Option<serde_json::Value>,
 }
 
 #[derive(Insertable, Debug, AsChangeset)]
@@ -114,6 +124,9 @@ pub struct UpdateItem {
     pub quantity: i32,
     pub attribute: String,
     pub effect: i32,
+    pub for_tribute_id: Option<i32>,
+    pub for_district: Option<i32>,
+    pub components: Option<serde_json::Value>,
 }
 
 impl From<Item> for UpdateItem {
@@ -128,6 +141,9 @@ impl From<Item> for UpdateItem {
             quantity: item.quantity,
             attribute: item.attribute,
             effect: item.effect,
+            for_tribute_id: item.for_tribute_id,
+            for_district: item.for_district,
+            components: item.components,
         }
     }
 }
@@ -144,10 +160,18 @@ impl From<crate::items::Item> for UpdateItem {
             quantity: item.quantity,
             attribute: item.attribute.to_string(),
             effect: item.effect,
+            for_tribute_id: item.for_tribute_id,
+            for_district: item.for_district,
+            components: components_value(&item.components),
         }
     }
 }
 
+/// Crafting components as they're stored, leaving out items that weren't crafted.
+fn components_value(components: &[String]) -> Option<serde_json::Value> {
+    if components.is_empty() { None } else { serde_json::to_value(components).ok() }
+}
+
 pub fn create_item(new_item: NewItem) -> Item {
     use crate::schema::item;
     let connection = &mut establish_connection();
@@ -177,6 +201,9 @@ impl From<Item> for NewItem {
             quantity: item.quantity,
             attribute: item.attribute,
             effect: item.effect,
+            for_tribute_id: item.for_tribute_id,
+            for_district: item.for_district,
+            components: item.components,
         }
     }
 }
@@ -192,6 +219,9 @@ impl From<crate::items::Item> for NewItem {
             quantity: item.quantity,
             attribute: item.attribute.to_string(),
             effect: item.effect,
+            for_tribute_id: item.for_tribute_id,
+            for_district: item.for_district,
+            components: components_value(&item.components),
         }
     }
 }
@@ -1,7 +1,10 @@
+use crate::i18n::Locale;
+use crate::messages::GameMessage;
 use crate::schema::log_entry;
 use crate::{establish_connection, models};
 use diesel::prelude::*;
 use models::get_game_by_id;
+use std::str::FromStr;
 
 #[derive(Queryable, Selectable, Debug, Associations, PartialEq, Clone)]
 #[diesel(table_name = log_entry)]
@@ -21,6 +24,10 @@ pub struct LogEntry {
     pub tribute_id: Option<i32>, // Tribute performing the action
     pub action_target_type: Option<String>, // Type of target of the action
     pub action_target_id: Option<i32>, // Target of the action
+
+    // the event itself, for filtering and replaying without parsing `message`
+    pub kind: Option<String>, // GameMessage variant, e.g. "TributeAttackSuccessKill"
+    pub payload: Option<serde_json::Value>, // Serialized GameMessage
 }
 
 #[derive(Insertable, Debug)]
@@ -33,6 +40,8 @@ pub struct NewLogEntry {
     pub tribute_id: Option<i32>,
     pub action_target_type: Option<String>,
     pub action_target_id: Option<i32>,
+    pub kind: Option<String>,
+    pub payload: Option<serde_json::Value>,
 }
 
 impl Default for NewLogEntry {
@@ -45,6 +54,8 @@ impl Default for NewLogEntry {
             tribute_id: None,
             action_target_type: None,
             action_target_id: None,
+            kind: None,
+            payload: None,
         }
     }
 }
@@ -61,6 +72,8 @@ impl LogEntry {
             tribute_id: None,
             action_target_type: None,
             action_target_id: None,
+            kind: None,
+            payload: None,
         };
 
         diesel::insert_into(log_entry::table)
@@ -76,11 +89,17 @@ impl LogEntry {
             None => None,
         }
     }
+
+    /// The event this entry was logged from, if it has a payload.
+    pub fn game_message(&self) -> Option<GameMessage> {
+        let payload = self.payload.clone()?;
+        serde_json::from_value(payload).ok()
+    }
 }
 
 pub fn create_full_log(
     game_id: i32,
-    message: String,
+    message: GameMessage,
     area_id: Option<i32>,
     tribute_id: Option<i32>,
     action_target_type: Option<String>,
@@ -97,11 +116,13 @@ pub fn create_full_log(
     let new_log_entry = NewLogEntry {
         game_id: selected_game.id,
         day: selected_game.day.unwrap_or(0),
-        message,
+        message: message.localized(&Locale::from_str(&selected_game.locale).unwrap_or_default()),
         area_id,
         tribute_id,
         action_target_type,
         action_target_id,
+        kind: Some(message.kind().to_string()),
+        payload: Some(serde_json::to_value(&message).expect("Error serializing log message")),
     };
 
     match diesel::insert_into(log_entry::table)
@@ -148,6 +169,18 @@ pub fn get_logs_for_game_day(id: i32, day: i32) -> Vec<LogEntry> {
         .expect("Error loading log entries")
 }
 
+/// Every log entry in a game of the given kinds, e.g. `&["TributeAttackSuccessKill", "DiedInArea"]`.
+pub fn get_logs_for_game_by_kind(id: i32, kinds: &[&str]) -> Vec<LogEntry> {
+    let connection = &mut establish_connection();
+    log_entry::table
+        .select(log_entry::all_columns)
+        .filter(log_entry::game_id.eq(id))
+        .filter(log_entry::kind.eq_any(kinds))
+        .order_by(log_entry::id)
+        .load(connection)
+        .expect("Error loading log entries")
+}
+
 pub fn get_logs_for_tribute(id: i32) -> Vec<LogEntry> {
     let connection = &mut establish_connection();
     log_entry::table
@@ -157,6 +190,18 @@ pub fn get_logs_for_tribute(id: i32) -> Vec<LogEntry> {
         .expect("Error loading log entries")
 }
 
+/// Every log entry about a tribute of the given kinds.
+pub fn get_logs_for_tribute_by_kind(id: i32, kinds: &[&str]) -> Vec<LogEntry> {
+    let connection = &mut establish_connection();
+    log_entry::table
+        .filter(log_entry::tribute_id.eq(id))
+        .filter(log_entry::kind.eq_any(kinds))
+        .order_by(log_entry::id)
+        .select(log_entry::all_columns)
+        .load(connection)
+        .expect("Error loading log entries")
+}
+
 pub fn delete_game_logs(game_id: i32) {
     let connection = &mut establish_connection();
     let count = diesel::delete(log_entry::table.filter(log_entry::game_id.eq(game_id)))
@@ -1,17 +1,29 @@
 pub mod action;
 pub mod area;
+pub mod bet;
 pub mod game;
 pub mod tribute;
 pub mod tribute_action;
+pub mod tribute_memory;
 pub mod event;
 pub mod log;
 pub mod item;
+pub mod spectator;
+pub mod spot_check;
+pub mod pack;
+pub mod trap;
 
 pub use action::*;
 pub use area::*;
+pub use bet::*;
 pub use event::*;
 pub use game::*;
 pub use item::*;
 pub use log::*;
+pub use pack::*;
+pub use spectator::*;
+pub use spot_check::*;
+pub use trap::*;
 pub use tribute::*;
 pub use tribute_action::*;
+pub use tribute_memory::*;
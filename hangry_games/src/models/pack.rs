@@ -0,0 +1,76 @@
+use crate::establish_connection;
+use crate::models::Game;
+use crate::schema::pack;
+use diesel::prelude::*;
+
+#[derive(Queryable, Selectable, Debug, Associations, Clone, AsChangeset)]
+#[diesel(table_name = pack)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(belongs_to(Game, foreign_key = game_id))]
+#[diesel(treat_none_as_null = true)]
+pub struct Pack {
+    pub id: i32,
+    pub game_id: i32,
+    pub area_id: i32,
+    pub animal: String,
+    pub count: i32,
+    pub health: i32,
+    pub name: Option<String>,
+    pub damage: Option<i32>,
+}
+
+#[derive(Insertable, Debug)]
+#[diesel(table_name = pack)]
+pub struct NewPack {
+    pub game_id: i32,
+    pub area_id: i32,
+    pub animal: String,
+    pub count: i32,
+    pub health: i32,
+    pub name: Option<String>,
+    pub damage: Option<i32>,
+}
+
+impl Pack {
+    pub fn create(new_pack: NewPack) -> Pack {
+        let connection = &mut establish_connection();
+        diesel::insert_into(pack::table)
+            .values(&new_pack)
+            .returning(Pack::as_returning())
+            .get_result(connection)
+            .expect("Error saving new pack")
+    }
+
+    /// Every pack still roaming the game's arena.
+    pub fn get_by_game(game_id: i32) -> Vec<Pack> {
+        let connection = &mut establish_connection();
+        pack::table
+            .filter(pack::game_id.eq(game_id))
+            .order_by(pack::id)
+            .select(Pack::as_select())
+            .load(connection)
+            .expect("Error loading packs")
+    }
+
+    pub fn update(&self) {
+        let connection = &mut establish_connection();
+        diesel::update(pack::table.find(self.id))
+            .set(self)
+            .execute(connection)
+            .expect("Error updating pack");
+    }
+
+    pub fn delete(&self) {
+        let connection = &mut establish_connection();
+        diesel::delete(pack::table.find(self.id))
+            .execute(connection)
+            .expect("Error deleting pack");
+    }
+}
+
+pub fn delete_game_packs(game_id: i32) {
+    let connection = &mut establish_connection();
+    diesel::delete(pack::table.filter(pack::game_id.eq(game_id)))
+        .execute(connection)
+        .expect("Error deleting packs");
+}
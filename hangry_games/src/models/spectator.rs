@@ -0,0 +1,75 @@
+use crate::establish_connection;
+use crate::models::Bet;
+use crate::schema::spectator;
+use diesel::prelude::*;
+
+#[derive(Queryable, Selectable, Debug, Clone, PartialEq)]
+#[diesel(table_name = spectator)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct Spectator {
+    pub id: i32,
+    pub name: String,
+    pub balance: i32,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+#[derive(Insertable, Debug)]
+#[diesel(table_name = spectator)]
+pub struct NewSpectator<'a> {
+    pub name: &'a str,
+}
+
+impl Spectator {
+    pub fn create(name: &str) -> Spectator {
+        let connection = &mut establish_connection();
+        diesel::insert_into(spectator::table)
+            .values(&NewSpectator { name })
+            .returning(Spectator::as_returning())
+            .get_result(connection)
+            .expect("Error saving new spectator")
+    }
+
+    /// Adds `amount` to the balance, or takes it away if it's negative.
+    pub fn adjust_balance(&mut self, amount: i32) {
+        let connection = &mut establish_connection();
+        self.balance = diesel::update(spectator::table.find(self.id))
+            .set(spectator::balance.eq(spectator::balance + amount))
+            .returning(spectator::balance)
+            .get_result(connection)
+            .expect("Error updating spectator");
+    }
+
+    /// Every bet the spectator has placed, newest first.
+    pub fn bets(&self) -> Vec<Bet> {
+        Bet::get_all_for_spectator(self.id)
+    }
+}
+
+pub fn get_spectator(name: &str) -> Option<Spectator> {
+    let connection = &mut establish_connection();
+    spectator::table
+        .filter(spectator::name.ilike(name))
+        .select(Spectator::as_select())
+        .first(connection)
+        .optional()
+        .expect("Error loading spectator")
+}
+
+pub fn get_spectator_by_id(id: i32) -> Spectator {
+    let connection = &mut establish_connection();
+    spectator::table
+        .find(id)
+        .select(Spectator::as_select())
+        .first(connection)
+        .expect("Error loading spectator")
+}
+
+/// Every spectator, richest first.
+pub fn get_standings() -> Vec<Spectator> {
+    let connection = &mut establish_connection();
+    spectator::table
+        .order_by((spectator::balance.desc(), spectator::name))
+        .select(Spectator::as_select())
+        .load(connection)
+        .expect("Error loading spectators")
+}
@@ -0,0 +1,60 @@
+use crate::establish_connection;
+use crate::models::Game;
+use crate::schema::spot_check;
+use diesel::prelude::*;
+
+#[derive(Queryable, Selectable, Debug, Associations, Clone)]
+#[diesel(table_name = spot_check)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(belongs_to(Game, foreign_key = game_id))]
+pub struct SpotCheck {
+    pub id: i32,
+    pub game_id: i32,
+    pub spotter_id: i32,
+    pub hider_id: i32,
+    pub spotted: bool,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+#[derive(Insertable, Debug)]
+#[diesel(table_name = spot_check)]
+pub struct NewSpotCheck {
+    pub game_id: i32,
+    pub spotter_id: i32,
+    pub hider_id: i32,
+    pub spotted: bool,
+}
+
+impl SpotCheck {
+    pub fn create(game_id: i32, spotter_id: i32, hider_id: i32, spotted: bool) -> SpotCheck {
+        let connection = &mut establish_connection();
+        let new_spot_check = NewSpotCheck { game_id, spotter_id, hider_id, spotted };
+
+        diesel::insert_into(spot_check::table)
+            .values(&new_spot_check)
+            .returning(SpotCheck::as_returning())
+            .get_result(connection)
+            .expect("Error saving new spot check")
+    }
+
+    /// This phase's result of `spotter_id` looking for `hider_id`, if they've looked yet.
+    pub fn get(game_id: i32, spotter_id: i32, hider_id: i32) -> Option<SpotCheck> {
+        let connection = &mut establish_connection();
+        spot_check::table
+            .filter(spot_check::game_id.eq(game_id))
+            .filter(spot_check::spotter_id.eq(spotter_id))
+            .filter(spot_check::hider_id.eq(hider_id))
+            .select(SpotCheck::as_select())
+            .first(connection)
+            .optional()
+            .expect("Error loading spot check")
+    }
+}
+
+/// Forget every spot check in the game, done at the start of each phase.
+pub fn clear_game_spot_checks(game_id: i32) {
+    let connection = &mut establish_connection();
+    diesel::delete(spot_check::table.filter(spot_check::game_id.eq(game_id)))
+        .execute(connection)
+        .expect("Error deleting spot checks");
+}
@@ -0,0 +1,66 @@
+use crate::establish_connection;
+use crate::models::Game;
+use crate::schema::trap;
+use diesel::prelude::*;
+
+#[derive(Queryable, Selectable, Debug, Associations, Clone)]
+#[diesel(table_name = trap)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(belongs_to(Game, foreign_key = game_id))]
+pub struct Trap {
+    pub id: i32,
+    pub game_id: i32,
+    pub area_id: i32,
+    pub owner_id: i32,
+    pub name: String,
+    pub strength: i32,
+    pub difficulty: i32,
+}
+
+#[derive(Insertable, Debug)]
+#[diesel(table_name = trap)]
+pub struct NewTrap {
+    pub game_id: i32,
+    pub area_id: i32,
+    pub owner_id: i32,
+    pub name: String,
+    pub strength: i32,
+    pub difficulty: i32,
+}
+
+impl Trap {
+    pub fn create(new_trap: NewTrap) -> Trap {
+        let connection = &mut establish_connection();
+        diesel::insert_into(trap::table)
+            .values(&new_trap)
+            .returning(Trap::as_returning())
+            .get_result(connection)
+            .expect("Error saving new trap")
+    }
+
+    /// Every trap still set somewhere in the game's area.
+    pub fn get_in_area(game_id: i32, area_id: i32) -> Vec<Trap> {
+        let connection = &mut establish_connection();
+        trap::table
+            .filter(trap::game_id.eq(game_id))
+            .filter(trap::area_id.eq(area_id))
+            .order_by(trap::id)
+            .select(Trap::as_select())
+            .load(connection)
+            .expect("Error loading traps")
+    }
+
+    pub fn delete(&self) {
+        let connection = &mut establish_connection();
+        diesel::delete(trap::table.find(self.id))
+            .execute(connection)
+            .expect("Error deleting trap");
+    }
+}
+
+pub fn delete_game_traps(game_id: i32) {
+    let connection = &mut establish_connection();
+    diesel::delete(trap::table.filter(trap::game_id.eq(game_id)))
+        .execute(connection)
+        .expect("Error deleting traps");
+}
@@ -1,8 +1,9 @@
 use super::get_area_by_id;
 use crate::establish_connection;
-use crate::models::{get_area, get_game_by_id, tribute_action, Action, Area, Game};
+use crate::models::{delete_tribute_bets, get_area, get_game_by_id, tribute_action, Action, Area, Game};
 use crate::schema::tribute;
 use crate::tributes::actors::Tribute as TributeActor;
+use crate::tributes::pronouns::Pronouns;
 use crate::tributes::statuses::TributeStatus;
 use diesel::prelude::*;
 
@@ -40,6 +41,14 @@ pub struct Tribute {
     pub status: String,
     pub avatar: Option<String>,
     pub real_name: Option<String>,
+    pub destination_id: Option<i32>,
+    pub stealth: Option<i32>,
+    pub breakdown: Option<String>,
+    pub pronouns: Option<String>,
+    pub training_score: Option<i32>,
+    pub likability: Option<i32>,
+    pub incubating: Option<String>,
+    pub incubation: Option<i32>,
 }
 
 impl Tribute {
@@ -81,17 +90,36 @@ impl Tribute {
         tribute_action::TributeAction::get_all_for_tribute(self.id)
     }
 
+    /// Get everything a Tribute remembers about the game so far.
+    pub fn memories(&self) -> Vec<crate::models::TributeMemory> {
+        crate::models::TributeMemory::get_all_for_tribute(self.id)
+    }
+
     pub fn take_action(&self, action: &Action) {
         use crate::models::TributeAction;
         TributeAction::create(self.id, action.id, None);
     }
 
+    /// Adds the tribute to the game, reaped from whichever district is next in line.
     pub fn set_game(&mut self, game: &Game) {
+        let district = game.next_district();
+        let connection = &mut establish_connection();
+        diesel::update(tribute::table.find(self.id))
+            .set((tribute::game_id.eq(Some(game.id)), tribute::district.eq(district)))
+            .execute(connection)
+            .expect("Error updating tribute");
+        self.district = district;
+    }
+
+    /// Set or clear the tribute's pronouns.
+    pub fn set_pronouns(&mut self, pronouns: Option<&Pronouns>) {
         let connection = &mut establish_connection();
+        let pronouns = pronouns.map(|p| p.to_string());
         diesel::update(tribute::table.find(self.id))
-            .set(tribute::game_id.eq(Some(game.id)))
+            .set(tribute::pronouns.eq(&pronouns))
             .execute(connection)
             .expect("Error updating tribute");
+        self.pronouns = pronouns;
     }
 
     pub fn unset_game(&mut self) {
@@ -106,9 +134,7 @@ impl Tribute {
         if self.game_id.is_some() {
             return Err("Tribute already has a game".to_string());
         }
-        if game.tributes().len() >= 24 {
-            return Err("Game is full".to_string());
-        }
+        game.check_room()?;
         self.set_game(game);
         Ok(())
     }
@@ -161,13 +187,27 @@ impl Tribute {
     pub fn delete(id: i32) {
         let connection = &mut establish_connection();
         use crate::schema::log_entry;
+        use crate::schema::spot_check;
         use crate::schema::tribute_action;
+        use crate::schema::tribute_memory;
         use crate::schema::tribute;
 
+        delete_tribute_bets(id);
+
         diesel::delete(log_entry::table.filter(log_entry::tribute_id.eq(id)))
             .execute(connection)
             .expect("Error deleting log entries");
 
+        diesel::delete(tribute_memory::table
+            .filter(tribute_memory::tribute_id.eq(id)))
+            .execute(connection)
+            .expect("Error deleting tribute memories");
+
+        diesel::delete(spot_check::table
+            .filter(spot_check::spotter_id.eq(id).or(spot_check::hider_id.eq(id))))
+            .execute(connection)
+            .expect("Error deleting spot checks");
+
         diesel::delete(tribute_action::table
             .filter(tribute_action::tribute_id.eq(id)))
             .execute(connection)
@@ -214,6 +254,14 @@ impl From<crate::tributes::actors::Tribute> for Tribute {
             status: tribute.status.to_string(),
             avatar: tribute.avatar,
             real_name: tribute.real_name,
+            destination_id: tribute.destination.map(|a| a.id()),
+            stealth: tribute.stealth,
+            breakdown: tribute.breakdown.map(|b| b.to_string()),
+            pronouns: tribute.pronouns.map(|p| p.to_string()),
+            training_score: tribute.training_score,
+            likability: tribute.likability,
+            incubating: tribute.incubating.map(|s| s.to_string()),
+            incubation: tribute.incubation,
         };
         out_tribute
     }
@@ -291,6 +339,14 @@ pub struct UpdateTribute {
     pub status: String,
     pub avatar: Option<String>,
     pub real_name: Option<String>,
+    pub destination_id: Option<Option<i32>>, // `Some(None)` clears it
+    pub stealth: Option<i32>,
+    pub breakdown: Option<Option<String>>, // `Some(None)` clears it
+    pub pronouns: Option<Option<String>>, // `Some(None)` clears it
+    pub training_score: Option<i32>,
+    pub likability: Option<i32>,
+    pub incubating: Option<Option<String>>, // `Some(None)` clears it
+    pub incubation: Option<Option<i32>>, // `Some(None)` clears it
 }
 
 #[derive(Insertable, Debug, AsChangeset)]
@@ -401,6 +457,14 @@ pub fn update_tribute(tribute_id: i32, tribute: Tribute) {
         status: tribute.status,
         avatar: tribute.avatar,
         real_name: tribute.real_name,
+        destination_id: Some(tribute.destination_id),
+        stealth: tribute.stealth,
+        breakdown: Some(tribute.breakdown),
+        pronouns: Some(tribute.pronouns),
+        training_score: tribute.training_score,
+        likability: tribute.likability,
+        incubating: Some(tribute.incubating),
+        incubation: Some(tribute.incubation),
     };
     diesel::update(tribute::table.find(tribute_id))
         .set(&update_tribute)
@@ -0,0 +1,72 @@
+use crate::establish_connection;
+use crate::models::{get_game_by_id, Area, Game, Tribute};
+use crate::schema::tribute_memory;
+use diesel::prelude::*;
+
+#[derive(Queryable, Selectable, Debug, Associations, Clone)]
+#[diesel(table_name = tribute_memory)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(belongs_to(Area, foreign_key = area_id))]
+#[diesel(belongs_to(Game, foreign_key = game_id))]
+#[diesel(belongs_to(Tribute, foreign_key = tribute_id))]
+pub struct TributeMemory {
+    pub id: i32,
+    pub tribute_id: i32,
+    pub game_id: i32,
+    pub day: i32,
+    pub kind: String,
+    pub subject_id: i32, // Tribute or item the memory is about
+    pub area_id: Option<i32>,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+#[derive(Insertable, Debug)]
+#[diesel(table_name = tribute_memory)]
+pub struct NewTributeMemory {
+    pub tribute_id: i32,
+    pub game_id: i32,
+    pub day: i32,
+    pub kind: String,
+    pub subject_id: i32,
+    pub area_id: Option<i32>,
+}
+
+impl TributeMemory {
+    pub fn create(tribute_id: i32, game_id: i32, kind: String, subject_id: i32, area_id: Option<i32>) -> TributeMemory {
+        let connection = &mut establish_connection();
+        let game = get_game_by_id(game_id).expect("Game not found");
+        let new_memory = NewTributeMemory {
+            tribute_id,
+            game_id,
+            day: game.day.unwrap_or(0),
+            kind,
+            subject_id,
+            area_id,
+        };
+
+        diesel::insert_into(tribute_memory::table)
+            .values(&new_memory)
+            .returning(TributeMemory::as_returning())
+            .get_result(connection)
+            .expect("Error saving new tribute memory")
+    }
+
+    /// Get all the memories for a tribute, oldest first.
+    pub fn get_all_for_tribute(tribute_id: i32) -> Vec<TributeMemory> {
+        let connection = &mut establish_connection();
+        tribute_memory::table
+            .filter(tribute_memory::tribute_id.eq(tribute_id))
+            .order_by(tribute_memory::id)
+            .select(tribute_memory::all_columns)
+            .load(connection)
+            .expect("Error loading tribute memories")
+    }
+}
+
+pub fn delete_game_tribute_memories(game_id: i32) {
+    let connection = &mut establish_connection();
+    let count = diesel::delete(tribute_memory::table.filter(tribute_memory::game_id.eq(game_id)))
+        .execute(connection)
+        .expect("Error deleting tribute memories");
+    println!("Deleted {} tribute memories", count);
+}
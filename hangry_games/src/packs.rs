@@ -0,0 +1,188 @@
+use crate::animals::Animal;
+use crate::areas::Area;
+use crate::i18n::Locale;
+use crate::models::pack::{NewPack, Pack as PackModel};
+use crate::tuning::Tuning;
+use rand::prelude::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Animals, or the gamemakers' mutts, roaming the arena together.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Pack {
+    pub id: Option<i32>,
+    pub game_id: i32,
+    pub area: Area,
+    pub animal: Animal,
+    pub count: i32,
+    /// The whole pack's health; animals drop as it wears down.
+    pub health: i32,
+    /// What the gamemakers call their mutts, if they named them.
+    pub name: Option<String>,
+    /// How hard each of them bites, if not as hard as their species.
+    pub damage: Option<i32>,
+}
+
+impl Pack {
+    /// Lets a pack loose in `area`, at full health.
+    pub fn new(game_id: i32, area: Area, animal: Animal, count: i32, name: Option<String>, damage: Option<i32>) -> Pack {
+        let mut pack = Pack { id: None, game_id, area, animal, count, health: 0, name, damage };
+        pack.health = pack.toughness() * count;
+        let model = PackModel::create(NewPack::from(&pack));
+        pack.id = Some(model.id);
+        pack
+    }
+
+    /// A few of some wild animal, wandering in from the edges of `area`.
+    pub fn wild(game_id: i32, area: Area, rng: &mut impl Rng) -> Pack {
+        Pack::new(game_id, area, Animal::random(), rng.gen_range(2..=5), None, None)
+    }
+
+    /// Every pack still roaming the game's arena.
+    pub fn all(game_id: i32) -> Vec<Pack> {
+        PackModel::get_by_game(game_id).into_iter().map(Pack::from).collect()
+    }
+
+    /// Saves the pack, or clears it out of the arena once the last of them is dead.
+    pub fn save(&self) {
+        let model = PackModel::from(self);
+        if self.is_alive() { model.update() } else { model.delete() }
+    }
+
+    /// Mutts were bred to hunt tributes, so they follow the crowd and aren't fit to eat.
+    /// Wild animals wander where they like and can be hunted for meat.
+    pub fn is_mutts(&self) -> bool {
+        self.animal == Animal::Mutt
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.count > 0
+    }
+
+    /// How much it takes to bring down one of them. The harder they bite, the harder they are to kill.
+    pub fn toughness(&self) -> i32 {
+        self.damage.unwrap_or(self.animal.damage()) * 5
+    }
+
+    /// How hard each of them bites.
+    pub fn bite(&self, tuning: &Tuning) -> i32 {
+        self.damage.unwrap_or(tuning.animal_damage(&self.animal))
+    }
+
+    /// What they're called in `locale`, by name if the gamemakers gave them one.
+    pub fn label(&self, locale: &Locale) -> String {
+        self.name.clone().unwrap_or(self.animal.localized(locale, self.count))
+    }
+
+    /// Wears the pack down by `damage`, returning how many of them it killed.
+    pub fn takes_damage(&mut self, damage: i32) -> i32 {
+        let before = self.count;
+        self.health = std::cmp::max(0, self.health - damage);
+        self.count = (self.health + self.toughness() - 1) / self.toughness();
+        before - self.count
+    }
+
+    /// Where the pack heads next. Mutts go wherever the most tributes are, wild animals
+    /// wander off now and again, and nothing stays in a closed area if it can help it.
+    pub fn roam(&self, closed_areas: &[Area], tributes_in: impl Fn(&Area) -> usize, rng: &mut impl Rng) -> Area {
+        let open = self.area.neighbors().into_iter()
+            .filter(|area| !closed_areas.contains(area))
+            .collect::<Vec<Area>>();
+        let trapped = closed_areas.contains(&self.area);
+
+        if self.is_mutts() {
+            let here = if trapped { 0 } else { tributes_in(&self.area) };
+            return match open.iter().max_by_key(|area| tributes_in(area)) {
+                Some(area) if tributes_in(area) > here => area.clone(),
+                _ => self.area.clone(),
+            };
+        }
+        match open.choose(rng) {
+            Some(area) if trapped || rng.gen_bool(0.25) => area.clone(),
+            _ => self.area.clone(),
+        }
+    }
+}
+
+impl From<PackModel> for Pack {
+    fn from(pack: PackModel) -> Self {
+        Pack {
+            id: Some(pack.id),
+            game_id: pack.game_id,
+            area: Area::get_by_id(pack.area_id).unwrap(),
+            animal: Animal::from_str(pack.animal.as_str()).unwrap(),
+            count: pack.count,
+            health: pack.health,
+            name: pack.name,
+            damage: pack.damage,
+        }
+    }
+}
+
+impl From<&Pack> for NewPack {
+    fn from(pack: &Pack) -> Self {
+        NewPack {
+            game_id: pack.game_id,
+            area_id: pack.area.id(),
+            animal: pack.animal.to_string(),
+            count: pack.count,
+            health: pack.health,
+            name: pack.name.clone(),
+            damage: pack.damage,
+        }
+    }
+}
+
+impl From<&Pack> for PackModel {
+    fn from(pack: &Pack) -> Self {
+        PackModel {
+            id: pack.id.unwrap(),
+            game_id: pack.game_id,
+            area_id: pack.area.id(),
+            animal: pack.animal.to_string(),
+            count: pack.count,
+            health: pack.health,
+            name: pack.name.clone(),
+            damage: pack.damage,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn wolves(count: i32) -> Pack {
+        let mut pack = Pack { id: None, game_id: 0, area: Area::Cornucopia, animal: Animal::Wolf, count, health: 0, name: None, damage: None };
+        pack.health = pack.toughness() * count;
+        pack
+    }
+
+    #[test]
+    fn wounds_thin_out_the_pack() {
+        let mut pack = wolves(3);
+        assert_eq!(pack.takes_damage(10), 0);
+        assert_eq!(pack.takes_damage(20), 1);
+        assert_eq!(pack.count, 2);
+        assert_eq!(pack.takes_damage(100), 2);
+        assert!(!pack.is_alive());
+    }
+
+    #[test]
+    fn mutts_go_where_the_tributes_are() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut mutts = wolves(3);
+        mutts.animal = Animal::Mutt;
+        let crowd = |area: &Area| if *area == Area::Northeast { 3 } else { 1 };
+        assert_eq!(mutts.roam(&[], crowd, &mut rng), Area::Northeast);
+        assert_eq!(mutts.roam(&[Area::Northeast], crowd, &mut rng), Area::Cornucopia);
+
+        let pack = wolves(3);
+        for _ in 0..20 {
+            assert_ne!(pack.roam(&[Area::Cornucopia], |_| 0, &mut rng), Area::Cornucopia);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::i18n::{translate, Locale};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+use strum::EnumIter;
+
+/// House rules a game is played under, picked when it's created.
+#[derive(Clone, Debug, Default, EnumIter, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Ruleset {
+    #[default]
+    Standard,
+    /// Tributes are reaped from the victors of earlier games.
+    VictorsOnly,
+    /// Twice as many tributes are reaped from each district.
+    DoubleTributes,
+    /// District mates can win together.
+    CoVictors,
+    /// Sponsors can't send gifts.
+    NoSponsors,
+    /// Every night, a hazard sweeps the whole arena.
+    NightHazards,
+}
+
+impl Ruleset {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Ruleset::Standard => "standard",
+            Ruleset::VictorsOnly => "victors only",
+            Ruleset::DoubleTributes => "double tributes",
+            Ruleset::CoVictors => "co-victors",
+            Ruleset::NoSponsors => "no sponsors",
+            Ruleset::NightHazards => "night hazards",
+        }
+    }
+
+    /// The ruleset's name in `locale`.
+    pub fn localized(&self, locale: &Locale) -> String {
+        translate(locale, &format!("ruleset-{}", self.as_str().replace(' ', "-")), None)
+    }
+
+    /// How many tributes each district sends for every one it would send under standard rules.
+    pub fn reaping_multiplier(&self) -> i32 {
+        match self {
+            Ruleset::DoubleTributes => 2,
+            _ => 1,
+        }
+    }
+
+    pub fn reaps_victors(&self) -> bool {
+        *self == Ruleset::VictorsOnly
+    }
+
+    pub fn allows_sponsors(&self) -> bool {
+        *self != Ruleset::NoSponsors
+    }
+
+    pub fn has_night_hazards(&self) -> bool {
+        *self == Ruleset::NightHazards
+    }
+
+    /// Whether district mates can win together, whatever the game's win condition.
+    pub fn allows_co_victors(&self) -> bool {
+        *self == Ruleset::CoVictors
+    }
+}
+
+impl FromStr for Ruleset {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace(['-', '_'], " ").as_str() {
+            "standard" => Ok(Ruleset::Standard),
+            "victors only" | "victors" => Ok(Ruleset::VictorsOnly),
+            "double tributes" | "double" => Ok(Ruleset::DoubleTributes),
+            "co victors" | "covictors" => Ok(Ruleset::CoVictors),
+            "no sponsors" => Ok(Ruleset::NoSponsors),
+            "night hazards" => Ok(Ruleset::NightHazards),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Ruleset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn round_trips_through_its_name() {
+        for ruleset in Ruleset::iter() {
+            assert_eq!(Ruleset::from_str(ruleset.as_str()), Ok(ruleset.clone()));
+        }
+        assert_eq!(Ruleset::from_str("victors-only"), Ok(Ruleset::VictorsOnly));
+        assert_eq!(Ruleset::from_str("mutts only"), Err(()));
+    }
+}
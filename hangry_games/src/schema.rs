@@ -27,6 +27,23 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    bet (id) {
+        id -> Int4,
+        game_id -> Int4,
+        spectator_id -> Int4,
+        tribute_id -> Int4,
+        #[max_length = 255]
+        kind -> Varchar,
+        kills -> Nullable<Int4>,
+        stake -> Int4,
+        odds -> Float8,
+        day -> Int4,
+        payout -> Nullable<Int4>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     game (id) {
         id -> Int4,
@@ -35,6 +52,20 @@ diesel::table! {
         day -> Nullable<Int4>,
         closed_areas -> Nullable<Array<Nullable<Int4>>>,
         ended_at -> Nullable<Timestamp>,
+        #[max_length = 8]
+        locale -> Varchar,
+        stat_budget -> Int4,
+        tuning -> Nullable<Jsonb>,
+        roster_size -> Int4,
+        districts -> Int4,
+        #[max_length = 32]
+        ruleset -> Varchar,
+        #[max_length = 32]
+        win_condition -> Varchar,
+        alliances -> Nullable<Jsonb>,
+        rule_changes -> Nullable<Jsonb>,
+        finale_day -> Nullable<Int4>,
+        feasts -> Nullable<Jsonb>,
     }
 }
 
@@ -52,6 +83,9 @@ diesel::table! {
         #[max_length = 255]
         attribute -> Varchar,
         effect -> Int4,
+        for_tribute_id -> Nullable<Int4>,
+        for_district -> Nullable<Int4>,
+        components -> Nullable<Jsonb>,
     }
 }
 
@@ -67,6 +101,58 @@ diesel::table! {
         #[max_length = 255]
         action_target_type -> Nullable<Varchar>,
         action_target_id -> Nullable<Int4>,
+        #[max_length = 255]
+        kind -> Nullable<Varchar>,
+        payload -> Nullable<Jsonb>,
+    }
+}
+
+diesel::table! {
+    spectator (id) {
+        id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        balance -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    pack (id) {
+        id -> Int4,
+        game_id -> Int4,
+        area_id -> Int4,
+        #[max_length = 32]
+        animal -> Varchar,
+        count -> Int4,
+        health -> Int4,
+        #[max_length = 64]
+        name -> Nullable<Varchar>,
+        damage -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    spot_check (id) {
+        id -> Int4,
+        game_id -> Int4,
+        spotter_id -> Int4,
+        hider_id -> Int4,
+        spotted -> Bool,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    trap (id) {
+        id -> Int4,
+        game_id -> Int4,
+        area_id -> Int4,
+        owner_id -> Int4,
+        #[max_length = 64]
+        name -> Varchar,
+        strength -> Int4,
+        difficulty -> Int4,
     }
 }
 
@@ -103,6 +189,17 @@ diesel::table! {
         status -> Varchar,
         avatar -> Nullable<Text>,
         real_name -> Nullable<Text>,
+        destination_id -> Nullable<Int4>,
+        stealth -> Nullable<Int4>,
+        #[max_length = 255]
+        breakdown -> Nullable<Varchar>,
+        #[max_length = 255]
+        pronouns -> Nullable<Varchar>,
+        training_score -> Nullable<Int4>,
+        likability -> Nullable<Int4>,
+        #[max_length = 255]
+        incubating -> Nullable<Varchar>,
+        incubation -> Nullable<Int4>,
     }
 }
 
@@ -116,26 +213,58 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    tribute_memory (id) {
+        id -> Int4,
+        tribute_id -> Int4,
+        game_id -> Int4,
+        day -> Int4,
+        #[max_length = 255]
+        kind -> Varchar,
+        subject_id -> Int4,
+        area_id -> Nullable<Int4>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::joinable!(area_event -> area (area_id));
 diesel::joinable!(area_event -> game (game_id));
+diesel::joinable!(bet -> game (game_id));
+diesel::joinable!(bet -> spectator (spectator_id));
+diesel::joinable!(bet -> tribute (tribute_id));
 diesel::joinable!(item -> area (area_id));
 diesel::joinable!(item -> game (game_id));
 diesel::joinable!(item -> tribute (tribute_id));
 diesel::joinable!(log_entry -> area (area_id));
 diesel::joinable!(log_entry -> game (game_id));
 diesel::joinable!(log_entry -> tribute (tribute_id));
+diesel::joinable!(pack -> area (area_id));
+diesel::joinable!(pack -> game (game_id));
+diesel::joinable!(spot_check -> game (game_id));
+diesel::joinable!(trap -> area (area_id));
+diesel::joinable!(trap -> game (game_id));
+diesel::joinable!(trap -> tribute (owner_id));
 diesel::joinable!(tribute -> area (area_id));
 diesel::joinable!(tribute -> game (game_id));
 diesel::joinable!(tribute_action -> action (action_id));
 diesel::joinable!(tribute_action -> tribute (tribute_id));
+diesel::joinable!(tribute_memory -> area (area_id));
+diesel::joinable!(tribute_memory -> game (game_id));
+diesel::joinable!(tribute_memory -> tribute (tribute_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     action,
     area,
     area_event,
+    bet,
     game,
     item,
     log_entry,
+    pack,
+    spectator,
+    spot_check,
+    trap,
     tribute,
     tribute_action,
+    tribute_memory,
 );
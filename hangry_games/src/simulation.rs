@@ -0,0 +1,524 @@
+use crate::animals::Animal;
+use crate::areas::Area;
+use crate::events::{AreaEvent, TributeEvent};
+use crate::games::{next_district, DEFAULT_DISTRICTS, DEFAULT_ROSTER_SIZE};
+use crate::items::Item;
+use crate::tributes::actions::{AttackResult, TributeAction};
+use crate::tributes::actors::{attack_total, contest_damage, contest_result, defense_total, interview_likability, spotted, target_weight, training_score, Tribute};
+use crate::tributes::brains::{feast_appeal, instinct};
+use crate::tributes::point_buy::Stat;
+use crate::tributes::statuses::TributeStatus;
+use crate::tuning::Tuning;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use strum::IntoEnumIterator;
+
+/// Games still going after this many days are called off with no winner.
+const MAX_DAYS: i32 = 100;
+/// Tributes are grouped by their total stat points in steps of this size.
+const STAT_BUCKET_SIZE: i32 = 200;
+
+/// How often a group of tributes won.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub struct WinRate {
+    pub entrants: u32,
+    pub wins: u32,
+    /// Wins per entrant. A fair share in a full game is 1 in 24.
+    pub rate: f64,
+}
+
+/// Outcomes across a batch of simulated games.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Report {
+    pub games: u32,
+    pub seed: u64,
+    /// The balance settings the games were played with.
+    pub tuning: Tuning,
+    pub no_winner: u32,
+    pub average_days: f64,
+    pub districts: BTreeMap<i32, WinRate>,
+    pub stat_buckets: BTreeMap<String, WinRate>,
+    pub causes_of_death: BTreeMap<String, u32>,
+    /// How many tributes finished a game with each kill count.
+    pub kills: BTreeMap<i32, u32>,
+    pub average_winner_kills: f64,
+}
+
+/// One game played out in memory. Dead tributes' `killed_by` holds what killed them:
+/// "attack", the status they died of, or the area event that caught them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Outcome {
+    pub days: i32,
+    pub tributes: Vec<Tribute>,
+}
+
+impl Outcome {
+    pub fn winner(&self) -> Option<&Tribute> {
+        let mut living = self.tributes.iter().filter(|t| t.is_alive());
+        match (living.next(), living.next()) {
+            (Some(winner), None) => Some(winner),
+            _ => None,
+        }
+    }
+}
+
+/// Plays `games` games with freshly rolled rosters and tallies how they went.
+/// The same seed and tuning always give the same report.
+pub fn simulate(games: u32, seed: u64, tuning: &Tuning) -> Report {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut report = Report { seed, tuning: tuning.clone(), ..Report::default() };
+    for _ in 0..games {
+        let outcome = Simulation::new(&mut rng, tuning).play();
+        report.record(&outcome);
+    }
+    report.finish();
+    report
+}
+
+fn stat_bucket(tribute: &Tribute) -> String {
+    let total: i32 = Stat::iter().map(|stat| stat.get(tribute)).sum();
+    let low = total / STAT_BUCKET_SIZE * STAT_BUCKET_SIZE;
+    format!("{:03}-{:03}", low, low + STAT_BUCKET_SIZE - 1)
+}
+
+impl Report {
+    fn record(&mut self, outcome: &Outcome) {
+        self.games += 1;
+        self.average_days += outcome.days as f64;
+        let winner = outcome.winner().map(|t| t.id);
+        if winner.is_none() {
+            self.no_winner += 1;
+        }
+
+        for tribute in &outcome.tributes {
+            let won = winner == Some(tribute.id);
+            for rate in [
+                self.districts.entry(tribute.district).or_default(),
+                self.stat_buckets.entry(stat_bucket(tribute)).or_default(),
+            ] {
+                rate.entrants += 1;
+                rate.wins += won as u32;
+            }
+            if let Some(cause) = &tribute.killed_by {
+                *self.causes_of_death.entry(cause.clone()).or_default() += 1;
+            }
+            *self.kills.entry(tribute.kills.unwrap_or(0)).or_default() += 1;
+            if won {
+                self.average_winner_kills += tribute.kills.unwrap_or(0) as f64;
+            }
+        }
+    }
+
+    fn finish(&mut self) {
+        if self.games > 0 {
+            self.average_days /= self.games as f64;
+        }
+        let winners = self.games - self.no_winner;
+        if winners > 0 {
+            self.average_winner_kills /= winners as f64;
+        }
+        for rate in self.districts.values_mut().chain(self.stat_buckets.values_mut()) {
+            rate.rate = rate.wins as f64 / rate.entrants as f64;
+        }
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} games, seed {}", self.games, self.seed)?;
+        writeln!(f, "Average length: {:.1} days", self.average_days)?;
+        writeln!(f, "No winner: {}", self.no_winner)?;
+        writeln!(f, "Winner's average kills: {:.2}", self.average_winner_kills)?;
+
+        writeln!(f, "\n{:<12} {:>9} {:>6} {:>8}", "District", "Entrants", "Wins", "Win %")?;
+        for (district, rate) in &self.districts {
+            writeln!(f, "{:<12} {:>9} {:>6} {:>7.1}%", district, rate.entrants, rate.wins, rate.rate * 100.0)?;
+        }
+
+        writeln!(f, "\n{:<12} {:>9} {:>6} {:>8}", "Stat points", "Entrants", "Wins", "Win %")?;
+        for (bucket, rate) in &self.stat_buckets {
+            writeln!(f, "{:<12} {:>9} {:>6} {:>7.1}%", bucket, rate.entrants, rate.wins, rate.rate * 100.0)?;
+        }
+
+        let deaths: u32 = self.causes_of_death.values().sum();
+        writeln!(f, "\n{:<24} {:>7} {:>8}", "Cause of death", "Deaths", "Share")?;
+        let mut causes = self.causes_of_death.iter().collect::<Vec<_>>();
+        causes.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        for (cause, count) in causes {
+            writeln!(f, "{:<24} {:>7} {:>7.1}%", cause, count, *count as f64 / deaths as f64 * 100.0)?;
+        }
+
+        writeln!(f, "\n{:<12} {:>9}", "Kills", "Tributes")?;
+        for (kills, count) in &self.kills {
+            writeln!(f, "{:<12} {:>9}", kills, count)?;
+        }
+        Ok(())
+    }
+}
+
+/// A game played entirely in memory, following the engine's rules for area events,
+/// tribute events, statuses, instinctive actions and fights, which roll and wear out
+/// weapons and shields from the Cornucopia the same way. Consumables, feast backpacks,
+/// sponsors, event packs, memories, packs, survival, crafting, traps, contagion,
+/// rulesets and win conditions are left out, so only the core balance is measured.
+struct Simulation<'a> {
+    rng: &'a mut StdRng,
+    tuning: &'a Tuning,
+    day: i32,
+    tributes: Vec<Tribute>,
+    /// What each tribute is carrying, by their index in `tributes`.
+    gear: Vec<Vec<Item>>,
+    /// Weapons and shields lying at the Cornucopia.
+    cornucopia: Vec<Item>,
+    closed_areas: Vec<(Area, AreaEvent)>,
+    finale_day: Option<i32>,
+}
+
+impl<'a> Simulation<'a> {
+    /// A standard roster with random stats, scored in training and interviewed.
+    fn new(rng: &'a mut StdRng, tuning: &'a Tuning) -> Simulation<'a> {
+        let mut districts = vec![];
+        let tributes = (0..DEFAULT_ROSTER_SIZE).map(|index| {
+            let district = next_district(&districts, DEFAULT_ROSTER_SIZE, DEFAULT_DISTRICTS);
+            districts.push(district);
+            let mut tribute = Tribute::new(format!("Tribute {}", index + 1), Some(district), None);
+            tribute.id = Some(index + 1);
+            for stat in Stat::iter() {
+                stat.set(&mut tribute, rng.gen_range(1..=stat.max()));
+            }
+            tribute.training_score = Some(training_score(&tribute, rng.gen_range(-15..=15)));
+            tribute.likability = Some(interview_likability(&tribute, rng.gen_range(-20..=20)));
+            tribute
+        }).collect::<Vec<Tribute>>();
+        // The same weapons the engine lays out when a game starts
+        let cornucopia = (0..10).map(|_| Item::rolled_weapon("weapon".to_string(), rng)).collect();
+        let gear = vec![vec![]; tributes.len()];
+        Simulation { rng, tuning, day: 0, tributes, gear, cornucopia, closed_areas: vec![], finale_day: None }
+    }
+
+    fn play(mut self) -> Outcome {
+        while self.living() > 1 && self.day < MAX_DAYS {
+            self.day += 1;
+            if self.finale_day.is_none() && self.tuning.finale_due(self.living(), self.day) {
+                self.finale_day = Some(self.day);
+            }
+            if self.day == self.tuning.feast_day {
+                self.restock();
+            }
+            self.phase(true);
+            self.phase(false);
+        }
+        Outcome { days: self.day, tributes: self.tributes }
+    }
+
+    fn living(&self) -> usize {
+        self.tributes.iter().filter(|t| t.is_alive()).count()
+    }
+
+    fn closed(&self) -> Vec<Area> {
+        self.closed_areas.iter().map(|(area, _)| area.clone()).collect()
+    }
+
+    fn dies(&mut self, index: usize, cause: String) {
+        let tribute = &mut self.tributes[index];
+        tribute.health = 0;
+        tribute.dies();
+        tribute.status = TributeStatus::Dead;
+        tribute.day_killed = Some(self.day);
+        tribute.killed_by = Some(cause);
+    }
+
+    fn phase(&mut self, day: bool) {
+        self.clean_up_area_events();
+
+        if self.day > 3 || !day {
+            let frequency = if day { self.tuning.day_event_frequency } else { self.tuning.night_event_frequency };
+            if self.rng.gen_bool(frequency) {
+                if self.finale_day.is_some() { self.close_outer_area() } else { self.close_random_area() }
+            }
+        }
+
+        let living = self.living();
+        if self.finale_day.is_some() {
+            self.close_outer_area();
+        } else if living > 1 && living < self.tuning.area_closing_threshold {
+            self.close_random_area();
+            if self.rng.gen_bool(living as f64 / DEFAULT_ROSTER_SIZE as f64) {
+                self.close_random_area();
+            }
+        }
+
+        let mut order = (0..self.tributes.len()).collect::<Vec<usize>>();
+        order.shuffle(self.rng);
+        for index in order {
+            if !self.tributes[index].is_alive() {
+                continue;
+            }
+            let luck = self.tributes[index].luck.unwrap_or(0) as f64 / 100.0;
+            if !self.rng.gen_bool(luck) {
+                let event = self.tribute_event();
+                self.tributes[index].handle_event(event);
+            }
+            if (self.is_holdout(index) || self.stalled()) && self.rng.gen_bool(self.tuning.mutt_chance) {
+                let tribute = &mut self.tributes[index];
+                tribute.status = TributeStatus::Mauled(Animal::Mutt);
+                tribute.status_effects(self.rng, self.tuning);
+                tribute.status = TributeStatus::Wounded;
+                if tribute.health <= 0 {
+                    self.dies(index, TributeStatus::Mauled(Animal::Mutt).to_string());
+                    continue;
+                }
+            }
+            self.turn(index, day);
+        }
+    }
+
+    fn tribute_event(&mut self) -> TributeEvent {
+        match TributeEvent::all().choose(self.rng).unwrap().clone() {
+            TributeEvent::AnimalAttack(_) => {
+                let animal = Animal::iter().filter(|animal| *animal != Animal::Mutt).choose(self.rng).unwrap();
+                TributeEvent::AnimalAttack(animal)
+            }
+            event => event,
+        }
+    }
+
+    fn close_random_area(&mut self) {
+        self.close_one_of(vec![Area::Cornucopia, Area::Northeast, Area::Northwest, Area::Southeast, Area::Southwest]);
+    }
+
+    fn close_outer_area(&mut self) {
+        self.close_one_of(Area::Cornucopia.neighbors());
+    }
+
+    fn close_one_of(&mut self, areas: Vec<Area>) {
+        let closed = self.closed();
+        let open = areas.into_iter()
+            .filter(|area| !closed.contains(area))
+            .collect::<Vec<Area>>();
+        if let Some(area) = open.choose(self.rng) {
+            let event = [
+                AreaEvent::Wildfire, AreaEvent::Flood, AreaEvent::Earthquake, AreaEvent::Avalanche,
+                AreaEvent::Blizzard, AreaEvent::Landslide, AreaEvent::Heatwave,
+            ].choose(self.rng).unwrap().clone();
+            self.closed_areas.push((area.clone(), event));
+        }
+    }
+
+    /// Anyone caught in a closed area is hurt if they're lucky and killed if not.
+    /// Each area then has a chance of opening back up, though only the Cornucopia does in the finale.
+    fn clean_up_area_events(&mut self) {
+        for (area, event) in self.closed_areas.clone() {
+            for index in 0..self.tributes.len() {
+                let tribute = &self.tributes[index];
+                if !tribute.is_alive() || tribute.area.as_ref() != Some(&area) {
+                    continue;
+                }
+                if self.rng.gen_bool(tribute.luck.unwrap_or(0) as f64 / 100.0) {
+                    let tribute = &mut self.tributes[index];
+                    tribute.status = event.status();
+                    tribute.takes_mental_damage(15);
+                } else {
+                    self.dies(index, event.to_string());
+                }
+            }
+            if (self.finale_day.is_none() || area == Area::Cornucopia) && self.rng.gen_bool(self.tuning.area_reopen_chance) {
+                self.closed_areas.retain(|(closed, _)| *closed != area);
+            }
+        }
+    }
+
+    /// A living tribute still away from the Cornucopia once the finale has begun.
+    fn is_holdout(&self, index: usize) -> bool {
+        let tribute = &self.tributes[index];
+        self.finale_day.is_some() && tribute.is_alive() && tribute.area.clone().unwrap_or_default() != Area::Cornucopia
+    }
+
+    /// Whether the finale has gone long enough without a death to send the mutts in everywhere.
+    fn stalled(&self) -> bool {
+        let Some(finale_day) = self.finale_day else { return false };
+        let last_death = self.tributes.iter().filter_map(|t| t.day_killed).max().unwrap_or(0);
+        self.day - last_death.max(finale_day) >= self.tuning.finale_standoff_days
+    }
+
+    /// Tops the Cornucopia up with weapons and shields for the feast, as the engine does.
+    fn restock(&mut self) {
+        if self.cornucopia.len() <= self.tuning.feast_items {
+            for _ in 0..(self.tuning.feast_items - self.cornucopia.len()) / 3 {
+                self.cornucopia.push(Item::rolled_weapon("weapon".to_string(), self.rng));
+                self.cornucopia.push(Item::rolled_shield("shield".to_string(), self.rng));
+            }
+        }
+    }
+
+    /// Whether the tribute sets out for the feast, on its day or the night it's announced.
+    /// Simulated feasts leave no backpacks, so only a weapon can be waiting.
+    fn feast_draws(&mut self, index: usize, tribute: &Tribute, day: bool) -> bool {
+        let feast_day = if day { self.day } else { self.day + 1 };
+        if feast_day != self.tuning.feast_day || tribute.area.clone().unwrap_or_default() == Area::Cornucopia {
+            return false;
+        }
+        let rivals = self.tributes.iter().filter(|t| t.is_alive() && t.district != tribute.district).count();
+        let armed = self.gear[index].iter().any(|item| item.is_weapon());
+        self.rng.gen_bool(feast_appeal(tribute, false, armed, rivals))
+    }
+
+    fn turn(&mut self, index: usize, day: bool) {
+        let mut tribute = self.tributes[index].clone();
+        tribute.status_effects(self.rng, self.tuning);
+        if tribute.health <= 0 {
+            let cause = tribute.status.to_string();
+            self.tributes[index] = tribute;
+            self.dies(index, cause);
+            return;
+        }
+        tribute.hiding_decays();
+
+        let area = tribute.area.clone().unwrap_or_default();
+        let closed = self.closed();
+        let nearby = self.tributes.iter()
+            .filter(|t| t.is_alive() && t.area.as_ref() == Some(&area))
+            .count();
+        let action = if closed.contains(&area) {
+            TributeAction::Move(None)
+        } else if (self.is_holdout(index) && self.rng.gen_bool(self.tuning.finale_pull)) || self.feast_draws(index, &tribute, day) {
+            TributeAction::Move(Some(Area::Cornucopia.to_string()))
+        } else if area == Area::Cornucopia && !self.cornucopia.is_empty() {
+            TributeAction::TakeItem
+        } else {
+            match (self.day, day) {
+                (1, true) if self.rng.gen_bool(0.5) => TributeAction::Move(None),
+                _ => instinct(&tribute, nearby),
+            }
+        };
+
+        match action {
+            TributeAction::Move(destination) => {
+                let destination = destination.and_then(|d| Area::from_str(&d));
+                let step = match destination {
+                    Some(destination) => area.path_to(&destination, &closed).and_then(|path| path.first().cloned()),
+                    None => area.neighbors().into_iter()
+                        .filter(|a| !closed.contains(a))
+                        .collect::<Vec<Area>>()
+                        .choose(self.rng)
+                        .cloned(),
+                };
+                match step {
+                    Some(next) if tribute.movement >= tribute.step_cost() => {
+                        tribute.movement -= tribute.step_cost();
+                        tribute.changes_area(next);
+                    }
+                    _ => tribute.short_rests(),
+                }
+            }
+            TributeAction::Hide => {
+                let roll = self.rng.gen_range(1..=20);
+                tribute.is_hidden = Some(true);
+                tribute.stealth = Some(tribute.concealment(0, roll));
+            }
+            TributeAction::TakeItem => {
+                let item = self.rng.gen_range(0..self.cornucopia.len());
+                self.gear[index].push(self.cornucopia.swap_remove(item));
+            }
+            TributeAction::Attack => {
+                self.tributes[index] = tribute;
+                self.fight(index);
+                return;
+            }
+            _ => tribute.long_rests(),
+        }
+        self.tributes[index] = tribute;
+    }
+
+    /// Attacks someone sharing the area, picked the way the engine picks targets.
+    fn fight(&mut self, index: usize) {
+        let attacker = self.tributes[index].clone();
+        let targets = (0..self.tributes.len())
+            .filter(|i| *i != index)
+            .filter(|i| self.tributes[*i].is_alive() && self.tributes[*i].area == attacker.area)
+            .collect::<Vec<usize>>();
+        let Ok(&target) = targets.choose_weighted(self.rng, |i| target_weight(&attacker, &self.tributes[*i])) else {
+            self.tributes[index].long_rests();
+            return;
+        };
+        if !self.tributes[target].is_visible() {
+            let roll = self.rng.gen_range(1..=50);
+            if !spotted(&attacker, &self.tributes[target], roll) {
+                return;
+            }
+        }
+
+        let weapon = self.gear[index].iter().rposition(|item| item.is_weapon());
+        let shield = self.gear[target].iter().position(|item| item.is_defensive());
+        let roll = self.rng.gen_range(1..=20);
+        let attack = attack_total(&attacker, weapon.map(|i| &self.gear[index][i]), roll);
+        let roll = self.rng.gen_range(1..=20);
+        let defense = defense_total(&self.tributes[target], shield.map(|i| &self.gear[target][i]), roll);
+        if let Some(weapon) = weapon {
+            if self.gear[index][weapon].wears() {
+                self.gear[index].remove(weapon);
+            }
+        }
+        if let Some(shield) = shield {
+            if self.gear[target][shield].wears() {
+                self.gear[target].remove(shield);
+            }
+        }
+
+        let result = contest_result(attack, defense, self.tuning.decisive_margin);
+        let damage = contest_damage(&result, &attacker, &self.tributes[target]);
+        let (winner, loser) = match result {
+            AttackResult::AttackerWins | AttackResult::AttackerWinsDecisively => (index, target),
+            AttackResult::DefenderWins | AttackResult::DefenderWinsDecisively => (target, index),
+            AttackResult::Miss => return,
+        };
+        self.tributes[loser].takes_physical_damage(damage);
+        if self.tributes[loser].health <= 0 {
+            self.dies(loser, "attack".to_string());
+            let winner = &mut self.tributes[winner];
+            winner.kills = Some(winner.kills.unwrap_or(0) + 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_report() {
+        let tuning = Tuning::default();
+        assert_eq!(simulate(5, 42, &tuning), simulate(5, 42, &tuning));
+        assert_ne!(simulate(5, 42, &tuning), simulate(5, 43, &tuning));
+    }
+
+    #[test]
+    fn every_tribute_is_counted() {
+        let report = simulate(10, 7, &Tuning::default());
+        assert_eq!(report.districts.len(), 12);
+        assert!(report.districts.values().all(|rate| rate.entrants == 20));
+        assert_eq!(report.kills.values().sum::<u32>(), 240);
+        let wins: u32 = report.districts.values().map(|rate| rate.wins).sum();
+        assert_eq!(wins, report.games - report.no_winner);
+        let deaths: u32 = report.causes_of_death.values().sum();
+        assert!(deaths + wins <= 240);
+        assert!(report.average_days >= 1.0);
+    }
+
+    #[test]
+    fn tuning_changes_the_games() {
+        let calm = Tuning {
+            day_event_frequency: 0.0,
+            night_event_frequency: 0.0,
+            area_closing_threshold: 0,
+            finale_tributes: 0,
+            ..Tuning::default()
+        };
+        let report = simulate(10, 7, &calm);
+        assert!(report.causes_of_death.keys().all(|cause| !["wildfire", "flood", "earthquake", "avalanche", "blizzard", "landslide", "heatwave"].contains(&cause.as_str())));
+        assert_ne!(report.causes_of_death, simulate(10, 7, &Tuning::default()).causes_of_death);
+    }
+}
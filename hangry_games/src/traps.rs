@@ -0,0 +1,136 @@
+use crate::areas::Area;
+use crate::items::Item;
+use crate::models::trap::{NewTrap, Trap as TrapModel};
+use crate::tributes::actors::Tribute;
+use serde::{Deserialize, Serialize};
+
+/// A trap a tribute has set and left behind for whoever comes through next.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Trap {
+    pub id: Option<i32>,
+    pub game_id: i32,
+    pub area: Area,
+    /// Whoever set it, and gets the credit for anyone it kills.
+    pub owner_id: i32,
+    pub name: String,
+    /// How much it hurts whoever springs it.
+    pub strength: i32,
+    /// What it takes to notice it before it's too late.
+    pub difficulty: i32,
+}
+
+impl Trap {
+    /// Sets `item` where the tribute is, hidden as well as they can manage with a d20 `roll`.
+    pub fn set(tribute: &Tribute, item: &Item, roll: i32) -> Trap {
+        let mut trap = Trap {
+            id: None,
+            game_id: tribute.game_id.unwrap(),
+            area: tribute.area.clone().unwrap(),
+            owner_id: tribute.id.unwrap(),
+            name: item.name.clone(),
+            strength: item.effect,
+            difficulty: concealment(tribute, roll),
+        };
+        let model = TrapModel::create(NewTrap::from(&trap));
+        trap.id = Some(model.id);
+        trap
+    }
+
+    /// Every trap still set in `area`.
+    pub fn in_area(game_id: i32, area: &Area) -> Vec<Trap> {
+        TrapModel::get_in_area(game_id, area.id()).into_iter().map(Trap::from).collect()
+    }
+
+    /// Takes the trap out of the arena, whether it went off or someone dealt with it.
+    pub fn remove(&self) {
+        TrapModel::from(self).delete();
+    }
+
+    /// Whether a d50 `roll` on top of the tribute's wits notices it in time.
+    pub fn spotted_by(&self, tribute: &Tribute, roll: i32) -> bool {
+        tribute.intelligence.unwrap_or(0) / 2 + roll >= self.difficulty
+    }
+
+    /// Whether, once they've spotted it, a d50 `roll` on top of a steady hand gets it out
+    /// in one piece for them to keep, rather than just breaking it.
+    pub fn stolen_by(&self, tribute: &Tribute, roll: i32) -> bool {
+        tribute.dexterity.unwrap_or(0) / 2 + roll >= self.difficulty
+    }
+}
+
+/// How well a trap is hidden: stealth counts for the most, wits for some, and a d20 `roll` for the rest.
+pub(crate) fn concealment(tribute: &Tribute, roll: i32) -> i32 {
+    tribute.stealth.unwrap_or(0) / 2 + tribute.intelligence.unwrap_or(0) / 4 + roll
+}
+
+impl From<TrapModel> for Trap {
+    fn from(trap: TrapModel) -> Self {
+        Trap {
+            id: Some(trap.id),
+            game_id: trap.game_id,
+            area: Area::get_by_id(trap.area_id).unwrap(),
+            owner_id: trap.owner_id,
+            name: trap.name,
+            strength: trap.strength,
+            difficulty: trap.difficulty,
+        }
+    }
+}
+
+impl From<&Trap> for NewTrap {
+    fn from(trap: &Trap) -> Self {
+        NewTrap {
+            game_id: trap.game_id,
+            area_id: trap.area.id(),
+            owner_id: trap.owner_id,
+            name: trap.name.clone(),
+            strength: trap.strength,
+            difficulty: trap.difficulty,
+        }
+    }
+}
+
+impl From<&Trap> for TrapModel {
+    fn from(trap: &Trap) -> Self {
+        TrapModel {
+            id: trap.id.unwrap(),
+            game_id: trap.game_id,
+            area_id: trap.area.id(),
+            owner_id: trap.owner_id,
+            name: trap.name.clone(),
+            strength: trap.strength,
+            difficulty: trap.difficulty,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sharp_eyes_and_steady_hands_beat_traps() {
+        let mut setter = Tribute::new("Foxface".to_string(), None, None);
+        setter.stealth = Some(80);
+        setter.intelligence = Some(40);
+        let trap = Trap {
+            id: None,
+            game_id: 0,
+            area: Area::Northeast,
+            owner_id: 0,
+            name: "fine snare".to_string(),
+            strength: 20,
+            difficulty: concealment(&setter, 10),
+        };
+        assert_eq!(trap.difficulty, 60);
+
+        let mut tribute = Tribute::new("Katniss".to_string(), None, None);
+        tribute.intelligence = Some(40);
+        tribute.dexterity = Some(90);
+        assert!(!trap.spotted_by(&tribute, 39));
+        assert!(trap.spotted_by(&tribute, 40));
+        assert!(trap.stolen_by(&tribute, 15));
+        tribute.dexterity = Some(20);
+        assert!(!trap.stolen_by(&tribute, 15));
+    }
+}
@@ -15,6 +15,12 @@ pub enum TributeAction {
     Attack,
     Hide,
     TakeItem,
+    Forage,
+    Hunt,
+    Fish,
+    FindWater,
+    Craft,
+    SetTrap,
 }
 
 impl TributeAction {
@@ -27,6 +33,12 @@ impl TributeAction {
             TributeAction::Attack => "Attack",
             TributeAction::Hide => "Hide",
             TributeAction::TakeItem => "Take Item",
+            TributeAction::Forage => "Forage",
+            TributeAction::Hunt => "Hunt",
+            TributeAction::Fish => "Fish",
+            TributeAction::FindWater => "Find Water",
+            TributeAction::Craft => "Craft",
+            TributeAction::SetTrap => "Set Trap",
         }
     }
 }
@@ -50,6 +62,12 @@ impl FromStr for TributeAction {
             "attack" => Ok(TributeAction::Attack),
             "hide" => Ok(TributeAction::Hide),
             "take item" => Ok(TributeAction::TakeItem),
+            "forage" => Ok(TributeAction::Forage),
+            "hunt" => Ok(TributeAction::Hunt),
+            "fish" => Ok(TributeAction::Fish),
+            "find water" => Ok(TributeAction::FindWater),
+            "craft" => Ok(TributeAction::Craft),
+            "set trap" => Ok(TributeAction::SetTrap),
             _ => Err(()),
         }
     }
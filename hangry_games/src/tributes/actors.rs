@@ -1,18 +1,29 @@
 use super::actions::{AttackOutcome, AttackResult, TributeAction};
-use super::brains::TributeBrain;
+use super::brains::{keep_journey, TravelGoal, TributeBrain};
+use super::breakdowns::Breakdown;
+use super::memories::{Memory, MemoryKind};
+use super::pronouns::Pronouns;
 use super::statuses::TributeStatus;
+use super::contagion::{incubation_period, is_contagious};
+use super::survival::{gather, Haul};
 use crate::areas::Area;
 use crate::events::TributeEvent;
 use crate::models;
 use crate::models::tribute::UpdateTribute;
 use rand::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
-use crate::items::{Attribute, Item};
+use crate::items::{Attribute, Item, ItemType};
+use crate::packs::Pack;
+use crate::traps::Trap;
+use crate::crafting::pick_recipe;
 use crate::messages::GameMessage;
+use crate::tuning::Tuning;
 use crate::models::{create_full_log, get_all_living_tributes, get_area, get_area_by_id, get_game_by_id,
-                    get_tribute_by_id, update_item, update_tribute, Action, Tribute as TributeModel};
+                    get_tribute_by_id, update_item, update_tribute, Action, SpotCheck, Tribute as TributeModel,
+                    TributeMemory as TributeMemoryModel};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Tribute {
     pub id: Option<i32>,
     pub game_id: Option<i32>,
@@ -21,6 +32,7 @@ pub struct Tribute {
     pub sanity: i32,
     pub movement: i32,
     pub district: i32,
+    #[serde(skip)]
     pub brain: TributeBrain,
     pub area: Option<Area>,
     pub day_killed: Option<i32>,
@@ -43,6 +55,18 @@ pub struct Tribute {
     pub status: TributeStatus,
     pub avatar: Option<String>,
     pub real_name: Option<String>,
+    pub destination: Option<Area>,
+    pub stealth: Option<i32>,
+    pub breakdown: Option<Breakdown>,
+    pub pronouns: Option<Pronouns>,
+    /// The gamemakers' 1-12 verdict from training, once the pre-game has run.
+    pub training_score: Option<i32>,
+    /// How well the interview went over, 1-100.
+    pub likability: Option<i32>,
+    /// A contagious illness they've caught but aren't showing yet.
+    pub incubating: Option<TributeStatus>,
+    /// Phases until it shows.
+    pub incubation: Option<i32>,
 }
 
 impl Tribute {
@@ -81,6 +105,14 @@ impl Tribute {
             status: TributeStatus::Healthy,
             avatar,
             real_name: None,
+            destination: None,
+            stealth: Some(0),
+            breakdown: None,
+            pronouns: None,
+            training_score: None,
+            likability: None,
+            incubating: None,
+            incubation: None,
         }
     }
 
@@ -123,6 +155,11 @@ impl Tribute {
         self.is_hidden = Some(false);
     }
 
+    /// Movement it costs to walk into a neighboring area. Faster tributes tire less.
+    pub fn step_cost(&self) -> i32 {
+        std::cmp::max(10, 50 - self.speed.unwrap_or(0) / 2)
+    }
+
     /// Restores movement.
     pub fn short_rests(&mut self) {
         self.movement = 100;
@@ -160,14 +197,168 @@ impl Tribute {
         self.is_hidden = Some(false);
     }
 
-    /// Hides the tribute from view.
+    /// Hides the tribute from view, as well as their skills, the terrain and any camouflage allow.
     pub fn hides(&mut self) {
+        let camouflage = self.items().iter()
+            .filter(|i| i.is_camouflage())
+            .map(|i| i.effect)
+            .sum();
         self.is_hidden = Some(true);
+        self.stealth = Some(self.hiding_quality(camouflage));
     }
 
     /// Reveals the tribute to view.
     pub fn reveals(&mut self) {
         self.is_hidden = Some(false);
+        self.stealth = Some(0);
+    }
+
+    /// How well the tribute can hide where they are. Nimble, clever tributes hide best.
+    pub fn hiding_quality(&self, camouflage: i32) -> i32 {
+        self.concealment(camouflage, thread_rng().gen_range(1..=20))
+    }
+
+    /// How well hidden the tribute ends up with `camouflage` and a d20 `roll`.
+    pub(crate) fn concealment(&self, camouflage: i32, roll: i32) -> i32 {
+        let skill = (self.dexterity.unwrap_or(0) + self.intelligence.unwrap_or(0)) / 4;
+        let cover = self.area.as_ref().map(|a| a.cover()).unwrap_or(0);
+        std::cmp::min(100, skill + cover + camouflage + roll)
+    }
+
+    /// Every phase spent hidden wears the tribute's cover down.
+    /// Returns true if they've been left exposed.
+    pub fn hiding_decays(&mut self) -> bool {
+        if !self.is_hidden.unwrap_or(false) {
+            return false;
+        }
+        self.stealth = Some(std::cmp::max(0, self.stealth.unwrap_or(0) - 10));
+        if self.stealth == Some(0) {
+            self.reveals();
+            return true;
+        }
+        false
+    }
+
+    /// Commits something to memory, both in the brain and in the database.
+    pub fn remembers(&mut self, kind: MemoryKind, subject_id: i32, area: Option<Area>) {
+        let memory = TributeMemoryModel::create(
+            self.id.unwrap(),
+            self.game_id.unwrap(),
+            kind.to_string(),
+            subject_id,
+            area.map(|a| a.id())
+        );
+        self.brain.remember(Memory::from(memory));
+    }
+
+    /// Remembers the tributes and unclaimed items the tribute can see in their area.
+    pub fn looks_around(&mut self) {
+        let area = self.area.clone().unwrap();
+        let game_id = self.game_id.unwrap();
+
+        let others = area.tributes(game_id).into_iter()
+            .filter(|t| t.is_alive())
+            .filter(|t| t.id != self.id)
+            .collect::<Vec<_>>();
+        for other in others {
+            let other_id = other.id.unwrap();
+            if self.brain.last_seen(other_id) != Some(area.clone()) && self.spots(&other) {
+                self.remembers(MemoryKind::SawTribute, other_id, Some(area.clone()));
+            }
+        }
+
+        for item in area.available_items(game_id) {
+            let item_id = item.id.unwrap();
+            if !self.brain.remembers_item(item_id, &area) {
+                self.remembers(MemoryKind::SawItem, item_id, Some(area.clone()));
+            }
+        }
+    }
+
+    /// Low sanity breaks a tribute down, and they stay broken until they've recovered enough of it.
+    pub fn copes(&mut self) {
+        let message = match &self.breakdown {
+            None if self.sanity <= 25 => {
+                let breakdown = Breakdown::random();
+                self.breakdown = Some(breakdown.clone());
+                GameMessage::TributeBreakdown(self.clone(), breakdown)
+            },
+            Some(_) if self.sanity >= 50 => {
+                self.breakdown = None;
+                GameMessage::TributeRecovers(self.clone())
+            },
+            _ => return,
+        };
+        create_full_log(
+            self.game_id.unwrap(),
+            message,
+            Some(self.area.clone().unwrap().id()),
+            Some(self.id.unwrap()),
+            None,
+            None
+        );
+    }
+
+    /// Having a district mate close by steadies the tribute a little.
+    pub fn takes_comfort(&mut self) {
+        if self.sanity >= 100 {
+            return;
+        }
+        let game = get_game_by_id(self.game_id.unwrap()).unwrap();
+        let ally = get_all_living_tributes(&game).into_iter()
+            .filter(|t| t.district == self.district)
+            .filter(|t| Some(t.id) != self.id)
+            .map(Tribute::from)
+            .find(|t| t.area == self.area);
+
+        if let Some(ally) = ally {
+            self.heals_mental_damage(5);
+            create_full_log(
+                self.game_id.unwrap(),
+                GameMessage::TributeComforted(self.clone(), ally.clone()),
+                Some(self.area.clone().unwrap().id()),
+                Some(self.id.unwrap()),
+                Some("Tribute".to_string()),
+                Some(ally.id.unwrap())
+            );
+        }
+    }
+
+    /// A private session in front of the gamemakers, scored 1 to 12.
+    pub fn trains(&mut self) {
+        let score = training_score(self, thread_rng().gen_range(-15..=15));
+        self.training_score = Some(score);
+        create_full_log(
+            self.game_id.unwrap(),
+            GameMessage::TributeTrainingScore(self.clone(), score),
+            None,
+            Some(self.id.unwrap()),
+            None,
+            None
+        );
+    }
+
+    /// The pre-game interview. Persuasive tributes win the audience over,
+    /// and a memorable training score gives them something to talk about.
+    pub fn gives_interview(&mut self) {
+        let likability = interview_likability(self, thread_rng().gen_range(-20..=20));
+        self.likability = Some(likability);
+        create_full_log(
+            self.game_id.unwrap(),
+            GameMessage::TributeInterview(self.clone(), likability),
+            None,
+            Some(self.id.unwrap()),
+            None,
+            None
+        );
+    }
+
+    /// How eager sponsors are to back the tribute, as a multiplier on their district's odds.
+    /// Likability counts double; tributes who skipped the pre-game are average.
+    pub fn sponsor_appeal(&self) -> f64 {
+        let training = self.training_score.unwrap_or(6) as f64 / 6.0;
+        let likability = self.likability.unwrap_or(50) as f64 / 50.0;
+        (training + likability * 2.0) / 3.0
     }
 
     /// Tribute is lonely/homesick/etc., loses some sanity.
@@ -186,7 +377,7 @@ impl Tribute {
         if terror.round() > 1.0 {
             create_full_log(
                 self.game_id.unwrap(),
-                GameMessage::TributeSuffer(self.clone()).to_string(),
+                GameMessage::TributeSuffer(self.clone()),
                 Some(self.area.clone().unwrap().id()),
                 Some(self.id.unwrap()),
                 None,
@@ -200,23 +391,27 @@ impl Tribute {
         if self == target {
             create_full_log(
                 self.game_id.unwrap(),
-                GameMessage::TributeSelfHarm(self.clone()).to_string(),
+                GameMessage::TributeSelfHarm(self.clone()),
                 Some(self.area.clone().unwrap().id()),
                 Some(self.id.unwrap()),
                 Some("attack".to_string()),
                 Some(self.id.unwrap())
             );
+        } else if self.id != target.id {
+            target.remembers(MemoryKind::AttackedBy, self.id.unwrap(), self.area.clone());
         }
 
-        match attack_contest(self.clone(), target.clone()) {
+        let result = attack_contest(self.clone(), target.clone());
+        let damage = contest_damage(&result, self, target);
+        match result {
             AttackResult::AttackerWins => {
-                target.takes_physical_damage(self.strength.unwrap());
+                target.takes_physical_damage(damage);
                 target.defeats = Some(target.defeats.unwrap_or(0) + 1);
                 self.wins = Some(self.wins.unwrap_or(0) + 1);
 
                 create_full_log(
                     self.game_id.unwrap(),
-                    GameMessage::TributeAttackWin(self.clone(), target.clone()).to_string(),
+                    GameMessage::TributeAttackWin(self.clone(), target.clone()),
                     Some(self.area.clone().unwrap().id()),
                     Some(self.id.unwrap()),
                     Some("attack".to_string()),
@@ -226,7 +421,7 @@ impl Tribute {
                 if target.health > 0 {
                     create_full_log(
                         self.game_id.unwrap(),
-                        GameMessage::TributeAttackWound(self.clone(), target.clone()).to_string(),
+                        GameMessage::TributeAttackWound(self.clone(), target.clone()),
                         Some(self.area.clone().unwrap().id()),
                         Some(self.id.unwrap()),
                         Some("attack".to_string()),
@@ -236,13 +431,13 @@ impl Tribute {
                 }
             }
             AttackResult::AttackerWinsDecisively => {
-                target.takes_physical_damage(self.strength.unwrap() * 2);
+                target.takes_physical_damage(damage);
                 target.defeats = Some(target.defeats.unwrap_or(0) + 1);
                 self.wins = Some(self.wins.unwrap_or(0) + 1);
 
                 create_full_log(
                     self.game_id.unwrap(),
-                    GameMessage::TributeAttackWinExtra(self.clone(), target.clone()).to_string(),
+                    GameMessage::TributeAttackWinExtra(self.clone(), target.clone()),
                     Some(self.area.clone().unwrap().id()),
                     Some(self.id.unwrap()),
                     Some("attack".to_string()),
@@ -252,7 +447,7 @@ impl Tribute {
                 if target.health > 0 {
                     create_full_log(
                         self.game_id.unwrap(),
-                        GameMessage::TributeAttackWound(self.clone(), target.clone()).to_string(),
+                        GameMessage::TributeAttackWound(self.clone(), target.clone()),
                         Some(self.area.clone().unwrap().id()),
                         Some(self.id.unwrap()),
                         Some("attack".to_string()),
@@ -262,13 +457,13 @@ impl Tribute {
                 }
             }
             AttackResult::DefenderWins => {
-                self.takes_physical_damage(target.strength.unwrap());
+                self.takes_physical_damage(damage);
                 self.defeats = Some(self.defeats.unwrap() + 1);
                 target.wins = Some(target.wins.unwrap() + 1);
 
                 create_full_log(
                     self.game_id.unwrap(),
-                    GameMessage::TributeAttackLose(self.clone(), target.clone()).to_string(),
+                    GameMessage::TributeAttackLose(self.clone(), target.clone()),
                     Some(self.area.clone().unwrap().id()),
                     Some(self.id.unwrap()),
                     Some("attack".to_string()),
@@ -278,7 +473,7 @@ impl Tribute {
                 if self.health > 0 {
                     create_full_log(
                         self.game_id.unwrap(),
-                        GameMessage::TributeAttackWound(target.clone(), self.clone()).to_string(),
+                        GameMessage::TributeAttackWound(target.clone(), self.clone()),
                         Some(self.area.clone().unwrap().id()),
                         Some(target.id.unwrap()),
                         Some("attack".to_string()),
@@ -288,13 +483,13 @@ impl Tribute {
                 }
             }
             AttackResult::DefenderWinsDecisively => {
-                self.takes_physical_damage(target.strength.unwrap() * 2);
+                self.takes_physical_damage(damage);
                 self.defeats = Some(self.defeats.unwrap() + 1);
                 target.wins = Some(target.wins.unwrap() + 1);
 
                 create_full_log(
                     self.game_id.unwrap(),
-                    GameMessage::TributeAttackLoseExtra(self.clone(), target.clone()).to_string(),
+                    GameMessage::TributeAttackLoseExtra(self.clone(), target.clone()),
                     Some(self.area.clone().unwrap().id()),
                     Some(self.id.unwrap()),
                     Some("attack".to_string()),
@@ -304,7 +499,7 @@ impl Tribute {
                 if self.health > 0 {
                     create_full_log(
                         self.game_id.unwrap(),
-                        GameMessage::TributeAttackWound(target.clone(), self.clone()).to_string(),
+                        GameMessage::TributeAttackWound(target.clone(), self.clone()),
                         Some(self.area.clone().unwrap().id()),
                         Some(target.id.unwrap()),
                         Some("attack".to_string()),
@@ -316,7 +511,7 @@ impl Tribute {
             AttackResult::Miss => {
                 create_full_log(
                     self.game_id.unwrap(),
-                    GameMessage::TributeAttackMiss(self.clone(), target.clone()).to_string(),
+                    GameMessage::TributeAttackMiss(self.clone(), target.clone()),
                     Some(self.area.clone().unwrap().id()),
                     Some(self.id.unwrap()),
                     Some("attack".to_string()),
@@ -333,7 +528,7 @@ impl Tribute {
             // Attacker was killed by target
             create_full_log(
                 self.game_id.unwrap(),
-                GameMessage::TributeAttackDied(self.clone(), target.clone()).to_string(),
+                GameMessage::TributeAttackDied(self.clone(), target.clone()),
                 Some(self.area.clone().unwrap().id()),
                 Some(target.id.unwrap()),
                 Some("attack".to_string()),
@@ -342,12 +537,15 @@ impl Tribute {
             self.killed_by = Some(target.name.clone());
             self.status = TributeStatus::RecentlyDead;
             self.dies();
+            remember_killer(target, self);
+            target.kills = Some(target.kills.unwrap_or(0) + 1);
+            apply_violence_stress(target);
             AttackOutcome::Kill(target.clone(), self.clone())
         } else if target.health <= 0 {
             // Target was killed by attacker
             create_full_log(
                 self.game_id.unwrap(),
-                GameMessage::TributeAttackSuccessKill(self.clone(), target.clone()).to_string(),
+                GameMessage::TributeAttackSuccessKill(self.clone(), target.clone()),
                 Some(self.area.clone().unwrap().id()),
                 Some(self.id.unwrap()),
                 Some("attack".to_string()),
@@ -356,25 +554,52 @@ impl Tribute {
             target.killed_by = Some(self.name.clone());
             target.status = TributeStatus::RecentlyDead;
             target.dies();
+            remember_killer(self, target);
+            if self.id != target.id {
+                self.kills = Some(self.kills.unwrap_or(0) + 1);
+                apply_violence_stress(self);
+            }
             AttackOutcome::Kill(self.clone(), target.clone())
         } else {
             AttackOutcome::Miss(self.clone(), target.clone())
         }
-
-        // apply_violence_stress(self);
     }
 
+    /// Whether the tribute is out in the open. Hidden tributes have to be spotted.
     pub fn is_visible(&self) -> bool {
-        let is_hidden = self.is_hidden.unwrap_or(false);
-        if is_hidden {
-            let mut rng = thread_rng();
-            !rng.gen_bool(self.intelligence.unwrap() as f64 / 100.0)
-        } else {
-            true
+        !self.is_hidden.unwrap_or(false)
+    }
+
+    /// Whether this tribute can see `other` this phase.
+    /// The first look at a hidden tribute is a contest, and its result holds until the next phase.
+    /// Only finding them is logged; staying hidden is news once someone tries to attack.
+    pub fn spots(&self, other: &Tribute) -> bool {
+        if other.is_visible() || self.id == other.id {
+            return true;
+        }
+
+        let game_id = self.game_id.unwrap();
+        let (spotter_id, hider_id) = (self.id.unwrap(), other.id.unwrap());
+        if let Some(spot_check) = SpotCheck::get(game_id, spotter_id, hider_id) {
+            return spot_check.spotted;
         }
+
+        let spotted = spot_contest(self, other);
+        SpotCheck::create(game_id, spotter_id, hider_id, spotted);
+        if spotted {
+            create_full_log(
+                game_id,
+                GameMessage::TributeSpotted(self.clone(), other.clone()),
+                Some(self.area.clone().unwrap().id()),
+                Some(spotter_id),
+                Some("Tribute".to_string()),
+                Some(hider_id)
+            );
+        }
+        spotted
     }
 
-    pub fn travels(&self, closed_areas: Vec<Area>, suggested_area: Option<String>) -> TravelResult {
+    pub fn travels(&mut self, closed_areas: Vec<Area>, suggested_area: Option<String>) -> TravelResult {
         let mut rng = thread_rng();
         let area = self.clone().area.unwrap();
 
@@ -395,299 +620,253 @@ impl Tribute {
         if suggested_area.is_some() && suggested_area.clone().unwrap() == area {
             create_full_log(
                 self.game_id.unwrap(),
-                GameMessage::TributeTravelAlreadyThere(self.clone(), suggested_area.clone().unwrap()).to_string(),
+                GameMessage::TributeTravelAlreadyThere(self.clone(), suggested_area.clone().unwrap()),
                 Some(area.id()),
                 Some(self.id.unwrap()),
                 None,
                 None
             );
+            self.destination = None;
             return TravelResult::Failure;
         }
 
-        let handle_suggested_area = || -> TravelResult {
-            if suggested_area.is_some() {
+        let goal = match self.movement {
+            // No movement left, can't move
+            0 => None,
+            // Low movement, can only move to suggested area
+            1..=10 => suggested_area.map(TravelGoal::Destination),
+            // High movement, can keep on with the current journey or head anywhere worth going
+            _ => suggested_area.map(TravelGoal::Destination)
+                .or_else(|| {
+                    let journey = self.destination.clone()
+                        .filter(|d| *d != area && !closed_areas.contains(d))
+                        .map(TravelGoal::Destination);
+                    keep_journey(journey, self.brain.choose_travel_goal(self, &closed_areas))
+                })
+                .or_else(|| {
+                    // Otherwise wander, steering clear of anywhere an enemy was last seen
+                    let open_areas = area.neighbors().into_iter()
+                        .filter(|a| *a != area && !closed_areas.contains(a))
+                        .collect::<Vec<Area>>();
+                    let threat_areas = self.brain.threat_areas();
+                    let safe_areas = open_areas.iter()
+                        .filter(|a| !threat_areas.contains(a))
+                        .cloned()
+                        .collect::<Vec<Area>>();
+
+                    safe_areas.choose(&mut rng)
+                        .or_else(|| open_areas.choose(&mut rng))
+                        .cloned()
+                        .map(TravelGoal::Destination)
+                }),
+        };
+
+        let Some(goal) = goal else {
+            if self.movement > 10 {
                 create_full_log(
                     self.game_id.unwrap(),
-                    GameMessage::TributeTravel(self.clone(), area.clone(), suggested_area.clone().unwrap()).to_string(),
+                    GameMessage::TributeTravelStay(self.clone(), area.clone()),
                     Some(area.id()),
                     Some(self.id.unwrap()),
                     Some("Move".to_string()),
-                    Some(suggested_area.clone().unwrap().id())
+                    Some(area.id())
                 );
-                return TravelResult::Success(suggested_area.unwrap());
+                return TravelResult::Success(area.clone());
             }
-            TravelResult::Failure
+            create_full_log(
+                self.game_id.unwrap(),
+                GameMessage::TributeTravelTooTired(self.clone(), area.clone()),
+                Some(area.id()),
+                Some(self.id.unwrap()),
+                None,
+                None
+            );
+            return TravelResult::Failure;
         };
 
-        match self.movement {
-            // No movement left, can't move
-            0 => {
-                create_full_log(
-                    self.game_id.unwrap(),
-                    GameMessage::TributeTravelTooTired(self.clone(), area.clone()).to_string(),
-                    Some(area.id()),
-                    Some(self.id.unwrap()),
-                    None,
-                    None
-                );
-                TravelResult::Failure
-            },
-            // Low movement, can only move to suggested area
-            1..=10 => {
-                match handle_suggested_area() {
-                    TravelResult::Success(area) => TravelResult::Success(area),
-                    TravelResult::Failure => {
-                        create_full_log(
-                            self.game_id.unwrap(),
-                            GameMessage::TributeTravelTooTired(self.clone(), area.clone()).to_string(),
-                            Some(area.id()),
-                            Some(self.id.unwrap()),
-                            None,
-                            None
-                        );
-                        TravelResult::Failure
-                    }
-                }
-            },
-            // High movement, can move to any open neighbor or the suggested area
-            _ => {
-                match handle_suggested_area() {
-                    TravelResult::Success(area) => return TravelResult::Success(area),
-                    TravelResult::Failure => ()
-                }
-                let neighbors = area.neighbors();
-                for area in &neighbors {
-                    if area.tributes(self.game_id.unwrap()).iter()
-                        .filter(|t| t.district == self.district)
-                        .count() > 0 {
-                            create_full_log(
-                                self.game_id.unwrap(),
-                                GameMessage::TributeTravelFollow(self.clone(), area.clone()).to_string(),
-                                Some(self.area.clone().unwrap().id()),
-                                Some(self.id.unwrap()),
-                                Some("Move".to_string()),
-                                Some(area.id())
-                            );
-                            return TravelResult::Success(area.clone());
-                    }
-                }
-                let mut count = 0;
-                let new_area = loop {
-                    let new_area = neighbors.choose(&mut rng).unwrap();
-                    if new_area == &area || closed_areas.contains(new_area) {
-                        count += 1;
-
-                        if count == 10 {
-                            create_full_log(
-                                self.game_id.unwrap(),
-                                GameMessage::TributeTravelStay(self.clone(), area.clone()).to_string(),
-                                Some(area.id()),
-                                Some(self.id.unwrap()),
-                                Some("Move".to_string()),
-                                Some(area.id())
-                            );
-                            return TravelResult::Success(area.clone());
-                        }
+        let destination = goal.area();
+        let Some(path) = area.path_to(&destination, &closed_areas) else {
+            self.destination = None;
+            create_full_log(
+                self.game_id.unwrap(),
+                GameMessage::TributeTravelStay(self.clone(), area.clone()),
+                Some(area.id()),
+                Some(self.id.unwrap()),
+                Some("Move".to_string()),
+                Some(area.id())
+            );
+            return TravelResult::Success(area.clone());
+        };
 
-                        continue;
-                    }
-                    break new_area.clone();
-                };
+        // Announce why they're setting out, unless they're already on their way
+        if self.destination.as_ref() != Some(&destination) {
+            let message = match &goal {
+                TravelGoal::Safety(area) => Some(GameMessage::TributeTravelFlee(self.clone(), area.clone())),
+                TravelGoal::Hunt(target_id, area) => {
+                    let target = Tribute::from(get_tribute_by_id(*target_id));
+                    Some(GameMessage::TributeTravelHunt(self.clone(), target, area.clone()))
+                },
+                TravelGoal::Ally(area) => Some(GameMessage::TributeTravelFollow(self.clone(), area.clone())),
+                TravelGoal::Supplies(area) => Some(GameMessage::TributeTravelScavenge(self.clone(), area.clone())),
+                TravelGoal::Destination(_) => None,
+            };
+            if let Some(message) = message {
                 create_full_log(
                     self.game_id.unwrap(),
-                    GameMessage::TributeTravel(self.clone(), area.clone(), new_area.clone()).to_string(),
+                    message,
                     Some(area.id()),
                     Some(self.id.unwrap()),
                     Some("Move".to_string()),
-                    Some(new_area.id())
+                    Some(destination.id())
                 );
-                TravelResult::Success(new_area)
             }
         }
+
+        // Walk as far along the path as their legs allow this phase
+        let mut current = area.clone();
+        for next in path {
+            if current != area && self.movement < self.step_cost() {
+                break;
+            }
+            self.movement = std::cmp::max(0, self.movement - self.step_cost());
+            create_full_log(
+                self.game_id.unwrap(),
+                GameMessage::TributeTravel(self.clone(), current.clone(), next.clone()),
+                Some(current.id()),
+                Some(self.id.unwrap()),
+                Some("Move".to_string()),
+                Some(next.id())
+            );
+            current = next;
+        }
+
+        self.destination = if current == destination { None } else { Some(destination) };
+        TravelResult::Success(current)
     }
 
-    pub fn process_status(&mut self) {
+    pub fn process_status(&mut self, tuning: &Tuning) {
+        if let Some(message) = self.status_effects(&mut thread_rng(), tuning) {
+            create_full_log(
+                self.game_id.unwrap(),
+                message,
+                Some(self.area.clone().unwrap().id()),
+                Some(self.id.unwrap()),
+                None,
+                None
+            );
+        }
+
+        if self.health <= 0 {
+            create_full_log(
+                self.game_id.unwrap(),
+                GameMessage::TributeDiesFromStatus(self.clone(), self.status.clone()),
+                Some(self.area.clone().unwrap().id()),
+                Some(self.id.unwrap()),
+                None,
+                None
+            );
+            self.killed_by = Some(self.status.to_string());
+            self.status = TributeStatus::RecentlyDead;
+        }
+    }
+
+    /// Catches a contagious `disease`, to show once it's incubated. Anyone already ill with
+    /// something contagious, or incubating it, can't catch anything else.
+    pub fn catches(&mut self, disease: TributeStatus, rng: &mut impl Rng) -> bool {
+        if !self.is_alive() || is_contagious(&self.status) || self.incubating.is_some() {
+            return false;
+        }
+        self.incubating = Some(disease);
+        self.incubation = Some(incubation_period(rng));
+        true
+    }
+
+    /// Counts down whatever they're incubating, and brings it on once it's had its time.
+    fn incubates(&mut self) -> Option<GameMessage> {
+        let disease = self.incubating.clone()?;
+        let left = self.incubation.unwrap_or(0) - 1;
+        if left > 0 {
+            self.incubation = Some(left);
+            return None;
+        }
+        self.incubating = None;
+        self.incubation = None;
+        self.status = disease.clone();
+        Some(GameMessage::TributeFallsIll(self.clone(), disease))
+    }
+
+    /// Applies the phase's toll from the tribute's status, returning what happened to them.
+    pub fn status_effects(&mut self, rng: &mut impl Rng, tuning: &Tuning) -> Option<GameMessage> {
         let status = self.status.clone();
         match status {
             TributeStatus::Wounded => {
                 self.takes_physical_damage(1);
-                create_full_log(
-                    self.game_id.unwrap(),
-                    GameMessage::TributeBleeds(self.clone()).to_string(),
-                    Some(self.area.clone().unwrap().id()),
-                    Some(self.id.unwrap()),
-                    None,
-                    None
-                );
+                Some(GameMessage::TributeBleeds(self.clone()))
             },
             TributeStatus::Sick => {
                 self.strength = Some(std::cmp::max(1, self.strength.unwrap() - 1));
                 self.speed = Some(std::cmp::max(1, self.speed.unwrap() - 1));
-                create_full_log(
-                    self.game_id.unwrap(),
-                    GameMessage::TributeSick(self.clone()).to_string(),
-                    Some(self.area.clone().unwrap().id()),
-                    Some(self.id.unwrap()),
-                    None,
-                    None
-                );
+                Some(GameMessage::TributeSick(self.clone()))
             },
             TributeStatus::Electrocuted => {
                 self.takes_physical_damage(20);
-                create_full_log(
-                    self.game_id.unwrap(),
-                    GameMessage::TributeElectrocuted(self.clone()).to_string(),
-                    Some(self.area.clone().unwrap().id()),
-                    Some(self.id.unwrap()),
-                    None,
-                    None
-                );
+                Some(GameMessage::TributeElectrocuted(self.clone()))
             },
             TributeStatus::Frozen => {
                 self.speed = Some(std::cmp::max(1, self.speed.unwrap() - 1));
-                create_full_log(
-                    self.game_id.unwrap(),
-                    GameMessage::TributeFrozen(self.clone()).to_string(),
-                    Some(self.area.clone().unwrap().id()),
-                    Some(self.id.unwrap()),
-                    None,
-                    None
-                );
+                Some(GameMessage::TributeFrozen(self.clone()))
             },
             TributeStatus::Overheated => {
                 self.speed = Some(std::cmp::max(1, self.speed.unwrap() - 1));
-                create_full_log(
-                    self.game_id.unwrap(),
-                    GameMessage::TributeOverheated(self.clone()).to_string(),
-                    Some(self.area.clone().unwrap().id()),
-                    Some(self.id.unwrap()),
-                    None,
-                    None
-                );
+                Some(GameMessage::TributeOverheated(self.clone()))
             },
             TributeStatus::Dehydrated => {
                 self.strength = Some(std::cmp::max(1, self.strength.unwrap() - 1));
-                create_full_log(
-                    self.game_id.unwrap(),
-                    GameMessage::TributeDehydrated(self.clone()).to_string(),
-                    Some(self.area.clone().unwrap().id()),
-                    Some(self.id.unwrap()),
-                    None,
-                    None
-                );
+                Some(GameMessage::TributeDehydrated(self.clone()))
             },
             TributeStatus::Starving => {
                 self.strength = Some(std::cmp::max(1, self.strength.unwrap() - 1));
-                create_full_log(
-                    self.game_id.unwrap(),
-                    GameMessage::TributeStarving(self.clone()).to_string(),
-                    Some(self.area.clone().unwrap().id()),
-                    Some(self.id.unwrap()),
-                    None,
-                    None
-                );
+                Some(GameMessage::TributeStarving(self.clone()))
             },
             TributeStatus::Poisoned => {
                 self.takes_mental_damage(5);
-                create_full_log(
-                    self.game_id.unwrap(),
-                    GameMessage::TributePoisoned(self.clone()).to_string(),
-                    Some(self.area.clone().unwrap().id()),
-                    Some(self.id.unwrap()),
-                    None,
-                    None
-                );
+                Some(GameMessage::TributePoisoned(self.clone()))
             },
             TributeStatus::Broken => {
                 // coin flip for which bone breaks
-                let leg_bone = thread_rng().gen_bool(0.5);
+                let leg_bone = rng.gen_bool(0.5);
 
                 // TODO: Add in other bones? Ribs and skull make sense.
 
                 if leg_bone {
                     self.speed = Some(std::cmp::max(1, self.speed.unwrap() - 5));
-                    create_full_log(
-                        self.game_id.unwrap(),
-                        GameMessage::TributeBrokenLeg(self.clone()).to_string(),
-                        Some(self.area.clone().unwrap().id()),
-                        Some(self.id.unwrap()),
-                        None,
-                        None
-                    );
+                    Some(GameMessage::TributeBrokenLeg(self.clone()))
                 } else {
                     self.strength = Some(std::cmp::max(1, self.strength.unwrap() - 5));
-                    create_full_log(
-                        self.game_id.unwrap(),
-                        GameMessage::TributeBrokenArm(self.clone()).to_string(),
-                        Some(self.area.clone().unwrap().id()),
-                        Some(self.id.unwrap()),
-                        None,
-                        None
-                    );
+                    Some(GameMessage::TributeBrokenArm(self.clone()))
                 }
             },
             TributeStatus::Infected => {
                 self.takes_physical_damage(2);
                 self.takes_mental_damage(2);
-                create_full_log(
-                    self.game_id.unwrap(),
-                    GameMessage::TributeInfected(self.clone()).to_string(),
-                    Some(self.area.clone().unwrap().id()),
-                    Some(self.id.unwrap()),
-                    None,
-                    None
-                );
+                Some(GameMessage::TributeInfected(self.clone()))
             },
             TributeStatus::Drowned => {
                 self.takes_physical_damage(2);
                 self.takes_mental_damage(2);
-                create_full_log(
-                    self.game_id.unwrap(),
-                    GameMessage::TributeDrowned(self.clone()).to_string(),
-                    Some(self.area.clone().unwrap().id()),
-                    Some(self.id.unwrap()),
-                    None,
-                    None
-                );
+                Some(GameMessage::TributeDrowned(self.clone()))
             },
             TributeStatus::Mauled(animal) => {
-                let number_of_animals = thread_rng().gen_range(2..=5);
-                let damage = animal.damage() * number_of_animals;
+                let number_of_animals = rng.gen_range(2..=5);
+                let damage = tuning.animal_damage(&animal) * number_of_animals;
                 self.takes_physical_damage(damage);
-                create_full_log(
-                    self.game_id.unwrap(),
-                    GameMessage::TributeMauled(self.clone(), number_of_animals, animal.clone(), damage).to_string(),
-                    Some(self.area.clone().unwrap().id()),
-                    Some(self.id.unwrap()),
-                    None,
-                    None
-                );
+                Some(GameMessage::TributeMauled(self.clone(), number_of_animals, animal.clone(), damage))
             },
             TributeStatus::Burned => {
                 self.takes_physical_damage(5);
-                create_full_log(
-                    self.game_id.unwrap(),
-                    GameMessage::TributeBurned(self.clone()).to_string(),
-                    Some(self.area.clone().unwrap().id()),
-                    Some(self.id.unwrap()),
-                    None,
-                    None
-                );
+                Some(GameMessage::TributeBurned(self.clone()))
             }
-            _ => {}
-        }
-
-        if self.health <= 0 {
-            create_full_log(
-                self.game_id.unwrap(),
-                GameMessage::TributeDiesFromStatus(self.clone(), self.status.clone()).to_string(),
-                Some(self.area.clone().unwrap().id()),
-                Some(self.id.unwrap()),
-                None,
-                None
-            );
-            self.killed_by = Some(self.status.to_string());
-            self.status = TributeStatus::RecentlyDead;
+            _ => None
         }
     }
 
@@ -733,7 +912,7 @@ impl Tribute {
         if self.health <= 0 {
             create_full_log(
                 self.game_id.unwrap(),
-                GameMessage::TributeDiesFromTributeEvent(self.clone(), tribute_event.clone()).to_string(),
+                GameMessage::TributeDiesFromTributeEvent(self.clone(), tribute_event.clone()),
                 Some(self.area.clone().unwrap().id()),
                 Some(self.id.unwrap()),
                 None,
@@ -751,7 +930,7 @@ impl Tribute {
         if !tribute.is_alive() {
             create_full_log(
                 self.game_id.unwrap(),
-                GameMessage::TributeAlreadyDead(tribute.clone()).to_string(),
+                GameMessage::TributeAlreadyDead(tribute.clone()),
                 Some(tribute.area.clone().unwrap().id()),
                 Some(self.id.unwrap()),
                 None,
@@ -760,29 +939,53 @@ impl Tribute {
             return tribute.clone();
         }
 
+        let game = get_game_by_id(self.game_id.unwrap()).unwrap();
+        let tuning = game.tuning();
+
+        // Anything caught earlier comes on once it's incubated, counted once a phase
+        if let Some(message) = tribute.incubates() {
+            create_full_log(
+                self.game_id.unwrap(),
+                message,
+                Some(tribute.area.clone().unwrap().id()),
+                Some(self.id.unwrap()),
+                None,
+                None
+            );
+        }
+
         // Update the tribute based on the period's events.
-        tribute.process_status();
+        tribute.process_status(&tuning);
+
+        // Hiding spots don't stay safe forever
+        if tribute.hiding_decays() {
+            create_full_log(
+                self.game_id.unwrap(),
+                GameMessage::TributeHidingSpotLost(tribute.clone()),
+                Some(tribute.area.clone().unwrap().id()),
+                Some(self.id.unwrap()),
+                None,
+                None
+            );
+        }
 
         // Nighttime terror
         if !day && tribute.is_alive() {
             tribute.suffers();
         }
 
+        // Friends help, but a mind pushed too far can still break
+        tribute.takes_comfort();
+        tribute.copes();
+
         // Gift from patrons?
-        let chance = match self.district {
-            1 | 2 => 1.0 / 10.0,
-            3 | 4 => 1.0 / 15.0,
-            5 | 6 => 1.0 / 20.0,
-            7 | 8 => 1.0 / 25.0,
-            9 | 10 => 1.0 / 30.0,
-            _ => 1.0 / 50.0,
-        };
+        let chance = tuning.sponsor_chance(self.district);
 
-        if thread_rng().gen_bool(chance) {
+        if game.ruleset().allows_sponsors() && thread_rng().gen_bool((chance * tribute.sponsor_appeal()).min(1.0)) {
             let item = Item::new_generic_consumable(self.game_id, None, self.id);
             create_full_log(
                 self.game_id.unwrap(),
-                GameMessage::SponsorGift(tribute.clone(), item.clone()).to_string(),
+                GameMessage::SponsorGift(tribute.clone(), item.clone()),
                 None,
                 Some(self.id.unwrap()),
                 None,
@@ -794,40 +997,44 @@ impl Tribute {
         if tribute.status == TributeStatus::RecentlyDead || tribute.health <= 0 {
             create_full_log(
                 self.game_id.unwrap(),
-                GameMessage::TributeDead(tribute.clone()).to_string(),
+                GameMessage::TributeDead(tribute.clone()),
                 Some(tribute.area.clone().unwrap().id()),
                 Some(self.id.unwrap()),
                 None,
                 None
             );
-            return self.clone();
+            return tribute;
         }
 
-        let game = get_game_by_id(self.game_id.unwrap()).unwrap();
         let area = tribute.area.clone().unwrap();
         let closed_areas = game.closed_areas().clone();
 
-        let brain = &mut tribute.brain.clone();
-
-        if suggested_action.is_some() {
-            brain.set_preferred_action(suggested_action.unwrap(), probability.unwrap());
-        }
+        let living_tributes = get_all_living_tributes(&game);
+        // Grudges and threats die with whoever they were about
+        tribute.brain.forgets_the_dead(&living_tributes.iter().map(|t| t.id).collect::<Vec<i32>>());
 
-        let nearby_tributes = get_all_living_tributes(&game).iter()
+        let nearby_tributes = living_tributes.iter()
             .filter(|t| t.area().is_some())
             .map(|t| Tribute::from(t.clone()))
             .filter(|t| t.clone().area.unwrap() == area)
             .collect::<Vec<_>>().len();
 
+        tribute.looks_around();
+        let brain = &mut tribute.brain.clone();
+        if let (Some(suggested_action), Some(probability)) = (suggested_action, probability) {
+            brain.set_preferred_action(suggested_action, probability);
+        }
+
         let action = brain.act(&tribute, nearby_tributes, closed_areas.clone());
 
         match &action {
             TributeAction::Move(area) => {
-                match self.travels(closed_areas.clone(), area.clone()) {
+                match tribute.travels(closed_areas.clone(), area.clone()) {
                     TravelResult::Success(area) => {
                         tribute.changes_area(area.clone());
                         self.take_action(action.clone(), Some(area.clone().to_string()));
                         // No need to log the move, it's already done in self.travels.
+                        tribute.checks_for_traps();
                     },
                     TravelResult::Failure => {
                         tribute.short_rests();
@@ -840,7 +1047,7 @@ impl Tribute {
                 self.take_action(action.clone(), None);
                 create_full_log(
                     self.game_id.unwrap(),
-                    GameMessage::TributeHide(tribute.clone()).to_string(),
+                    GameMessage::TributeHide(tribute.clone()),
                     Some(tribute.area.clone().unwrap().id()),
                     Some(self.id.unwrap()),
                     Some(action.clone().as_str().to_string()),
@@ -850,18 +1057,23 @@ impl Tribute {
             TributeAction::Rest | TributeAction::None => {
                 tribute.long_rests();
                 self.take_action(action, None);
+                let message = match tribute.breakdown {
+                    Some(Breakdown::Catatonia) => GameMessage::TributeCatatonic(tribute.clone()),
+                    _ => GameMessage::TributeLongRest(tribute.clone()),
+                };
                 create_full_log(
                     self.game_id.unwrap(),
-                    GameMessage::TributeLongRest(tribute.clone()).to_string(),
+                    message,
                     Some(tribute.area.clone().unwrap().id()),
                     Some(self.id.unwrap()),
                     None,
                     None
                 );
+                tribute.checks_for_traps();
             },
             TributeAction::Attack => {
                 if let Some(mut target) = pick_target(tribute.clone().into()) {
-                    if target.is_visible() {
+                    if tribute.spots(&target) {
                         match tribute.attacks(&mut target) {
                             AttackOutcome::Kill(mut attacker, mut target) => {
                                 if attacker.health <= 0 {
@@ -886,7 +1098,15 @@ impl Tribute {
                     } else {
                         create_full_log(
                             self.game_id.unwrap(),
-                            GameMessage::TributeAttackHidden(tribute.clone(), target.clone()).to_string(),
+                            GameMessage::TributeUnspotted(tribute.clone(), target.clone()),
+                            Some(tribute.area.clone().unwrap().id()),
+                            Some(self.id.unwrap()),
+                            Some("Tribute".to_string()),
+                            Some(target.id.unwrap())
+                        );
+                        create_full_log(
+                            self.game_id.unwrap(),
+                            GameMessage::TributeAttackHidden(tribute.clone(), target.clone()),
                             Some(tribute.area.clone().unwrap().id()),
                             Some(self.id.unwrap()),
                             Some(action.clone().as_str().to_string()),
@@ -896,17 +1116,63 @@ impl Tribute {
                     }
                 }
             },
-            TributeAction::TakeItem => {
-                let item = tribute.take_nearby_item(area);
-                self.take_action(action.clone(), Some(item.name.clone()));
-                create_full_log(
-                    self.game_id.unwrap(),
-                    GameMessage::TributeTakeItem(tribute.clone(), item.clone()).to_string(),
-                    Some(tribute.area.clone().unwrap().id()),
-                    Some(self.id.unwrap()),
-                    Some(action.clone().as_str().to_string()),
-                    Some(item.id.unwrap())
-                );
+            TributeAction::Forage | TributeAction::Hunt | TributeAction::Fish | TributeAction::FindWater => {
+                tribute.lives_off_land(&action);
+                self.take_action(action, None);
+            },
+            TributeAction::Craft => {
+                if let Some(item) = tribute.crafts() {
+                    self.take_action(action.clone(), Some(item.name.clone()));
+                    create_full_log(
+                        self.game_id.unwrap(),
+                        GameMessage::TributeCrafts(tribute.clone(), item.clone()),
+                        Some(tribute.area.clone().unwrap().id()),
+                        Some(self.id.unwrap()),
+                        Some(action.clone().as_str().to_string()),
+                        Some(item.id.unwrap())
+                    );
+                }
+            },
+            TributeAction::SetTrap => {
+                match tribute.sets_trap() {
+                    Some(trap) => {
+                        self.take_action(action.clone(), Some(trap.name.clone()));
+                        create_full_log(
+                            self.game_id.unwrap(),
+                            GameMessage::TributeSetsTrap(tribute.clone(), trap),
+                            Some(tribute.area.clone().unwrap().id()),
+                            Some(self.id.unwrap()),
+                            None,
+                            None
+                        );
+                    }
+                    None => {
+                        tribute.long_rests();
+                        self.take_action(TributeAction::Rest, None);
+                    }
+                }
+            },
+            TributeAction::TakeItem => match tribute.take_nearby_item(area) {
+                Some(item) => {
+                    self.take_action(action.clone(), Some(item.name.clone()));
+                    let message = match item.is_backpack() {
+                        true => GameMessage::TributeTakeBackpack(tribute.clone(), item.clone()),
+                        false => GameMessage::TributeTakeItem(tribute.clone(), item.clone()),
+                    };
+                    create_full_log(
+                        self.game_id.unwrap(),
+                        message,
+                        Some(tribute.area.clone().unwrap().id()),
+                        Some(self.id.unwrap()),
+                        Some(action.clone().as_str().to_string()),
+                        Some(item.id.unwrap())
+                    );
+                }
+                // Nothing here is theirs to take
+                None => {
+                    tribute.long_rests();
+                    self.take_action(TributeAction::Rest, None);
+                }
             },
             TributeAction::UseItem(None) => {
                 // Get consumable items
@@ -921,7 +1187,7 @@ impl Tribute {
                         true => {
                             create_full_log(
                                 self.game_id.unwrap(),
-                                GameMessage::TributeUseItem(tribute.clone(), item.clone()).to_string(),
+                                GameMessage::TributeUseItem(tribute.clone(), item.clone()),
                                 Some(tribute.area.clone().unwrap().id()),
                                 Some(self.id.unwrap()),
                                 Some(action.clone().as_str().to_string()),
@@ -932,7 +1198,7 @@ impl Tribute {
                         false => {
                             create_full_log(
                                 self.game_id.unwrap(),
-                                GameMessage::TributeCannotUseItem(tribute.clone(), item.clone()).to_string(),
+                                GameMessage::TributeCannotUseItem(tribute.clone(), item.clone()),
                                 Some(tribute.area.clone().unwrap().id()),
                                 Some(self.id.unwrap()),
                                 Some(action.clone().as_str().to_string()),
@@ -953,7 +1219,7 @@ impl Tribute {
                             true => {
                                 create_full_log(
                                     self.game_id.unwrap(),
-                                    GameMessage::TributeUseItem(tribute.clone(), selected_item.unwrap().clone()).to_string(),
+                                    GameMessage::TributeUseItem(tribute.clone(), selected_item.unwrap().clone()),
                                     Some(tribute.area.clone().unwrap().id()),
                                     Some(self.id.unwrap()),
                                     Some(action.clone().as_str().to_string()),
@@ -964,7 +1230,7 @@ impl Tribute {
                             false => {
                                 create_full_log(
                                     self.game_id.unwrap(),
-                                    GameMessage::TributeCannotUseItem(tribute.clone(), selected_item.unwrap().clone()).to_string(),
+                                    GameMessage::TributeCannotUseItem(tribute.clone(), selected_item.unwrap().clone()),
                                     Some(tribute.area.clone().unwrap().id()),
                                     Some(self.id.unwrap()),
                                     Some(action.clone().as_str().to_string()),
@@ -981,6 +1247,151 @@ impl Tribute {
         tribute.clone()
     }
 
+    /// Forages, hunts, fishes or looks for water where they are. Hunters go after any wild pack
+    /// in the area, which makes for easier game than whatever's hiding in the undergrowth.
+    pub fn lives_off_land(&mut self, action: &TributeAction) {
+        let area = self.area.clone().unwrap();
+        let mut prey = Pack::all(self.game_id.unwrap()).into_iter()
+            .filter(|_| *action == TributeAction::Hunt)
+            .find(|pack| pack.area == area && !pack.is_mutts());
+        let resources = area.resources(action) + if prey.is_some() { 30 } else { 0 };
+
+        let kind = action.as_str().to_lowercase().replace(' ', "-");
+        let message = match gather(action, self, resources, &mut thread_rng()) {
+            Haul::Provisions(name, effect) => {
+                let name = match prey.as_mut() {
+                    Some(pack) => {
+                        pack.takes_damage(pack.toughness());
+                        pack.save();
+                        "meat".to_string()
+                    }
+                    None => name,
+                };
+                let item = Item::create(name, ItemType::Consumable.to_string(), 1, Attribute::Health.to_string(), effect, self.game_id, None, self.id);
+                GameMessage::TributeGathers(self.clone(), kind, item)
+            }
+            Haul::Materials(name, quantity) => {
+                let item = Item::create(name, ItemType::Material.to_string(), quantity, Attribute::Strength.to_string(), 0, self.game_id, None, self.id);
+                GameMessage::TributeGathers(self.clone(), kind, item)
+            }
+            Haul::Mishap(status) => {
+                self.status = status;
+                GameMessage::TributeGatheringMishap(self.clone(), kind)
+            }
+            Haul::Nothing => GameMessage::TributeGathersNothing(self.clone(), kind),
+        };
+        create_full_log(
+            self.game_id.unwrap(),
+            message,
+            Some(area.id()),
+            Some(self.id.unwrap()),
+            None,
+            None
+        );
+    }
+
+    /// Makes the best thing they can out of what they're carrying, using up its ingredients.
+    pub fn crafts(&mut self) -> Option<Item> {
+        let held = self.items();
+        let recipe = pick_recipe(self, &held, !self.weapons().is_empty())?;
+        for (ingredient, count) in recipe.ingredients {
+            let mut needed = *count;
+            for mut item in held.iter().filter(|i| i.name == *ingredient).cloned() {
+                let used = std::cmp::min(needed, item.quantity);
+                item.quantity -= used;
+                item.save();
+                needed -= used;
+                if needed == 0 {
+                    break;
+                }
+            }
+        }
+
+        let quality = recipe.quality(recipe.skill.of(self), thread_rng().gen_range(1..=20));
+        let mut item = Item::create(
+            recipe.name_for(quality),
+            recipe.item_type.to_string(),
+            1,
+            recipe.attribute.to_string(),
+            recipe.effect_for(quality),
+            self.game_id,
+            None,
+            self.id,
+        );
+        item.components = recipe.components();
+        item.save();
+        Some(item)
+    }
+
+    /// Leaves one of the traps they're carrying set where they are.
+    pub fn sets_trap(&mut self) -> Option<Trap> {
+        let mut item = self.items().into_iter().find(|i| i.is_trap())?;
+        let trap = Trap::set(self, &item, thread_rng().gen_range(1..=20));
+        item.quantity -= 1;
+        item.save();
+        Some(trap)
+    }
+
+    /// Looks out for traps other tributes have left, coming into an area or settling down in it.
+    /// Any they spot they break, or take for themselves if they're handy enough. The first one
+    /// they don't spot goes off on them.
+    pub fn checks_for_traps(&mut self) {
+        let mut rng = thread_rng();
+        let area = self.area.clone().unwrap();
+        let traps = Trap::in_area(self.game_id.unwrap(), &area).into_iter()
+            .filter(|trap| Some(trap.owner_id) != self.id);
+        for trap in traps {
+            trap.remove();
+            if !trap.spotted_by(self, rng.gen_range(1..=50)) {
+                self.springs_trap(&trap);
+                return;
+            }
+
+            let message = match trap.stolen_by(self, rng.gen_range(1..=50)) {
+                true => {
+                    Item::create(trap.name.clone(), ItemType::Material.to_string(), 1, Attribute::Strength.to_string(), trap.strength, self.game_id, None, self.id);
+                    GameMessage::TributeStealsTrap(self.clone(), trap.clone())
+                }
+                false => GameMessage::TributeDisarmsTrap(self.clone(), trap.clone()),
+            };
+            create_full_log(self.game_id.unwrap(), message, Some(area.id()), self.id, None, None);
+        }
+    }
+
+    /// Takes the trap's damage, leaving them wounded. If it kills them, it's the owner's kill.
+    fn springs_trap(&mut self, trap: &Trap) {
+        let mut owner = Tribute::from(get_tribute_by_id(trap.owner_id));
+        self.takes_physical_damage(trap.strength);
+        create_full_log(
+            self.game_id.unwrap(),
+            GameMessage::TributeCaughtInTrap(self.clone(), owner.clone(), trap.clone(), trap.strength),
+            Some(trap.area.id()),
+            self.id,
+            None,
+            None
+        );
+        if self.health > 0 {
+            if self.status == TributeStatus::Healthy {
+                self.status = TributeStatus::Wounded;
+            }
+            return;
+        }
+
+        create_full_log(
+            self.game_id.unwrap(),
+            GameMessage::TributeDiesInTrap(self.clone(), owner.clone(), trap.clone()),
+            Some(trap.area.id()),
+            self.id,
+            None,
+            None
+        );
+        self.killed_by = Some(owner.name.clone());
+        self.dies();
+        remember_killer(&owner, self);
+        owner.kills = Some(owner.kills.unwrap_or(0) + 1);
+        update_tribute(owner.id.unwrap(), owner.into());
+    }
+
     fn take_action(&self, action: TributeAction, target: Option<String>) {
         use models::tribute_action::take_action;
         use models::action::get_action;
@@ -990,12 +1401,18 @@ impl Tribute {
         take_action(&tribute, &action, target);
     }
 
-    fn take_nearby_item(&self, area: Area) -> Item {
+    /// Picks up a backpack meant for them if there is one, otherwise anything they're allowed.
+    /// None if everything here is someone else's.
+    fn take_nearby_item(&self, area: Area) -> Option<Item> {
         let mut rng = thread_rng();
-        let mut items = area.available_items(self.game_id.unwrap());
-        let item = items.choose_mut(&mut rng).unwrap();
+        let items = area.available_items(self.game_id.unwrap()).into_iter()
+            .filter(|i| i.is_for(self))
+            .collect::<Vec<Item>>();
+        let item = items.iter().find(|i| i.is_backpack())
+            .or_else(|| items.choose(&mut rng))?
+            .clone();
         self.take_item(item.clone());
-        item.clone()
+        Some(item)
     }
 
     fn take_item(&self, item: Item) {
@@ -1022,6 +1439,9 @@ impl Tribute {
         match item.attribute {
             Attribute::Health => {
                 self.heals(item.effect);
+                if item.cures(&self.status) {
+                    self.status = TributeStatus::Healthy;
+                }
             },
             Attribute::Sanity => {
                 self.heals_mental_damage(item.effect);
@@ -1076,6 +1496,64 @@ pub enum TravelResult {
     Failure,
 }
 
+/// Anyone who watched the victim die, and their district mates wherever they are, take it hard.
+/// Braver tributes take it better.
+pub fn trauma_from_death(victim: &Tribute) {
+    let game = get_game_by_id(victim.game_id.unwrap()).unwrap();
+    let survivors = get_all_living_tributes(&game).into_iter()
+        .map(Tribute::from)
+        .filter(|t| t.id != victim.id)
+        .filter(|t| Some(&t.name) != victim.killed_by.as_ref());
+
+    for mut survivor in survivors {
+        let courage = 150 - survivor.bravery.unwrap_or(0);
+        let message = if survivor.district == victim.district {
+            let damage = 20 * courage / 100;
+            survivor.takes_mental_damage(damage);
+            GameMessage::TributeMournDistrictMate(survivor.clone(), victim.clone(), damage)
+        } else if survivor.area == victim.area {
+            let damage = 10 * courage / 100;
+            survivor.takes_mental_damage(damage);
+            GameMessage::TributeWitnessDeath(survivor.clone(), victim.clone(), damage)
+        } else {
+            continue;
+        };
+
+        create_full_log(
+            game.id,
+            message,
+            Some(survivor.area.clone().unwrap().id()),
+            Some(survivor.id.unwrap()),
+            Some("Tribute".to_string()),
+            Some(victim.id.unwrap())
+        );
+        update_tribute(survivor.id.unwrap(), survivor.clone().into());
+    }
+}
+
+/// The victim's surviving district mates won't forget who killed them.
+pub fn remember_killer(killer: &Tribute, victim: &Tribute) {
+    if killer.id == victim.id {
+        return;
+    }
+
+    let game = get_game_by_id(victim.game_id.unwrap()).unwrap();
+    let district_mates = get_all_living_tributes(&game).into_iter()
+        .filter(|t| t.district == victim.district)
+        .filter(|t| Some(t.id) != victim.id && Some(t.id) != killer.id)
+        .collect::<Vec<_>>();
+
+    for district_mate in district_mates {
+        TributeMemoryModel::create(
+            district_mate.id,
+            game.id,
+            MemoryKind::KilledDistrictMate.to_string(),
+            killer.id.unwrap(),
+            victim.area.as_ref().map(|a| a.id())
+        );
+    }
+}
+
 #[allow(dead_code)]
 fn apply_violence_stress(tribute: &mut Tribute) {
     let kills = tribute.kills.unwrap_or(0);
@@ -1090,7 +1568,7 @@ fn apply_violence_stress(tribute: &mut Tribute) {
     if terror.round() > 0.0 {
         create_full_log(
             tribute.game_id.unwrap(),
-            GameMessage::TributeHorrified(tribute.clone(), terror.round() as i32).to_string(),
+            GameMessage::TributeHorrified(tribute.clone(), terror.round() as i32),
             Some(tribute.area.clone().unwrap().id()),
             Some(tribute.id.unwrap()),
             None,
@@ -1101,16 +1579,14 @@ fn apply_violence_stress(tribute: &mut Tribute) {
 }
 
 fn attack_contest(attacker: Tribute, target: Tribute) -> AttackResult {
-    let mut tribute1_roll = thread_rng().gen_range(1..=20); // Base roll
-    tribute1_roll += attacker.strength.unwrap(); // Add strength
+    let mut weapon = attacker.weapons().last().cloned();
+    let tribute1_roll = attack_total(&attacker, weapon.as_ref(), thread_rng().gen_range(1..=20));
 
-    if let Some(weapon) = attacker.weapons().iter_mut().last() {
-        tribute1_roll += weapon.effect; // Add weapon damage
-        weapon.quantity -= 1;
-        if weapon.quantity <= 0 {
+    if let Some(weapon) = weapon.as_mut() {
+        if weapon.wears() {
             create_full_log(
                 attacker.game_id.unwrap(),
-                GameMessage::WeaponBreak(attacker.clone(), weapon.clone()).to_string(),
+                GameMessage::WeaponBreak(attacker.clone(), weapon.clone()),
                 Some(attacker.area.clone().unwrap().id()),
                 Some(attacker.id.unwrap()),
                 Some("Weapon".to_string()),
@@ -1123,16 +1599,14 @@ fn attack_contest(attacker: Tribute, target: Tribute) -> AttackResult {
 
     // Add luck in here?
 
-    let mut tribute2_roll = thread_rng().gen_range(1..=20); // Base roll
-    tribute2_roll += target.defense.unwrap(); // Add defense
+    let mut shield = target.defensive_items().first().cloned();
+    let tribute2_roll = defense_total(&target, shield.as_ref(), thread_rng().gen_range(1..=20));
 
-    if let Some(shield) = target.items().iter_mut().filter(|i| i.is_defensive()).next() {
-        tribute2_roll += shield.effect; // Add weapon defense
-        shield.quantity -= 1;
-        if shield.quantity <= 0 {
+    if let Some(shield) = shield.as_mut() {
+        if shield.wears() {
             create_full_log(
                 target.game_id.unwrap(),
-                GameMessage::ShieldBreak(target.clone(), shield.clone()).to_string(),
+                GameMessage::ShieldBreak(target.clone(), shield.clone()),
                 Some(target.area.clone().unwrap().id()),
                 Some(target.id.unwrap()),
                 Some("Shield".to_string()),
@@ -1143,24 +1617,93 @@ fn attack_contest(attacker: Tribute, target: Tribute) -> AttackResult {
         update_item(models::UpdateItem::from(shield.clone()).into());
     }
 
-    let response = {
-        if tribute1_roll > tribute2_roll {
-            if tribute1_roll >= tribute2_roll + 5 { // Attacker wins significantly
-                AttackResult::AttackerWinsDecisively
-            } else {
-                AttackResult::AttackerWins
-            }
-        } else if tribute2_roll > tribute1_roll {
-            if tribute2_roll >= tribute1_roll + 5 { // Defender wins significantly
-                AttackResult::DefenderWinsDecisively
-            } else {
-                AttackResult::DefenderWins
-            }
+    let margin = get_game_by_id(attacker.game_id.unwrap()).expect("Game doesn't exist").tuning().decisive_margin;
+    contest_result(tribute1_roll, tribute2_roll, margin)
+}
+
+/// A d20 `roll` plus the attacker's strength and the damage of their weapon, if they have one.
+pub(crate) fn attack_total(attacker: &Tribute, weapon: Option<&Item>, roll: i32) -> i32 {
+    roll + attacker.strength.unwrap_or(0) + weapon.map_or(0, |weapon| weapon.effect)
+}
+
+/// A d20 `roll` plus the target's defense and the protection of their shield, if they have one.
+pub(crate) fn defense_total(target: &Tribute, shield: Option<&Item>, roll: i32) -> i32 {
+    roll + target.defense.unwrap_or(0) + shield.map_or(0, |shield| shield.effect)
+}
+
+/// What the loser of a contest takes: the winner's strength, doubled when it was decisive.
+pub(crate) fn contest_damage(result: &AttackResult, attacker: &Tribute, target: &Tribute) -> i32 {
+    match result {
+        AttackResult::AttackerWins => attacker.strength.unwrap_or(0),
+        AttackResult::AttackerWinsDecisively => attacker.strength.unwrap_or(0) * 2,
+        AttackResult::DefenderWins => target.strength.unwrap_or(0),
+        AttackResult::DefenderWinsDecisively => target.strength.unwrap_or(0) * 2,
+        AttackResult::Miss => 0,
+    }
+}
+
+/// Who comes out on top when an attack totalling `attack` meets a defense totalling `defense`.
+/// Winning by `margin` or more is decisive.
+pub(crate) fn contest_result(attack: i32, defense: i32, margin: i32) -> AttackResult {
+    if attack > defense {
+        if attack >= defense + margin { // Attacker wins significantly
+            AttackResult::AttackerWinsDecisively
+        } else {
+            AttackResult::AttackerWins
+        }
+    } else if defense > attack {
+        if defense >= attack + margin { // Defender wins significantly
+            AttackResult::DefenderWinsDecisively
         } else {
-            AttackResult::Miss
+            AttackResult::DefenderWins
         }
-    };
-    response
+    } else {
+        AttackResult::Miss
+    }
+}
+
+/// The spotter's wits against however well the hider is concealed.
+fn spot_contest(spotter: &Tribute, hider: &Tribute) -> bool {
+    spotted(spotter, hider, thread_rng().gen_range(1..=50))
+}
+
+/// Whether a d50 `roll` on top of the spotter's wits finds the hider.
+pub(crate) fn spotted(spotter: &Tribute, hider: &Tribute, roll: i32) -> bool {
+    spotter.intelligence.unwrap_or(0) / 2 + roll >= hider.stealth.unwrap_or(0)
+}
+
+/// A showing in training, nudged by `roll`, as the gamemakers' 1-12 score.
+/// Fighting ability counts for the most.
+pub(crate) fn training_score(tribute: &Tribute, roll: i32) -> i32 {
+    let showing = (tribute.strength.unwrap_or(0) * 2
+        + tribute.defense.unwrap_or(0) * 2
+        + tribute.dexterity.unwrap_or(0)
+        + tribute.speed.unwrap_or(0)
+        + tribute.intelligence.unwrap_or(0)
+        + tribute.bravery.unwrap_or(0)) / 6;
+    let showing = (showing + roll).clamp(1, 100);
+    (showing * 12 + 99) / 100
+}
+
+pub(crate) fn interview_likability(tribute: &Tribute, roll: i32) -> i32 {
+    let talking_point = tribute.training_score.map_or(0, |score| score - 6);
+    (tribute.persuasion.unwrap_or(0) + talking_point + roll).clamp(1, 100)
+}
+
+/// How readily `attacker` goes after `target`. Tributes who scored higher in training are
+/// feared, less so by the brave; high scorers are rivals to anyone who scored as well;
+/// and nobody minds going after an unlikable tribute.
+pub(crate) fn target_weight(attacker: &Tribute, target: &Tribute) -> f64 {
+    let own_score = attacker.training_score.unwrap_or(6);
+    let their_score = target.training_score.unwrap_or(6);
+    let mut weight = 1.0;
+    if their_score > own_score {
+        let fear = (100 - attacker.bravery.unwrap_or(0)) as f64 / 100.0;
+        weight /= 1.0 + (their_score - own_score) as f64 * fear / 4.0;
+    } else if their_score >= 8 {
+        weight *= 1.5;
+    }
+    weight * (1.5 - target.likability.unwrap_or(50) as f64 / 100.0)
 }
 
 pub fn pick_target(tribute: TributeModel) -> Option<Tribute> {
@@ -1177,7 +1720,7 @@ pub fn pick_target(tribute: TributeModel) -> Option<Tribute> {
                 0..=9 => { // attempt suicide
                     create_full_log(
                         tribute.game_id.unwrap(),
-                        GameMessage::TributeSuicide(Tribute::from(tribute.clone())).to_string(),
+                        GameMessage::TributeSuicide(Tribute::from(tribute.clone())),
                         Some(area.id),
                         Some(tribute.id),
                         Some("Tribute".to_string()),
@@ -1189,7 +1732,7 @@ pub fn pick_target(tribute: TributeModel) -> Option<Tribute> {
                     true => { // attempt suicide
                         create_full_log(
                             tribute.game_id.unwrap(),
-                            GameMessage::TributeSuicide(Tribute::from(tribute.clone())).to_string(),
+                            GameMessage::TributeSuicide(Tribute::from(tribute.clone())),
                             Some(area.id),
                             Some(tribute.id),
                             Some("Tribute".to_string()),
@@ -1203,22 +1746,43 @@ pub fn pick_target(tribute: TributeModel) -> Option<Tribute> {
             }
         },
         _ => {
+            let attacker = Tribute::from(tribute.clone());
+            let game = get_game_by_id(tribute.game_id.unwrap()).unwrap();
             let mut targets = tributes.clone();
             let enemy_targets: Vec<Tribute> = targets.iter().cloned()
-                .filter(|t| t.district != tribute.district)
-                .filter(|t| t.is_visible())
+                .filter(|t| !game.are_allies(tribute.district, t.district))
+                .filter(|t| attacker.spots(t))
                 .collect();
 
-            match tribute.sanity {
-                0..20 => (), // Sanity is low, target everyone
-                _ => targets = enemy_targets.clone() // Sane enough not to attack district mate
+            match (tribute.sanity, &attacker.breakdown) {
+                (0..20, _) if !game.allows_co_victors() => (), // Sanity is low, target everyone
+                (_, Some(Breakdown::Paranoia)) => (), // Trusts no one, target everyone
+                _ => targets = enemy_targets.clone() // Sane enough not to attack district mates or allies
+            }
+
+            // Anyone they hold a grudge against comes first
+            let grudge_targets: Vec<Tribute> = targets.iter()
+                .filter(|t| attacker.brain.holds_grudge_against(t.id.unwrap()))
+                .cloned()
+                .collect();
+            if let Some(target) = grudge_targets.choose(&mut thread_rng()) {
+                create_full_log(
+                    tribute.game_id.unwrap(),
+                    GameMessage::TributeRevenge(attacker.clone(), target.clone()),
+                    Some(area.id),
+                    Some(tribute.id),
+                    Some("Tribute".to_string()),
+                    Some(target.id.unwrap())
+                );
+                return Some(target.clone());
             }
 
             match targets.len() {
                 0 | 1 => Some(targets.first()?.clone()), // Easy choice
                 _ => {
                     let mut rng = thread_rng();
-                    Some(targets.choose(&mut rng)?.clone()) // Get a random enemy
+                    // Get a random enemy, sizing them up by their pre-game scores
+                    targets.choose_weighted(&mut rng, |t| target_weight(&attacker, t)).ok().cloned()
                 }
             }
         }
@@ -1244,10 +1808,16 @@ impl From<TributeModel> for Tribute {
             .map(TributeAction::from)
             .collect();
 
+        let memories: Vec<Memory> = tribute.memories()
+            .into_iter()
+            .map(Memory::from)
+            .collect();
+
         let brain = TributeBrain {
             previous_actions: actions,
             preferred_action: None,
             preferred_action_percentage: 0.0,
+            memories,
         };
 
         Self {
@@ -1280,6 +1850,14 @@ impl From<TributeModel> for Tribute {
             status: TributeStatus::from_str(tribute.status.as_str()).unwrap(),
             avatar: tribute.avatar.clone(),
             real_name: tribute.real_name,
+            destination: tribute.destination_id.and_then(Area::get_by_id),
+            stealth: tribute.stealth,
+            breakdown: tribute.breakdown.and_then(|b| Breakdown::from_str(b.as_str()).ok()),
+            pronouns: tribute.pronouns.and_then(|p| Pronouns::from_str(p.as_str()).ok()),
+            training_score: tribute.training_score,
+            likability: tribute.likability,
+            incubating: tribute.incubating.and_then(|s| TributeStatus::from_str(s.as_str()).ok()),
+            incubation: tribute.incubation,
         }
     }
 }
@@ -1319,6 +1897,14 @@ impl Into<UpdateTribute> for Tribute {
             status: self.status.to_string(),
             avatar: self.avatar,
             real_name: self.real_name.clone(),
+            destination_id: Some(self.destination.as_ref().map(|a| a.id())),
+            stealth: self.stealth,
+            breakdown: Some(self.breakdown.as_ref().map(|b| b.to_string())),
+            pronouns: Some(self.pronouns.as_ref().map(|p| p.to_string())),
+            training_score: self.training_score,
+            likability: self.likability,
+            incubating: Some(self.incubating.as_ref().map(|s| s.to_string())),
+            incubation: Some(self.incubation),
         }
     }
 }
@@ -1360,6 +1946,17 @@ mod tests {
         assert_eq!(tribute.movement, 100);
     }
 
+    #[test]
+    fn step_cost() {
+        let mut tribute = Tribute::new("Katniss".to_string(), None, None);
+        tribute.speed = Some(1);
+        assert_eq!(tribute.step_cost(), 50);
+        tribute.speed = Some(50);
+        assert_eq!(tribute.step_cost(), 25);
+        tribute.speed = Some(100);
+        assert_eq!(tribute.step_cost(), 10);
+    }
+
     #[test]
     fn is_hidden_true() {
         let mut tribute = Tribute::new("Katniss".to_string(), None, None);
@@ -1367,4 +1964,116 @@ mod tests {
         tribute.is_hidden = Some(true);
         assert!(!tribute.is_visible());
     }
+
+    #[test]
+    fn hiding_quality_uses_cover_and_camouflage() {
+        let mut tribute = Tribute::new("Katniss".to_string(), None, None);
+        tribute.dexterity = Some(100);
+        tribute.intelligence = Some(100);
+        tribute.area = Some(Area::Cornucopia);
+        let quality = tribute.hiding_quality(0);
+        assert!((51..=70).contains(&quality));
+
+        tribute.area = Some(Area::Southwest);
+        assert_eq!(tribute.hiding_quality(50), 100);
+    }
+
+    #[test]
+    fn hiding_decays() {
+        let mut tribute = Tribute::new("Katniss".to_string(), None, None);
+        assert!(!tribute.hiding_decays());
+
+        tribute.is_hidden = Some(true);
+        tribute.stealth = Some(15);
+        assert!(!tribute.hiding_decays());
+        assert_eq!(tribute.stealth, Some(5));
+        assert!(tribute.hiding_decays());
+        assert!(tribute.is_visible());
+    }
+
+    #[test]
+    fn spot_contest_extremes() {
+        let mut spotter = Tribute::new("Cato".to_string(), None, None);
+        let mut hider = Tribute::new("Katniss".to_string(), None, None);
+        hider.stealth = Some(0);
+        assert!(spot_contest(&spotter, &hider));
+
+        spotter.intelligence = Some(0);
+        hider.stealth = Some(51);
+        assert!(!spot_contest(&spotter, &hider));
+    }
+
+    #[test]
+    fn training_scores_stay_in_range() {
+        let mut tribute = Tribute::new("Thresh".to_string(), None, None);
+        tribute.strength = Some(50);
+        tribute.defense = Some(50);
+        tribute.dexterity = Some(100);
+        tribute.speed = Some(100);
+        tribute.intelligence = Some(100);
+        tribute.bravery = Some(100);
+        assert_eq!(training_score(&tribute, 15), 12);
+        assert_eq!(training_score(&tribute, -15), 11);
+
+        for stat in [&mut tribute.strength, &mut tribute.defense, &mut tribute.dexterity,
+                     &mut tribute.speed, &mut tribute.intelligence, &mut tribute.bravery] {
+            *stat = Some(1);
+        }
+        assert_eq!(training_score(&tribute, -15), 1);
+        assert_eq!(training_score(&tribute, 15), 2);
+    }
+
+    #[test]
+    fn likability_follows_persuasion() {
+        let mut charmer = Tribute::new("Finnick".to_string(), None, None);
+        let mut mumbler = Tribute::new("Foxface".to_string(), None, None);
+        charmer.persuasion = Some(90);
+        mumbler.persuasion = Some(10);
+        assert!(interview_likability(&charmer, -20) > interview_likability(&mumbler, 20));
+
+        mumbler.training_score = Some(12);
+        assert_eq!(interview_likability(&mumbler, 0), 16);
+        assert!(charmer.sponsor_appeal() == 1.0);
+        charmer.likability = Some(100);
+        assert!(charmer.sponsor_appeal() > 1.0);
+    }
+
+    #[test]
+    fn high_scorers_are_feared() {
+        let mut attacker = Tribute::new("Rue".to_string(), None, None);
+        let mut career = Tribute::new("Cato".to_string(), None, None);
+        let mut weakling = Tribute::new("Glimmer".to_string(), None, None);
+        attacker.training_score = Some(7);
+        attacker.bravery = Some(20);
+        career.training_score = Some(10);
+        weakling.training_score = Some(3);
+        assert!(target_weight(&attacker, &career) < target_weight(&attacker, &weakling));
+
+        attacker.training_score = Some(11);
+        assert!(target_weight(&attacker, &career) > target_weight(&attacker, &weakling));
+    }
+
+    #[test]
+    fn gear_counts_in_a_fight() {
+        let mut attacker = Tribute::new("Clove".to_string(), None, None);
+        let mut target = Tribute::new("Rue".to_string(), None, None);
+        attacker.strength = Some(10);
+        target.strength = Some(4);
+        target.defense = Some(8);
+        let mut knife = Item::rolled_weapon("knife".to_string(), &mut thread_rng());
+        knife.effect = 3;
+        let mut shield = Item::rolled_shield("shield".to_string(), &mut thread_rng());
+        shield.effect = 5;
+
+        assert_eq!(attack_total(&attacker, None, 10), 20);
+        assert_eq!(attack_total(&attacker, Some(&knife), 10), 23);
+        assert_eq!(defense_total(&target, Some(&shield), 10), 23);
+        assert_eq!(contest_damage(&AttackResult::AttackerWinsDecisively, &attacker, &target), 20);
+        assert_eq!(contest_damage(&AttackResult::DefenderWins, &attacker, &target), 4);
+        assert_eq!(contest_damage(&AttackResult::Miss, &attacker, &target), 0);
+
+        knife.quantity = 2;
+        assert!(!knife.wears());
+        assert!(knife.wears());
+    }
 }
@@ -1,14 +1,50 @@
+use rand::prelude::SliceRandom;
 use rand::{thread_rng, Rng};
 use crate::areas::Area;
+use crate::crafting::pick_recipe;
 use crate::items::Item;
+use crate::models::{get_all_living_tributes, get_game_by_id, get_tribute_by_id};
+use crate::tributes::statuses::TributeStatus;
+use crate::tributes::survival::best_survival_action;
 use crate::tributes::actions::TributeAction;
 use crate::tributes::actors::Tribute;
+use crate::tributes::breakdowns::Breakdown;
+use crate::tributes::memories::{Memory, MemoryKind};
+
+/// Where a tribute wants to end up, and why.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TravelGoal {
+    Safety(Area),
+    Hunt(i32, Area),
+    Ally(Area),
+    Supplies(Area),
+    Destination(Area),
+}
+
+impl TravelGoal {
+    pub fn area(&self) -> Area {
+        match self {
+            TravelGoal::Safety(area) => area.clone(),
+            TravelGoal::Hunt(_, area) => area.clone(),
+            TravelGoal::Ally(area) => area.clone(),
+            TravelGoal::Supplies(area) => area.clone(),
+            TravelGoal::Destination(area) => area.clone(),
+        }
+    }
+}
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct TributeBrain {
     pub(crate) previous_actions: Vec<TributeAction>,
     pub(crate) preferred_action: Option<TributeAction>,
     pub(crate) preferred_action_percentage: f64,
+    pub(crate) memories: Vec<Memory>,
+}
+
+impl Default for TributeBrain {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl TributeBrain {
@@ -17,7 +53,125 @@ impl TributeBrain {
             previous_actions: Vec::new(),
             preferred_action: None,
             preferred_action_percentage: 0.0,
+            memories: Vec::new(),
+        }
+    }
+
+    pub fn remember(&mut self, memory: Memory) {
+        self.memories.push(memory);
+    }
+
+    /// Drops what they remember about any tribute who isn't among the `living`.
+    /// Memories of items are kept.
+    pub fn forgets_the_dead(&mut self, living: &[i32]) {
+        self.memories.retain(|m| m.kind == MemoryKind::SawItem || living.contains(&m.subject_id));
+    }
+
+    /// Tributes who attacked this tribute or killed their district mate.
+    pub fn grudges(&self) -> Vec<i32> {
+        let mut grudges: Vec<i32> = vec![];
+        for memory in self.memories.iter().filter(|m| m.is_grudge()) {
+            if !grudges.contains(&memory.subject_id) {
+                grudges.push(memory.subject_id);
+            }
+        }
+        grudges
+    }
+
+    pub fn holds_grudge_against(&self, tribute_id: i32) -> bool {
+        self.memories.iter()
+            .filter(|m| m.is_grudge())
+            .any(|m| m.subject_id == tribute_id)
+    }
+
+    /// Where the tribute last saw another tribute, if they've seen them at all.
+    pub fn last_seen(&self, tribute_id: i32) -> Option<Area> {
+        self.memories.iter()
+            .rev()
+            .filter(|m| m.kind == MemoryKind::SawTribute)
+            .find(|m| m.subject_id == tribute_id)
+            .and_then(|m| m.area.clone())
+    }
+
+    pub fn remembers_item(&self, item_id: i32, area: &Area) -> bool {
+        self.memories.iter()
+            .filter(|m| m.kind == MemoryKind::SawItem)
+            .any(|m| m.subject_id == item_id && m.area.as_ref() == Some(area))
+    }
+
+    /// Areas where the tribute has seen unclaimed items, most recent first.
+    pub fn remembered_item_areas(&self) -> Vec<Area> {
+        let mut areas: Vec<Area> = vec![];
+        for memory in self.memories.iter().rev().filter(|m| m.kind == MemoryKind::SawItem) {
+            if let Some(area) = &memory.area {
+                if !areas.contains(area) {
+                    areas.push(area.clone());
+                }
+            }
         }
+        areas
+    }
+
+    /// Areas where anyone the tribute holds a grudge against was last seen.
+    pub fn threat_areas(&self) -> Vec<Area> {
+        self.grudges().iter()
+            .filter_map(|id| self.last_seen(*id))
+            .collect()
+    }
+
+    /// Pick somewhere worth walking to, anywhere in the arena.
+    /// Escaping danger comes first, then revenge, district mates, and remembered supplies.
+    /// Among goals of the same kind, the closest one wins.
+    pub fn choose_travel_goal(&self, tribute: &Tribute, closed_areas: &[Area]) -> Option<TravelGoal> {
+        let area = tribute.area.clone()?;
+        let game_id = tribute.game_id?;
+        let threat_areas = self.threat_areas();
+        let nearest = |goals: &[Area]| -> Option<Area> {
+            area.path_to_nearest(closed_areas, |a| goals.contains(a))
+                .and_then(|path| path.last().cloned())
+        };
+
+        // Get out of a closed area, or away from enemies when too hurt to fight
+        if closed_areas.contains(&area) || (tribute.health <= 50 && threat_areas.contains(&area)) {
+            let safety = area.path_to_nearest(closed_areas, |a| !threat_areas.contains(a))
+                .or_else(|| area.path_to_nearest(closed_areas, |_| true))
+                .and_then(|path| path.last().cloned());
+            return safety.map(TravelGoal::Safety);
+        }
+
+        // Go after someone they hold a grudge against, if they know where to look
+        if tribute.health > 50 {
+            let targets = self.grudges().into_iter()
+                .filter_map(|id| self.last_seen(id).map(|seen| (id, seen)))
+                .filter(|(_, seen)| *seen != area)
+                .filter(|(id, _)| Tribute::from(get_tribute_by_id(*id)).is_alive())
+                .collect::<Vec<(i32, Area)>>();
+            let areas = targets.iter().map(|(_, seen)| seen.clone()).collect::<Vec<Area>>();
+            if let Some(hunt_area) = nearest(&areas) {
+                let (target_id, _) = targets.iter().find(|(_, seen)| *seen == hunt_area)?;
+                return Some(TravelGoal::Hunt(*target_id, hunt_area));
+            }
+        }
+
+        // Regroup with a district mate
+        let game = get_game_by_id(game_id).expect("Game doesn't exist");
+        let mate_areas = get_all_living_tributes(&game).iter()
+            .filter(|t| t.district == tribute.district)
+            .filter(|t| Some(t.id) != tribute.id)
+            .filter_map(|t| t.area().map(Area::from))
+            .collect::<Vec<Area>>();
+        if !mate_areas.contains(&area) {
+            if let Some(mate_area) = nearest(&mate_areas) {
+                return Some(TravelGoal::Ally(mate_area));
+            }
+        }
+
+        // Head back to where they saw supplies lying around
+        let supply_areas = self.remembered_item_areas().into_iter()
+            .filter(|a| *a != area)
+            .filter(|a| !a.available_items(game_id).is_empty())
+            .collect::<Vec<Area>>();
+        nearest(&supply_areas).map(TravelGoal::Supplies)
     }
 
     pub fn set_preferred_action(&mut self, action: TributeAction, percentage: f64) {
@@ -70,6 +224,24 @@ impl TributeBrain {
 
         let _area = tribute.area.as_ref().unwrap();
 
+        // A broken mind doesn't weigh its options
+        match tribute.breakdown {
+            Some(Breakdown::Catatonia) => return TributeAction::None,
+            Some(Breakdown::Hallucination) => {
+                let actions = [TributeAction::Move(None), TributeAction::Hide, TributeAction::Attack, TributeAction::Rest];
+                return actions.choose(&mut thread_rng()).unwrap().clone();
+            },
+            Some(Breakdown::Paranoia) => {
+                // The count includes the tribute themselves
+                return match nearby_tributes {
+                    0..=1 if tribute.is_visible() => TributeAction::Hide,
+                    0..=1 => TributeAction::Rest,
+                    _ => TributeAction::Attack,
+                }
+            },
+            None => (),
+        }
+
         // If there is a preferred action, we should take it, assuming a positive roll
         if let Some(preferred_action) = self.preferred_action.clone() {
             if thread_rng().gen_bool(self.preferred_action_percentage) {
@@ -78,9 +250,25 @@ impl TributeBrain {
             }
         }
 
+        // Remembered enemies nearby: settle the score if healthy enough, otherwise get away
+        if !self.grudges().is_empty() {
+            let enemies_here = _area.tributes(tribute.game_id.unwrap()).iter()
+                .filter(|t| t.is_alive())
+                .filter(|t| self.holds_grudge_against(t.id.unwrap()))
+                .count();
+            if enemies_here > 0 {
+                return match tribute.health {
+                    51..=100 => TributeAction::Attack,
+                    _ => TributeAction::Move(None),
+                }
+            }
+        }
+
         // If there are items available, take one
         // Get the items for an area
-        let area_items = _area.available_items(tribute.game_id.unwrap());
+        let area_items = _area.available_items(tribute.game_id.unwrap()).into_iter()
+            .filter(|i| i.is_for(tribute))
+            .collect::<Vec<Item>>();
         // Items exist in the area?
         if !area_items.is_empty() {
             // Are there items with sufficient quantities?
@@ -96,73 +284,136 @@ impl TributeBrain {
             return TributeAction::UseItem(None);
         }
 
-        match &nearby_tributes {
-            0 => {
-                match tribute.health {
-                    // health is low, rest
-                    1..=20 => TributeAction::Rest,
-                    // health isn't great, hide
-                    // unless sanity is also low, then move
-                    21..=30 => {
-                        if tribute.sanity > 20 && tribute.is_visible() {
-                            TributeAction::Hide
-                        } else {
-                            TributeAction::Move(None)
-                        }
-                    },
-                    // health is good, move
-                    _ => {
-                        // If the tribute has movement, move
-                        match tribute.movement {
-                            0 => TributeAction::Rest,
-                            _ => TributeAction::Move(None),
-                        }
+        // A trap is no use carried around; leave it where someone else will come through
+        if nearby_tributes <= 1 && tribute.items().iter().any(|i| i.is_trap()) {
+            return TributeAction::SetTrap;
+        }
+
+        // Alone with the makings of something useful, make it
+        if nearby_tributes <= 1 && pick_recipe(tribute, &tribute.items(), !tribute.weapons().is_empty()).is_some() {
+            return TributeAction::Craft;
+        }
+
+        // Alone and empty-handed, live off the land, more so when hurt or going without.
+        // Anyone hurt worse than that is left to instinct.
+        let needs_supplies = matches!(tribute.status, TributeStatus::Starving | TributeStatus::Dehydrated);
+        let foraging = match tribute.health {
+            31..=70 => true,
+            71.. => thread_rng().gen_bool(0.3),
+            _ => false,
+        };
+        if nearby_tributes <= 1 && (needs_supplies || foraging) {
+            return best_survival_action(tribute, _area);
+        }
+
+        instinct(tribute, nearby_tributes)
+    }
+}
+
+/// A journey already under way is seen through, unless the tribute has to get to safety.
+pub(crate) fn keep_journey(journey: Option<TravelGoal>, goal: Option<TravelGoal>) -> Option<TravelGoal> {
+    match goal {
+        Some(TravelGoal::Safety(area)) => Some(TravelGoal::Safety(area)),
+        goal => journey.or(goal),
+    }
+}
+
+/// What a tribute does on gut feeling alone, given how many tributes are nearby.
+pub(crate) fn instinct(tribute: &Tribute, nearby_tributes: usize) -> TributeAction {
+    match &nearby_tributes {
+        0 => {
+            match tribute.health {
+                // health is low, rest
+                1..=20 => TributeAction::Rest,
+                // health isn't great, hide
+                // unless sanity is also low, then move
+                21..=30 => {
+                    if tribute.sanity > 20 && tribute.is_visible() {
+                        TributeAction::Hide
+                    } else {
+                        TributeAction::Move(None)
+                    }
+                },
+                // health is good, move
+                _ => {
+                    // If the tribute has movement, move
+                    match tribute.movement {
+                        0 => TributeAction::Rest,
+                        _ => TributeAction::Move(None),
                     }
                 }
             }
-            1..6 => {
-                // Enemies are nearby, attack depending on health
-                match tribute.health {
-                    // health is low, hide
-                    1..=5 => {
-                        if tribute.sanity > 20 && tribute.is_visible() {
-                            TributeAction::Hide
-                        } else {
-                            TributeAction::Attack
-                        }
-                    },
-                    // health isn't great, run away
-                    6..=10 => {
-                        if tribute.sanity > 20 {
-                            TributeAction::Move(None)
-                        } else {
-                            TributeAction::Attack
-                        }
-                    },
-                    // health is good, attack
-                    _ => TributeAction::Attack,
-                }
-            },
-            _ => {
-                // More than 5 enemies? Intelligence decides next move
-                let sense = 100 - tribute.intelligence.unwrap() - tribute.sanity;
-                match sense {
-                    // Too dumb to know better, attacks
-                    0..36 => TributeAction::Attack,
-                    // Smart enough to know better, hides
-                    85..101 => TributeAction::Hide,
-                    // Average intelligence, moves
-                    _ => TributeAction::Move(None),
-                }
+        }
+        1..6 => {
+            // Enemies are nearby, attack depending on health
+            match tribute.health {
+                // health is low, hide
+                1..=5 => {
+                    if tribute.sanity > 20 && tribute.is_visible() {
+                        TributeAction::Hide
+                    } else {
+                        TributeAction::Attack
+                    }
+                },
+                // health isn't great, run away
+                6..=10 => {
+                    if tribute.sanity > 20 {
+                        TributeAction::Move(None)
+                    } else {
+                        TributeAction::Attack
+                    }
+                },
+                // health is good, attack
+                _ => TributeAction::Attack,
+            }
+        },
+        _ => {
+            // More than 5 enemies? Intelligence decides next move
+            let sense = 100 - tribute.intelligence.unwrap() - tribute.sanity;
+            match sense {
+                // Too dumb to know better, attacks
+                0..36 => TributeAction::Attack,
+                // Smart enough to know better, hides
+                85..101 => TributeAction::Hide,
+                // Average intelligence, moves
+                _ => TributeAction::Move(None),
             }
         }
     }
 }
 
+/// How likely a tribute is to make for a feast. What they need pulls them in: a backpack
+/// with their name on it, treatment for their status, a weapon if they have none.
+/// The rivals they'd meet there hold them back, more so when they're badly hurt.
+pub(crate) fn feast_appeal(tribute: &Tribute, backpack: bool, armed: bool, rivals: usize) -> f64 {
+    let mut need = 0.5;
+    if backpack {
+        need += 0.2;
+    }
+    if tribute.status.remedy().is_some() {
+        need += if backpack { 0.2 } else { 0.1 };
+    }
+    if !armed {
+        need += 0.1;
+    }
+
+    let mut risk = (rivals as f64 * 0.03).min(0.3);
+    if tribute.health <= 30 {
+        risk *= 1.5;
+    }
+    (need - risk).clamp(0.05, 0.95)
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::areas::Area;
     use crate::tributes::actors::Tribute;
     use crate::tributes::actions::TributeAction;
+    use crate::tributes::breakdowns::Breakdown;
+    use crate::tributes::memories::{Memory, MemoryKind};
+    use crate::tributes::statuses::TributeStatus;
+    use super::{feast_appeal, keep_journey, TravelGoal};
+    use crate::tributes::survival::SURVIVAL_ACTIONS;
     #[test]
     fn decide_on_action_default() {
         // If there are no enemies nearby, the tribute should move
@@ -197,6 +448,21 @@ mod tests {
         assert_eq!(action, TributeAction::Rest);
     }
 
+    #[test]
+    fn decide_on_action_alone_and_badly_hurt() {
+        // A lone tribute in a bad way is left to instinct rather than going foraging
+        let mut tribute = Tribute::new("Katniss".to_string(), None, None);
+        tribute.id = Some(1);
+        tribute.game_id = Some(1);
+        tribute.takes_physical_damage(85);
+        assert!(!SURVIVAL_ACTIONS.contains(&tribute.brain.act(&tribute.clone(), 1, vec![])));
+        assert_eq!(tribute.brain.act(&tribute.clone(), 0, vec![]), TributeAction::Rest);
+
+        tribute.health = 25;
+        assert!(!SURVIVAL_ACTIONS.contains(&tribute.brain.act(&tribute.clone(), 1, vec![])));
+        assert_eq!(tribute.brain.act(&tribute.clone(), 0, vec![]), TributeAction::Hide);
+    }
+
     #[test]
     fn decide_on_action_enemies() {
         // If there are enemies nearby, the tribute should attack
@@ -207,6 +473,78 @@ mod tests {
         assert_eq!(action, TributeAction::Attack);
     }
 
+    #[test]
+    fn remembers_grudges_and_sightings() {
+        let mut tribute = Tribute::new("Katniss".to_string(), None, None);
+        tribute.brain.remember(Memory::new(MemoryKind::SawTribute, 2, Some(Area::Northeast), 1));
+        tribute.brain.remember(Memory::new(MemoryKind::AttackedBy, 2, Some(Area::Northeast), 1));
+        tribute.brain.remember(Memory::new(MemoryKind::SawTribute, 2, Some(Area::Southwest), 2));
+        tribute.brain.remember(Memory::new(MemoryKind::SawItem, 7, Some(Area::Cornucopia), 2));
+
+        assert_eq!(tribute.brain.grudges(), vec![2]);
+        assert!(tribute.brain.holds_grudge_against(2));
+        assert!(!tribute.brain.holds_grudge_against(3));
+        assert_eq!(tribute.brain.last_seen(2), Some(Area::Southwest));
+        assert_eq!(tribute.brain.threat_areas(), vec![Area::Southwest]);
+        assert_eq!(tribute.brain.remembered_item_areas(), vec![Area::Cornucopia]);
+        assert!(tribute.brain.remembers_item(7, &Area::Cornucopia));
+    }
+
+    #[test]
+    fn journeys_are_seen_through() {
+        let journey = Some(TravelGoal::Destination(Area::Southwest));
+        assert_eq!(keep_journey(journey.clone(), Some(TravelGoal::Ally(Area::Northeast))), journey);
+        assert_eq!(keep_journey(journey.clone(), None), journey);
+        assert_eq!(keep_journey(journey, Some(TravelGoal::Safety(Area::Northwest))), Some(TravelGoal::Safety(Area::Northwest)));
+        assert_eq!(keep_journey(None, Some(TravelGoal::Supplies(Area::Cornucopia))), Some(TravelGoal::Supplies(Area::Cornucopia)));
+    }
+
+    #[test]
+    fn no_grudges_against_the_dead() {
+        let mut tribute = Tribute::new("Katniss".to_string(), None, None);
+        tribute.brain.remember(Memory::new(MemoryKind::AttackedBy, 2, Some(Area::Northeast), 1));
+        tribute.brain.remember(Memory::new(MemoryKind::SawTribute, 2, Some(Area::Northeast), 1));
+        tribute.brain.remember(Memory::new(MemoryKind::KilledDistrictMate, 3, None, 1));
+        tribute.brain.remember(Memory::new(MemoryKind::SawTribute, 3, Some(Area::Southwest), 1));
+        tribute.brain.remember(Memory::new(MemoryKind::SawItem, 2, Some(Area::Cornucopia), 1));
+
+        tribute.brain.forgets_the_dead(&[1, 3]);
+        assert_eq!(tribute.brain.grudges(), vec![3]);
+        assert_eq!(tribute.brain.threat_areas(), vec![Area::Southwest]);
+        assert!(tribute.brain.remembers_item(2, &Area::Cornucopia));
+    }
+
+    #[test]
+    fn decide_on_action_breakdowns() {
+        let mut tribute = Tribute::new("Katniss".to_string(), None, None);
+        tribute.id = Some(1);
+        tribute.game_id = Some(1);
+
+        tribute.breakdown = Some(Breakdown::Catatonia);
+        assert_eq!(tribute.brain.act(&tribute.clone(), 2, vec![]), TributeAction::None);
+
+        tribute.breakdown = Some(Breakdown::Paranoia);
+        assert_eq!(tribute.brain.act(&tribute.clone(), 1, vec![]), TributeAction::Hide);
+        assert_eq!(tribute.brain.act(&tribute.clone(), 2, vec![]), TributeAction::Attack);
+        tribute.is_hidden = Some(true);
+        assert_eq!(tribute.brain.act(&tribute.clone(), 1, vec![]), TributeAction::Rest);
+    }
+
+    #[test]
+    fn feasts_weigh_need_against_risk() {
+        let mut tribute = Tribute::new("Katniss".to_string(), None, None);
+        let content = feast_appeal(&tribute, false, true, 10);
+
+        tribute.status = TributeStatus::Infected;
+        let needy = feast_appeal(&tribute, true, true, 10);
+        assert!(needy > content);
+        assert!(feast_appeal(&tribute, true, true, 2) > needy);
+
+        tribute.health = 20;
+        assert!(feast_appeal(&tribute, true, true, 10) < needy);
+        assert!(feast_appeal(&tribute, true, false, 0) <= 0.95);
+    }
+
     #[test]
     fn decide_on_action_enemies_low_health() {
         // If there are enemies nearby, but the tribute is low on health
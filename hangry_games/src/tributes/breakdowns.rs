@@ -0,0 +1,59 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// What happens to a tribute whose sanity gives out.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Breakdown {
+    Paranoia, // Trusts no one, lashes out at anyone nearby
+    Hallucination, // Can't tell what's real, acts at random
+    Catatonia, // Shuts down completely
+}
+
+impl Breakdown {
+    pub fn random() -> Breakdown {
+        let mut rng = rand::thread_rng();
+        match rng.gen_range(0..3) {
+            0 => Breakdown::Paranoia,
+            1 => Breakdown::Hallucination,
+            _ => Breakdown::Catatonia,
+        }
+    }
+}
+
+impl Display for Breakdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Breakdown::Paranoia => write!(f, "paranoia"),
+            Breakdown::Hallucination => write!(f, "hallucination"),
+            Breakdown::Catatonia => write!(f, "catatonia"),
+        }
+    }
+}
+
+impl FromStr for Breakdown {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "paranoia" | "paranoid" => Ok(Breakdown::Paranoia),
+            "hallucination" | "hallucinating" => Ok(Breakdown::Hallucination),
+            "catatonia" | "catatonic" => Ok(Breakdown::Catatonia),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breakdown_round_trip() {
+        for breakdown in [Breakdown::Paranoia, Breakdown::Hallucination, Breakdown::Catatonia] {
+            assert_eq!(Breakdown::from_str(breakdown.to_string().as_str()), Ok(breakdown));
+        }
+        assert_eq!(Breakdown::from_str("catatonic"), Ok(Breakdown::Catatonia));
+    }
+}
@@ -0,0 +1,55 @@
+use super::actors::Tribute;
+use super::statuses::TributeStatus;
+use rand::Rng;
+
+/// The statuses that pass from one tribute to another.
+pub const CONTAGIOUS: [TributeStatus; 2] = [TributeStatus::Sick, TributeStatus::Infected];
+
+/// An area takes this many new cases in one phase before the outbreak makes the news.
+pub const OUTBREAK_SIZE: usize = 2;
+
+pub fn is_contagious(status: &TributeStatus) -> bool {
+    CONTAGIOUS.contains(status)
+}
+
+/// How close `other` gets to someone who's ill in the same area. Allies share food, water
+/// and supplies, so they're closest; anyone hiding keeps their distance.
+pub fn proximity(other: &Tribute, allies: bool) -> f64 {
+    match (allies, other.is_visible()) {
+        (true, _) => 1.0,
+        (false, true) => 0.5,
+        (false, false) => 0.25,
+    }
+}
+
+/// How likely `other` is to catch it, between how close they get and how well their defense holds up.
+pub fn spread_chance(base: f64, proximity: f64, other: &Tribute) -> f64 {
+    let resistance = other.defense.unwrap_or(0).clamp(0, 100) as f64 / 200.0;
+    (base * proximity * (1.0 - resistance)).clamp(0.0, 1.0)
+}
+
+/// How many phases it takes to show, once caught.
+pub fn incubation_period(rng: &mut impl Rng) -> i32 {
+    rng.gen_range(1..=3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allies_and_the_unguarded_catch_it_first() {
+        let mut tribute = Tribute::new("Peeta".to_string(), None, None);
+        tribute.defense = Some(0);
+        assert_eq!(spread_chance(0.4, proximity(&tribute, true), &tribute), 0.4);
+        assert_eq!(spread_chance(0.4, proximity(&tribute, false), &tribute), 0.2);
+
+        tribute.is_hidden = Some(true);
+        assert_eq!(spread_chance(0.4, proximity(&tribute, false), &tribute), 0.1);
+        tribute.defense = Some(100);
+        assert_eq!(spread_chance(0.4, proximity(&tribute, false), &tribute), 0.05);
+
+        assert!(is_contagious(&TributeStatus::Sick));
+        assert!(!is_contagious(&TributeStatus::Wounded));
+    }
+}
@@ -0,0 +1,95 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use crate::areas::Area;
+use crate::models::TributeMemory as TributeMemoryModel;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MemoryKind {
+    SawTribute,
+    AttackedBy,
+    KilledDistrictMate,
+    SawItem,
+}
+
+impl MemoryKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            MemoryKind::SawTribute => "saw tribute",
+            MemoryKind::AttackedBy => "attacked by",
+            MemoryKind::KilledDistrictMate => "killed district mate",
+            MemoryKind::SawItem => "saw item",
+        }
+    }
+}
+
+impl Display for MemoryKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for MemoryKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "saw tribute" => Ok(MemoryKind::SawTribute),
+            "attacked by" => Ok(MemoryKind::AttackedBy),
+            "killed district mate" => Ok(MemoryKind::KilledDistrictMate),
+            "saw item" => Ok(MemoryKind::SawItem),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Something a tribute saw or suffered.
+/// `subject_id` is a tribute for every kind except `SawItem`, where it's an item.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Memory {
+    pub kind: MemoryKind,
+    pub subject_id: i32,
+    pub area: Option<Area>,
+    pub day: i32,
+}
+
+impl Memory {
+    pub fn new(kind: MemoryKind, subject_id: i32, area: Option<Area>, day: i32) -> Self {
+        Self { kind, subject_id, area, day }
+    }
+
+    /// A grudge is held against anyone who attacked the tribute or killed their district mate.
+    pub fn is_grudge(&self) -> bool {
+        matches!(self.kind, MemoryKind::AttackedBy | MemoryKind::KilledDistrictMate)
+    }
+}
+
+impl From<TributeMemoryModel> for Memory {
+    fn from(memory: TributeMemoryModel) -> Self {
+        Self {
+            kind: MemoryKind::from_str(memory.kind.as_str()).expect("Couldn't match that memory"),
+            subject_id: memory.subject_id,
+            area: memory.area_id.and_then(Area::get_by_id),
+            day: memory.day,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_kind_round_trip() {
+        for kind in [MemoryKind::SawTribute, MemoryKind::AttackedBy, MemoryKind::KilledDistrictMate, MemoryKind::SawItem] {
+            assert_eq!(MemoryKind::from_str(kind.as_str()), Ok(kind));
+        }
+    }
+
+    #[test]
+    fn grudges() {
+        assert!(Memory::new(MemoryKind::AttackedBy, 1, None, 1).is_grudge());
+        assert!(Memory::new(MemoryKind::KilledDistrictMate, 1, None, 1).is_grudge());
+        assert!(!Memory::new(MemoryKind::SawTribute, 1, Some(Area::Cornucopia), 1).is_grudge());
+        assert!(!Memory::new(MemoryKind::SawItem, 1, Some(Area::Cornucopia), 1).is_grudge());
+    }
+}
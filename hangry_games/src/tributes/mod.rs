@@ -2,3 +2,10 @@ pub mod actors;
 pub mod actions;
 pub mod statuses;
 pub mod brains;
+pub mod memories;
+pub mod breakdowns;
+pub mod pronouns;
+pub mod rosters;
+pub mod point_buy;
+pub mod survival;
+pub mod contagion;
@@ -0,0 +1,288 @@
+use crate::tributes::actors::Tribute;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use strum::{EnumIter, IntoEnumIterator};
+
+/// A stat that costs points when a tribute is built by hand. Every point costs one.
+#[derive(Clone, Copy, Debug, EnumIter, Eq, Hash, PartialEq)]
+pub enum Stat {
+    Bravery,
+    Loyalty,
+    Speed,
+    Intelligence,
+    Persuasion,
+    Luck,
+    Strength,
+    Defense,
+    Dexterity,
+}
+
+impl Stat {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Stat::Bravery => "bravery",
+            Stat::Loyalty => "loyalty",
+            Stat::Speed => "speed",
+            Stat::Intelligence => "intelligence",
+            Stat::Persuasion => "persuasion",
+            Stat::Luck => "luck",
+            Stat::Strength => "strength",
+            Stat::Defense => "defense",
+            Stat::Dexterity => "dexterity",
+        }
+    }
+
+    /// The highest a random roll goes, and the default cap.
+    pub fn max(&self) -> i32 {
+        match self {
+            Stat::Strength | Stat::Defense => 50,
+            _ => 100,
+        }
+    }
+
+    pub fn get(&self, tribute: &Tribute) -> i32 {
+        let value = match self {
+            Stat::Bravery => tribute.bravery,
+            Stat::Loyalty => tribute.loyalty,
+            Stat::Speed => tribute.speed,
+            Stat::Intelligence => tribute.intelligence,
+            Stat::Persuasion => tribute.persuasion,
+            Stat::Luck => tribute.luck,
+            Stat::Strength => tribute.strength,
+            Stat::Defense => tribute.defense,
+            Stat::Dexterity => tribute.dexterity,
+        };
+        value.unwrap_or(0)
+    }
+
+    pub fn set(&self, tribute: &mut Tribute, value: i32) {
+        let field = match self {
+            Stat::Bravery => &mut tribute.bravery,
+            Stat::Loyalty => &mut tribute.loyalty,
+            Stat::Speed => &mut tribute.speed,
+            Stat::Intelligence => &mut tribute.intelligence,
+            Stat::Persuasion => &mut tribute.persuasion,
+            Stat::Luck => &mut tribute.luck,
+            Stat::Strength => &mut tribute.strength,
+            Stat::Defense => &mut tribute.defense,
+            Stat::Dexterity => &mut tribute.dexterity,
+        };
+        *field = Some(value);
+    }
+}
+
+impl Display for Stat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Stat {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Stat::iter().find(|stat| stat.as_str() == s.trim().to_lowercase()).ok_or(())
+    }
+}
+
+/// How many points a hand-built tribute gets, and how high each stat may go.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointBuy {
+    pub budget: i32,
+    pub caps: HashMap<Stat, i32>,
+}
+
+impl PointBuy {
+    pub fn new(budget: i32) -> PointBuy {
+        PointBuy { budget, caps: Stat::iter().map(|stat| (stat, stat.max())).collect() }
+    }
+
+    pub fn cap(&self, stat: Stat) -> i32 {
+        self.caps.get(&stat).copied().unwrap_or(stat.max())
+    }
+
+    pub fn spent(&self, tribute: &Tribute) -> i32 {
+        Stat::iter().map(|stat| stat.get(tribute)).sum()
+    }
+
+    pub fn remaining(&self, tribute: &Tribute) -> i32 {
+        self.budget - self.spent(tribute)
+    }
+
+    /// Every stat out of bounds, then the budget if it's overspent.
+    pub fn problems(&self, tribute: &Tribute) -> Vec<String> {
+        let mut problems = Stat::iter()
+            .filter(|stat| !(1..=self.cap(*stat)).contains(&stat.get(tribute)))
+            .map(|stat| format!("{} must be between 1 and {}, got {}", stat, self.cap(stat), stat.get(tribute)))
+            .collect::<Vec<String>>();
+        if self.spent(tribute) > self.budget {
+            problems.push(format!("spends {} points, the budget is {}", self.spent(tribute), self.budget));
+        }
+        problems
+    }
+
+    pub fn check(&self, tribute: &Tribute) -> Result<(), Vec<String>> {
+        let problems = self.problems(tribute);
+        if problems.is_empty() { Ok(()) } else { Err(problems) }
+    }
+
+    /// Like `check`, but only once a stat has been touched, so renaming a tribute
+    /// rolled outside the point-buy (e.g. by `fill_tributes`) still saves.
+    pub fn check_edit(&self, before: &Tribute, after: &Tribute) -> Result<(), Vec<String>> {
+        if Stat::iter().all(|stat| stat.get(before) == stat.get(after)) {
+            return Ok(());
+        }
+        self.check(after)
+    }
+
+    /// Resets the weighted stats to 1, then spends what's left of the budget on them
+    /// in proportion to their weights, as far as their caps allow. Other stats are untouched.
+    pub fn allocate(&self, tribute: &mut Tribute, weights: &[(Stat, i32)]) {
+        for (stat, _) in weights {
+            stat.set(tribute, 1);
+        }
+        let mut left = self.remaining(tribute);
+        loop {
+            let open = weights.iter()
+                .filter(|(stat, weight)| *weight > 0 && stat.get(tribute) < self.cap(*stat))
+                .copied()
+                .collect::<Vec<(Stat, i32)>>();
+            if open.is_empty() || left <= 0 {
+                break;
+            }
+
+            let total_weight: i32 = open.iter().map(|(_, weight)| weight).sum();
+            let mut given = 0;
+            for (stat, weight) in &open {
+                let share = (left * weight / total_weight).min(self.cap(*stat) - stat.get(tribute));
+                stat.set(tribute, stat.get(tribute) + share);
+                given += share;
+            }
+            // Rounding leaves a few points over, hand them out one at a time
+            if given == 0 {
+                for (stat, _) in open.iter().take(left as usize) {
+                    stat.set(tribute, stat.get(tribute) + 1);
+                    given += 1;
+                }
+            }
+            left -= given;
+        }
+    }
+}
+
+/// Ready-made builds for players who don't want to spend points one by one.
+#[derive(Clone, Copy, Debug, Default, EnumIter, Eq, PartialEq)]
+pub enum Preset {
+    #[default]
+    Balanced,
+    Brawler,
+    Sneak,
+    Survivor,
+}
+
+impl Preset {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Preset::Balanced => "balanced",
+            Preset::Brawler => "brawler",
+            Preset::Sneak => "sneak",
+            Preset::Survivor => "survivor",
+        }
+    }
+
+    fn weights(&self) -> Vec<(Stat, i32)> {
+        use Stat::*;
+        match self {
+            Preset::Balanced => Stat::iter().map(|stat| (stat, 1)).collect(),
+            Preset::Brawler => vec![
+                (Strength, 5), (Defense, 5), (Bravery, 4), (Loyalty, 2), (Speed, 2),
+                (Dexterity, 2), (Intelligence, 1), (Persuasion, 1), (Luck, 1),
+            ],
+            Preset::Sneak => vec![
+                (Dexterity, 5), (Speed, 5), (Intelligence, 4), (Luck, 2), (Bravery, 1),
+                (Loyalty, 1), (Persuasion, 1), (Strength, 1), (Defense, 1),
+            ],
+            Preset::Survivor => vec![
+                (Intelligence, 4), (Luck, 4), (Defense, 4), (Dexterity, 3), (Loyalty, 2),
+                (Speed, 2), (Persuasion, 2), (Bravery, 1), (Strength, 1),
+            ],
+        }
+    }
+
+    /// Rebuilds all of the tribute's stats within `point_buy`.
+    pub fn apply(&self, tribute: &mut Tribute, point_buy: &PointBuy) {
+        point_buy.allocate(tribute, &self.weights());
+    }
+}
+
+impl Display for Preset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Preset {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Preset::iter().find(|preset| preset.as_str() == s.trim().to_lowercase()).ok_or(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_spend_the_budget() {
+        for budget in [9, 200, 450, 800] {
+            let point_buy = PointBuy::new(budget);
+            for preset in Preset::iter() {
+                let mut tribute = Tribute::new("Katniss".to_string(), Some(12), None);
+                preset.apply(&mut tribute, &point_buy);
+                assert_eq!(point_buy.check(&tribute), Ok(()), "{} at {}", preset, budget);
+                assert_eq!(point_buy.spent(&tribute), budget, "{} at {}", preset, budget);
+            }
+        }
+    }
+
+    #[test]
+    fn brawlers_hit_harder_than_sneaks() {
+        let point_buy = PointBuy::new(450);
+        let mut brawler = Tribute::new("Cato".to_string(), Some(2), None);
+        let mut sneak = Tribute::new("Foxface".to_string(), Some(5), None);
+        Preset::Brawler.apply(&mut brawler, &point_buy);
+        Preset::Sneak.apply(&mut sneak, &point_buy);
+        assert!(brawler.strength > sneak.strength);
+        assert!(sneak.dexterity > brawler.dexterity);
+    }
+
+    #[test]
+    fn maxing_everything_is_over_budget() {
+        let point_buy = PointBuy::new(450);
+        let mut tribute = Tribute::new("Thresh".to_string(), Some(11), None);
+        for stat in Stat::iter() {
+            stat.set(&mut tribute, stat.max());
+        }
+        assert_eq!(point_buy.problems(&tribute), vec!["spends 800 points, the budget is 450"]);
+
+        tribute.strength = Some(60);
+        assert_eq!(point_buy.problems(&tribute)[0], "strength must be between 1 and 50, got 60");
+    }
+
+    #[test]
+    fn renaming_an_over_budget_tribute_saves() {
+        let point_buy = PointBuy::new(450);
+        let mut rolled = Tribute::new("Thresh".to_string(), Some(11), None);
+        for stat in Stat::iter() {
+            stat.set(&mut rolled, stat.max());
+        }
+        let mut renamed = rolled.clone();
+        renamed.name = "Rue".to_string();
+        assert_eq!(point_buy.check_edit(&rolled, &renamed), Ok(()));
+
+        renamed.luck = Some(99);
+        assert_eq!(point_buy.check_edit(&rolled, &renamed), Err(vec!["spends 799 points, the budget is 450".to_string()]));
+    }
+}
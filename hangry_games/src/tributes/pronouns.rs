@@ -0,0 +1,146 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// How a tribute is referred to in the game log. Tributes without a set use they/them.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Pronouns {
+    #[default]
+    They,
+    She,
+    He,
+    Custom {
+        subject: String,
+        object: String,
+        possessive: String,
+        reflexive: String,
+    },
+}
+
+impl Pronouns {
+    /// She or he, for filling a district's pair.
+    pub fn random_binary() -> Pronouns {
+        let mut rng = rand::thread_rng();
+        if rng.gen_bool(0.5) { Pronouns::She } else { Pronouns::He }
+    }
+
+    /// The other half of a one-girl-one-boy pair, if there is one.
+    pub fn counterpart(&self) -> Option<Pronouns> {
+        match self {
+            Pronouns::She => Some(Pronouns::He),
+            Pronouns::He => Some(Pronouns::She),
+            _ => None,
+        }
+    }
+
+    /// What message catalogs select on: "she", "he", "they" or "custom".
+    pub fn selector(&self) -> &str {
+        match self {
+            Pronouns::They => "they",
+            Pronouns::She => "she",
+            Pronouns::He => "he",
+            Pronouns::Custom { .. } => "custom",
+        }
+    }
+
+    pub fn subject(&self) -> &str {
+        match self {
+            Pronouns::They => "they",
+            Pronouns::She => "she",
+            Pronouns::He => "he",
+            Pronouns::Custom { subject, .. } => subject,
+        }
+    }
+
+    pub fn object(&self) -> &str {
+        match self {
+            Pronouns::They => "them",
+            Pronouns::She => "her",
+            Pronouns::He => "him",
+            Pronouns::Custom { object, .. } => object,
+        }
+    }
+
+    pub fn possessive(&self) -> &str {
+        match self {
+            Pronouns::They => "their",
+            Pronouns::She => "her",
+            Pronouns::He => "his",
+            Pronouns::Custom { possessive, .. } => possessive,
+        }
+    }
+
+    pub fn reflexive(&self) -> &str {
+        match self {
+            Pronouns::They => "themself",
+            Pronouns::She => "herself",
+            Pronouns::He => "himself",
+            Pronouns::Custom { reflexive, .. } => reflexive,
+        }
+    }
+}
+
+impl Display for Pronouns {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Pronouns::They => write!(f, "they/them"),
+            Pronouns::She => write!(f, "she/her"),
+            Pronouns::He => write!(f, "he/him"),
+            Pronouns::Custom { subject, object, possessive, reflexive } => {
+                write!(f, "{}/{}/{}/{}", subject, object, possessive, reflexive)
+            }
+        }
+    }
+}
+
+impl FromStr for Pronouns {
+    type Err = ();
+
+    /// Accepts "she/her", "he", "they/them" and so on, or a custom
+    /// "subject/object/possessive/reflexive" set like "xe/xem/xyr/xemself".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "they" | "they/them" | "they/them/their" => Ok(Pronouns::They),
+            "she" | "she/her" | "she/her/her" => Ok(Pronouns::She),
+            "he" | "he/him" | "he/him/his" => Ok(Pronouns::He),
+            custom => {
+                let parts = custom.split('/').map(str::trim).collect::<Vec<&str>>();
+                match parts.as_slice() {
+                    [subject, object, possessive, reflexive] if parts.iter().all(|p| !p.is_empty()) => {
+                        Ok(Pronouns::Custom {
+                            subject: subject.to_string(),
+                            object: object.to_string(),
+                            possessive: possessive.to_string(),
+                            reflexive: reflexive.to_string(),
+                        })
+                    }
+                    _ => Err(()),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pronouns_round_trip() {
+        let custom = Pronouns::from_str("xe/xem/xyr/xemself").unwrap();
+        assert_eq!(custom.possessive(), "xyr");
+        for pronouns in [Pronouns::They, Pronouns::She, Pronouns::He, custom] {
+            assert_eq!(Pronouns::from_str(&pronouns.to_string()), Ok(pronouns));
+        }
+        assert_eq!(Pronouns::from_str("She"), Ok(Pronouns::She));
+        assert_eq!(Pronouns::from_str("xe/xem"), Err(()));
+    }
+
+    #[test]
+    fn counterparts() {
+        assert_eq!(Pronouns::She.counterpart(), Some(Pronouns::He));
+        assert_eq!(Pronouns::He.counterpart(), Some(Pronouns::She));
+        assert_eq!(Pronouns::They.counterpart(), None);
+    }
+}
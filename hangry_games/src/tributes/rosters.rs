@@ -0,0 +1,357 @@
+use crate::models::{create_tribute, get_all_living_tributes, get_game_tributes, get_games, Game};
+use crate::tributes::actors::Tribute;
+use crate::tributes::point_buy::{PointBuy, Stat};
+use crate::tributes::pronouns::Pronouns;
+use rand::prelude::SliceRandom;
+use rand::thread_rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::path::Path;
+use std::str::FromStr;
+use strum::IntoEnumIterator;
+
+/// One tribute's full record in a roster file.
+/// Blank stats share whatever the game's stat budget has left after the filled-in ones.
+/// A row with every stat filled in, like an exported one, is taken as it is.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RosterRow {
+    pub name: String,
+    pub real_name: Option<String>,
+    pub district: Option<i32>,
+    pub avatar: Option<String>,
+    pub pronouns: Option<String>,
+    pub health: Option<i32>,
+    pub sanity: Option<i32>,
+    pub movement: Option<i32>,
+    pub bravery: Option<i32>,
+    pub loyalty: Option<i32>,
+    pub speed: Option<i32>,
+    pub intelligence: Option<i32>,
+    pub persuasion: Option<i32>,
+    pub luck: Option<i32>,
+    pub strength: Option<i32>,
+    pub defense: Option<i32>,
+    pub dexterity: Option<i32>,
+    pub stealth: Option<i32>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RosterFormat {
+    Csv,
+    Json,
+}
+
+impl RosterFormat {
+    /// Picked from the file's extension.
+    pub fn from_path(path: &Path) -> Option<RosterFormat> {
+        match path.extension()?.to_str()?.to_lowercase().as_str() {
+            "csv" => Some(RosterFormat::Csv),
+            "json" => Some(RosterFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+/// A problem with one row of a roster, numbered from 1 after any header.
+/// Row 0 is the file as a whole.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RowError {
+    pub row: usize,
+    pub message: String,
+}
+
+impl Display for RowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.row {
+            0 => write!(f, "{}", self.message),
+            row => write!(f, "row {}: {}", row, self.message),
+        }
+    }
+}
+
+impl RosterRow {
+    pub fn from_tribute(tribute: &Tribute) -> RosterRow {
+        RosterRow {
+            name: tribute.name.clone(),
+            real_name: tribute.real_name.clone(),
+            district: Some(tribute.district),
+            avatar: tribute.avatar.clone(),
+            pronouns: tribute.pronouns.as_ref().map(|p| p.to_string()),
+            health: Some(tribute.health),
+            sanity: Some(tribute.sanity),
+            movement: Some(tribute.movement),
+            bravery: tribute.bravery,
+            loyalty: tribute.loyalty,
+            speed: tribute.speed,
+            intelligence: tribute.intelligence,
+            persuasion: tribute.persuasion,
+            luck: tribute.luck,
+            strength: tribute.strength,
+            defense: tribute.defense,
+            dexterity: tribute.dexterity,
+            stealth: tribute.stealth,
+        }
+    }
+
+    /// Every field that's out of range, in the order they appear in the file.
+    /// `districts` is how many districts the game reaps from.
+    pub fn problems(&self, districts: i32) -> Vec<String> {
+        let mut problems = vec![];
+        if self.name.trim().is_empty() {
+            problems.push("name is blank".to_string());
+        }
+        if let Some(pronouns) = &self.pronouns {
+            if !pronouns.trim().is_empty() && Pronouns::from_str(pronouns).is_err() {
+                problems.push(format!("unknown pronouns {}", pronouns));
+            }
+        }
+        let ranges = [
+            ("district", self.district, 1, districts),
+            ("health", self.health, 1, 100),
+            ("sanity", self.sanity, 1, 100),
+            ("movement", self.movement, 0, 100),
+            ("bravery", self.bravery, 1, 100),
+            ("loyalty", self.loyalty, 1, 100),
+            ("speed", self.speed, 1, 100),
+            ("intelligence", self.intelligence, 1, 100),
+            ("persuasion", self.persuasion, 1, 100),
+            ("luck", self.luck, 1, 100),
+            ("strength", self.strength, 1, 50),
+            ("defense", self.defense, 1, 50),
+            ("dexterity", self.dexterity, 1, 100),
+            ("stealth", self.stealth, 0, 100),
+        ];
+        for (field, value, min, max) in ranges {
+            if let Some(value) = value.filter(|v| !(min..=max).contains(v)) {
+                problems.push(format!("{} must be between {} and {}, got {}", field, min, max, value));
+            }
+        }
+        problems
+    }
+
+    /// Copies every filled-in field onto `tribute`, then spends the rest of the budget on the blanks.
+    fn apply(&self, tribute: &mut Tribute, point_buy: &PointBuy) {
+        tribute.name = self.name.trim().to_string();
+        tribute.real_name = self.real_name.clone().filter(|n| !n.trim().is_empty());
+        tribute.avatar = self.avatar.clone().filter(|a| !a.trim().is_empty());
+        tribute.pronouns = self.pronouns.as_ref().and_then(|p| Pronouns::from_str(p).ok());
+        tribute.district = self.district.unwrap_or(tribute.district);
+        tribute.health = self.health.unwrap_or(tribute.health);
+        tribute.sanity = self.sanity.unwrap_or(tribute.sanity);
+        tribute.movement = self.movement.unwrap_or(tribute.movement);
+        tribute.stealth = self.stealth.or(tribute.stealth);
+
+        let mut blanks = vec![];
+        for stat in Stat::iter() {
+            match self.stat(stat) {
+                Some(value) => stat.set(tribute, value),
+                None => blanks.push((stat, 1)),
+            }
+        }
+        point_buy.allocate(tribute, &blanks);
+    }
+
+    /// Where the row overspends the budget or a cap, if it leaves any stat to the point-buy.
+    fn budget_problems(&self, point_buy: &PointBuy) -> Vec<String> {
+        if Stat::iter().all(|stat| self.stat(stat).is_some()) {
+            return vec![];
+        }
+        let mut tribute = Tribute::new(self.name.clone(), None, None);
+        self.apply(&mut tribute, point_buy);
+        point_buy.problems(&tribute)
+    }
+
+    fn stat(&self, stat: Stat) -> Option<i32> {
+        match stat {
+            Stat::Bravery => self.bravery,
+            Stat::Loyalty => self.loyalty,
+            Stat::Speed => self.speed,
+            Stat::Intelligence => self.intelligence,
+            Stat::Persuasion => self.persuasion,
+            Stat::Luck => self.luck,
+            Stat::Strength => self.strength,
+            Stat::Defense => self.defense,
+            Stat::Dexterity => self.dexterity,
+        }
+    }
+}
+
+/// Parses a roster for a game with `districts` districts, reporting every row that
+/// can't be read or is out of range.
+pub fn read_roster(contents: &str, format: RosterFormat, districts: i32) -> Result<Vec<RosterRow>, Vec<RowError>> {
+    let parsed: Vec<Result<RosterRow, String>> = match format {
+        RosterFormat::Csv => {
+            let mut reader = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(contents.as_bytes());
+            reader.deserialize().map(|row| row.map_err(|e| e.to_string())).collect()
+        }
+        RosterFormat::Json => {
+            let values: Vec<serde_json::Value> = serde_json::from_str(contents)
+                .map_err(|e| vec![RowError { row: 0, message: e.to_string() }])?;
+            values.into_iter().map(|value| serde_json::from_value(value).map_err(|e| e.to_string())).collect()
+        }
+    };
+
+    let mut rows = vec![];
+    let mut errors = vec![];
+    for (index, row) in parsed.into_iter().enumerate() {
+        match row {
+            Ok(row) => {
+                errors.extend(row.problems(districts).into_iter().map(|message| RowError { row: index + 1, message }));
+                rows.push(row);
+            }
+            Err(message) => errors.push(RowError { row: index + 1, message }),
+        }
+    }
+    if errors.is_empty() { Ok(rows) } else { Err(errors) }
+}
+
+pub fn write_roster(rows: &[RosterRow], format: RosterFormat) -> String {
+    match format {
+        RosterFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(vec![]);
+            for row in rows {
+                writer.serialize(row).expect("Error writing roster row");
+            }
+            String::from_utf8(writer.into_inner().expect("Error writing roster")).expect("Roster isn't UTF-8")
+        }
+        RosterFormat::Json => serde_json::to_string_pretty(rows).expect("Error writing roster"),
+    }
+}
+
+/// Adds every row to `game` as a new tribute. Nothing is added unless every row fits,
+/// including within the game's stat budget if it leaves stats blank.
+pub fn import_roster(game: &Game, rows: &[RosterRow]) -> Result<Vec<Tribute>, Vec<RowError>> {
+    let roster_size = game.roster_size.max(0) as usize;
+    let room = roster_size - get_game_tributes(game).len().min(roster_size);
+    if rows.len() > room {
+        return Err(vec![RowError {
+            row: room + 1,
+            message: format!("{} only has room for {} more tributes", game.name, room),
+        }]);
+    }
+
+    let point_buy = game.point_buy();
+    let errors = rows.iter().enumerate()
+        .flat_map(|(index, row)| {
+            row.budget_problems(&point_buy).into_iter().map(move |message| RowError { row: index + 1, message })
+        })
+        .collect::<Vec<RowError>>();
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let mut tributes = vec![];
+    for row in rows {
+        let mut model = create_tribute(row.name.trim(), None);
+        model.try_set_game(game).expect("Error adding tribute to game");
+        model.game_id = Some(game.id);
+
+        let mut tribute = Tribute::from(model);
+        row.apply(&mut tribute, &point_buy);
+        tribute.update(tribute.clone().into());
+        tributes.push(tribute);
+    }
+    Ok(tributes)
+}
+
+/// Brings back up to `count` victors of finished games as tributes in `game`. They keep
+/// their stats, looks and district but come back rested. Returns the tributes reaped.
+pub fn reap_victors(game: &Game, count: usize) -> Vec<Tribute> {
+    let mut names = get_game_tributes(game).into_iter().map(|t| t.name).collect::<HashSet<String>>();
+    let mut victors = get_games().iter()
+        .filter(|g| g.ended_at.is_some() && g.id != game.id)
+        .flat_map(get_all_living_tributes)
+        .filter(|victor| names.insert(victor.name.clone()))
+        .map(Tribute::from)
+        .collect::<Vec<Tribute>>();
+    victors.shuffle(&mut thread_rng());
+
+    let point_buy = game.point_buy();
+    victors.into_iter().take(count).map(|victor| {
+        let mut row = RosterRow::from_tribute(&victor);
+        row.health = None;
+        row.sanity = None;
+        row.movement = None;
+        row.district = row.district.filter(|district| (1..=game.districts).contains(district));
+
+        let mut model = create_tribute(&victor.name, None);
+        model.try_set_game(game).expect("Error adding tribute to game");
+        model.game_id = Some(game.id);
+
+        let mut tribute = Tribute::from(model);
+        row.apply(&mut tribute, &point_buy);
+        tribute.update(tribute.clone().into());
+        tribute
+    }).collect()
+}
+
+pub fn export_roster(game: &Game) -> Vec<RosterRow> {
+    get_game_tributes(game).into_iter()
+        .map(|tribute| RosterRow::from_tribute(&Tribute::from(tribute)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_both_formats() {
+        let mut tribute = Tribute::new("Katniss".to_string(), Some(12), None);
+        tribute.pronouns = Some(Pronouns::She);
+        tribute.real_name = Some("Katniss Everdeen".to_string());
+        let rows = vec![RosterRow::from_tribute(&tribute)];
+        for format in [RosterFormat::Csv, RosterFormat::Json] {
+            let written = write_roster(&rows, format);
+            assert_eq!(read_roster(&written, format, 12), Ok(rows.clone()));
+        }
+    }
+
+    #[test]
+    fn rolled_tributes_come_back_over_budget() {
+        let mut tribute = Tribute::new("Thresh".to_string(), Some(11), None);
+        tribute.strength = Some(50);
+        tribute.defense = Some(50);
+        tribute.speed = Some(100);
+        tribute.bravery = Some(100);
+        tribute.luck = Some(100);
+        let point_buy = PointBuy::new(450);
+        assert!(point_buy.spent(&tribute) > 450);
+
+        let rows = vec![RosterRow::from_tribute(&tribute)];
+        let read = read_roster(&write_roster(&rows, RosterFormat::Csv), RosterFormat::Csv, 12).unwrap();
+        assert_eq!(read, rows);
+        assert!(read[0].budget_problems(&point_buy).is_empty());
+
+        let blank = RosterRow { dexterity: None, ..rows[0].clone() };
+        assert!(!blank.budget_problems(&point_buy).is_empty());
+    }
+
+    #[test]
+    fn blank_stats_are_optional() {
+        let rows = read_roster("name,district,strength\nRue,11,\n", RosterFormat::Csv, 12).unwrap();
+        assert_eq!(rows[0].district, Some(11));
+        assert_eq!(rows[0].strength, None);
+    }
+
+    #[test]
+    fn reports_row_level_errors() {
+        let csv = "name,district,strength,luck\nCato,2,40,50\n,13,70,50\nGlimmer,1,ten,50\n";
+        let errors = read_roster(csv, RosterFormat::Csv, 12).unwrap_err();
+        let messages = errors.iter().map(|e| e.to_string()).collect::<Vec<String>>();
+        assert_eq!(messages[0], "row 2: name is blank");
+        assert_eq!(messages[1], "row 2: district must be between 1 and 12, got 13");
+        assert_eq!(messages[2], "row 2: strength must be between 1 and 50, got 70");
+        assert!(messages[3].starts_with("row 3: "));
+        assert_eq!(messages.len(), 4);
+    }
+
+    #[test]
+    fn districts_follow_the_game() {
+        let csv = "name,district\nFinnick,4\nJohanna,7\n";
+        assert!(read_roster(csv, RosterFormat::Csv, 12).is_ok());
+        let errors = read_roster(csv, RosterFormat::Csv, 4).unwrap_err();
+        assert_eq!(errors[0].to_string(), "row 2: district must be between 1 and 4, got 7");
+    }
+}
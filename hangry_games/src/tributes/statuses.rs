@@ -2,10 +2,13 @@ use std::fmt::Display;
 use std::str::FromStr;
 use dioxus::dioxus_core::AttributeValue;
 use crate::animals::Animal;
+use crate::i18n::{translate, Locale};
+use fluent_bundle::FluentArgs;
 use dioxus::prelude::IntoAttributeValue;
+use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
-#[derive(Clone, Debug, Default, EnumIter, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Default, EnumIter, Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub enum TributeStatus {
     #[default]
     Healthy,
@@ -70,6 +73,34 @@ impl FromStr for TributeStatus {
     }
 }
 
+impl TributeStatus {
+    /// The status in `locale`.
+    pub fn localized(&self, locale: &Locale) -> String {
+        match self {
+            TributeStatus::Mauled(animal) => {
+                let mut args = FluentArgs::new();
+                args.set("animal", animal.localized(locale, 1));
+                translate(locale, "status-mauled", Some(&args))
+            }
+            _ => translate(locale, &format!("status-{}", self.to_string().replace(' ', "-")), None),
+        }
+    }
+
+    /// The supply that treats the status, if anything does.
+    pub fn remedy(&self) -> Option<&str> {
+        match self {
+            TributeStatus::Wounded | TributeStatus::Broken | TributeStatus::Mauled(_) => Some("bandages"),
+            TributeStatus::Sick | TributeStatus::Infected | TributeStatus::Electrocuted | TributeStatus::Drowned => Some("medicine"),
+            TributeStatus::Poisoned => Some("antidote"),
+            TributeStatus::Burned => Some("burn ointment"),
+            TributeStatus::Dehydrated | TributeStatus::Overheated => Some("water"),
+            TributeStatus::Starving => Some("food"),
+            TributeStatus::Frozen => Some("sleeping bag"),
+            _ => None,
+        }
+    }
+}
+
 impl Display for TributeStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
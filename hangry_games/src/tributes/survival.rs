@@ -0,0 +1,120 @@
+use super::actions::TributeAction;
+use super::actors::Tribute;
+use super::statuses::TributeStatus;
+use crate::areas::Area;
+use crate::crafting::MATERIALS;
+use rand::prelude::SliceRandom;
+use rand::Rng;
+
+/// Anything that treats starvation the same as plain food does.
+pub const FOODS: [&str; 9] = ["food", "berries", "roots", "nuts", "rabbit", "groosling", "squirrel", "fish", "meat"];
+
+/// The ways a tribute can live off the land.
+pub const SURVIVAL_ACTIONS: [TributeAction; 4] = [
+    TributeAction::Forage,
+    TributeAction::Hunt,
+    TributeAction::Fish,
+    TributeAction::FindWater,
+];
+
+/// What a tribute comes back with after trying to live off the land.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Haul {
+    /// Something to eat or drink, by name, and how much health it gives back.
+    Provisions(String, i32),
+    /// Something to craft with, by name, and how many of it.
+    Materials(String, i32),
+    /// It went wrong, and they're worse off for trying.
+    Mishap(TributeStatus),
+    Nothing,
+}
+
+/// Knowing which berries are safe and where water runs takes brains; hunting and fishing take a steady hand.
+pub fn skill(action: &TributeAction, tribute: &Tribute) -> i32 {
+    match action {
+        TributeAction::Forage | TributeAction::FindWater => tribute.intelligence.unwrap_or(0),
+        _ => tribute.dexterity.unwrap_or(0),
+    }
+}
+
+/// How likely the tribute is to come back with something, between their skill and what the area has to offer.
+pub fn success_chance(action: &TributeAction, tribute: &Tribute, resources: i32) -> f64 {
+    ((skill(action, tribute) + resources) as f64 / 200.0).clamp(0.05, 0.95)
+}
+
+/// The survival action the tribute is likeliest to pull off in `area`, or what they need most if it's
+/// food or water.
+pub fn best_survival_action(tribute: &Tribute, area: &Area) -> TributeAction {
+    match tribute.status {
+        TributeStatus::Dehydrated | TributeStatus::Overheated => return TributeAction::FindWater,
+        _ => (),
+    }
+    SURVIVAL_ACTIONS.iter()
+        .filter(|action| tribute.status != TributeStatus::Starving || **action != TributeAction::FindWater)
+        .max_by_key(|action| skill(action, tribute) + area.resources(action))
+        .cloned()
+        .unwrap()
+}
+
+/// Rolls for what the tribute finds, which when foraging might be something to craft with.
+/// Failing might just mean coming back empty-handed, but the less skilled they are,
+/// the likelier it is to hurt them.
+pub fn gather(action: &TributeAction, tribute: &Tribute, resources: i32, rng: &mut impl Rng) -> Haul {
+    if rng.gen_bool(success_chance(action, tribute, resources)) {
+        // Not everything foraged is for eating
+        if *action == TributeAction::Forage && rng.gen_bool(0.4) {
+            return Haul::Materials(MATERIALS.choose(rng).unwrap().to_string(), rng.gen_range(1..=2));
+        }
+        let (names, effect): (&[&str], i32) = match action {
+            TributeAction::Forage => (&["berries", "roots", "nuts"], rng.gen_range(3..=8)),
+            TributeAction::Hunt => (&["rabbit", "groosling", "squirrel"], rng.gen_range(6..=12)),
+            TributeAction::Fish => (&["fish"], rng.gen_range(5..=10)),
+            _ => (&["water"], rng.gen_range(3..=6)),
+        };
+        return Haul::Provisions(names.choose(rng).unwrap().to_string(), effect);
+    }
+
+    let clumsiness = (100 - skill(action, tribute)).clamp(0, 100) as f64 / 200.0;
+    if !rng.gen_bool(clumsiness) {
+        return Haul::Nothing;
+    }
+    Haul::Mishap(match action {
+        TributeAction::Forage => TributeStatus::Poisoned,
+        TributeAction::Hunt => TributeStatus::Wounded,
+        TributeAction::Fish => TributeStatus::Drowned,
+        _ => TributeStatus::Sick,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn skill_and_resources_decide_the_haul() {
+        let mut tribute = Tribute::new("Katniss".to_string(), None, None);
+        tribute.intelligence = Some(20);
+        tribute.dexterity = Some(90);
+        assert!(success_chance(&TributeAction::Hunt, &tribute, 40) > success_chance(&TributeAction::Forage, &tribute, 40));
+        assert!(success_chance(&TributeAction::Fish, &tribute, 0) < success_chance(&TributeAction::Fish, &tribute, 50));
+        assert_eq!(success_chance(&TributeAction::FindWater, &tribute, 0), 0.1);
+
+        let mut rng = StdRng::seed_from_u64(5);
+        let hauls = (0..200).map(|_| gather(&TributeAction::Forage, &tribute, 0, &mut rng)).collect::<Vec<Haul>>();
+        assert!(hauls.iter().any(|haul| matches!(haul, Haul::Provisions(_, _))));
+        assert!(hauls.contains(&Haul::Mishap(TributeStatus::Poisoned)));
+    }
+
+    #[test]
+    fn tributes_go_after_what_they_need() {
+        let mut tribute = Tribute::new("Katniss".to_string(), None, None);
+        tribute.intelligence = Some(20);
+        tribute.dexterity = Some(90);
+        assert_eq!(best_survival_action(&tribute, &Area::Northwest), TributeAction::Hunt);
+
+        tribute.status = TributeStatus::Dehydrated;
+        assert_eq!(best_survival_action(&tribute, &Area::Northwest), TributeAction::FindWater);
+    }
+}
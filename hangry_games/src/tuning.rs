@@ -0,0 +1,224 @@
+use crate::animals::Animal;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+use strum::IntoEnumIterator;
+
+/// Games without tuning of their own use this file, if it exists.
+pub const TUNING_FILE: &str = "./tuning.json";
+
+/// The numbers that decide how a game plays out. Anything left out of a tuning file keeps its default.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Tuning {
+    /// Chance of an area event each day, once the first three days are over.
+    pub day_event_frequency: f64,
+    /// Chance of an area event each night.
+    pub night_event_frequency: f64,
+    /// The day of the feast, for games that don't schedule their own.
+    pub feast_day: i32,
+    /// How many items the Cornucopia is topped back up to for the feast.
+    pub feast_items: usize,
+    /// Areas start closing every phase once fewer than this many tributes are alive.
+    pub area_closing_threshold: usize,
+    /// Chance a closed area opens back up each phase.
+    pub area_reopen_chance: f64,
+    /// The finale begins once this many tributes or fewer are alive.
+    pub finale_tributes: usize,
+    /// The day the finale begins, however many tributes are left. `None` waits on the survivors alone.
+    pub finale_day: Option<i32>,
+    /// How strongly tributes are drawn to the Cornucopia during the finale.
+    pub finale_pull: f64,
+    /// Chance each phase that mutts hunt down a tribute holding out away from the Cornucopia.
+    pub mutt_chance: f64,
+    /// Days the finale can go without a death before the mutts come for the Cornucopia too.
+    pub finale_standoff_days: i32,
+    /// Chance each phase that a pack of wild animals wanders into an open area.
+    pub pack_spawn_chance: f64,
+    /// Chance each phase that a pack goes for someone in its area.
+    pub pack_attack_chance: f64,
+    /// Chance each phase that a tribute catches something contagious from an ally sharing their area.
+    /// Strangers are half as likely to catch it, and anyone hiding half as likely again.
+    pub contagion_chance: f64,
+    /// Chance of a sponsor gift each phase, starting from District 1.
+    /// Districts past the end of the list get the last chance.
+    pub sponsor_chances: Vec<f64>,
+    /// Damage each animal does when a pack of them mauls a tribute.
+    pub animal_damage: BTreeMap<Animal, i32>,
+    /// How far one side's roll has to beat the other's for a decisive hit.
+    pub decisive_margin: i32,
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        Tuning {
+            day_event_frequency: 1.0 / 4.0,
+            night_event_frequency: 1.0 / 8.0,
+            feast_day: 3,
+            feast_items: 12,
+            area_closing_threshold: 7,
+            area_reopen_chance: 0.5,
+            finale_tributes: 4,
+            finale_day: None,
+            finale_pull: 0.9,
+            mutt_chance: 0.5,
+            finale_standoff_days: 2,
+            pack_spawn_chance: 0.1,
+            pack_attack_chance: 0.4,
+            contagion_chance: 0.3,
+            sponsor_chances: [10.0, 10.0, 15.0, 15.0, 20.0, 20.0, 25.0, 25.0, 30.0, 30.0, 50.0, 50.0]
+                .iter()
+                .map(|odds| 1.0 / odds)
+                .collect(),
+            animal_damage: Animal::iter().map(|animal| (animal.clone(), animal.damage())).collect(),
+            decisive_margin: 5,
+        }
+    }
+}
+
+impl Tuning {
+    /// Reads and checks a tuning file.
+    pub fn load(path: &Path) -> Result<Tuning, Vec<String>> {
+        let contents = fs::read_to_string(path).map_err(|e| vec![e.to_string()])?;
+        let tuning: Tuning = serde_json::from_str(&contents).map_err(|e| vec![e.to_string()])?;
+        tuning.validate()?;
+        Ok(tuning)
+    }
+
+    /// Reads and checks a tuning saved as JSON, e.g. on a game.
+    pub fn from_value(value: serde_json::Value) -> Result<Tuning, Vec<String>> {
+        let tuning: Tuning = serde_json::from_value(value).map_err(|e| vec![e.to_string()])?;
+        tuning.validate()?;
+        Ok(tuning)
+    }
+
+    /// The tuning in `TUNING_FILE`, or the defaults if there isn't one or it's broken.
+    pub fn global() -> &'static Tuning {
+        static TUNING: OnceLock<Tuning> = OnceLock::new();
+        TUNING.get_or_init(|| {
+            let path = Path::new(TUNING_FILE);
+            if !path.exists() {
+                return Tuning::default();
+            }
+            Tuning::load(path).unwrap_or_else(|errors| {
+                eprintln!("Ignoring {}: {}", TUNING_FILE, errors.join("; "));
+                Tuning::default()
+            })
+        })
+    }
+
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = vec![];
+        let chances = [
+            ("day_event_frequency", self.day_event_frequency),
+            ("night_event_frequency", self.night_event_frequency),
+            ("area_reopen_chance", self.area_reopen_chance),
+            ("finale_pull", self.finale_pull),
+            ("mutt_chance", self.mutt_chance),
+            ("pack_spawn_chance", self.pack_spawn_chance),
+            ("pack_attack_chance", self.pack_attack_chance),
+            ("contagion_chance", self.contagion_chance),
+        ];
+        for (name, chance) in chances {
+            if !(0.0..=1.0).contains(&chance) {
+                errors.push(format!("{} must be between 0 and 1, got {}", name, chance));
+            }
+        }
+        for (index, chance) in self.sponsor_chances.iter().enumerate() {
+            if !(0.0..=1.0).contains(chance) {
+                errors.push(format!("District {}'s sponsor chance must be between 0 and 1, got {}", index + 1, chance));
+            }
+        }
+        if self.sponsor_chances.is_empty() {
+            errors.push("sponsor_chances needs at least one chance".to_string());
+        }
+        // Day one belongs to the bloodbath
+        if self.feast_day < 2 {
+            errors.push(format!("feast_day must be 2 or later, got {}", self.feast_day));
+        }
+        if self.finale_day.is_some_and(|day| day < 2) {
+            errors.push(format!("finale_day must be 2 or later, got {}", self.finale_day.unwrap()));
+        }
+        for (animal, damage) in &self.animal_damage {
+            if *damage < 0 {
+                errors.push(format!("{} damage can't be negative, got {}", animal.as_str(), damage));
+            }
+        }
+        if self.finale_standoff_days < 1 {
+            errors.push(format!("finale_standoff_days must be at least 1, got {}", self.finale_standoff_days));
+        }
+        if self.decisive_margin < 1 {
+            errors.push(format!("decisive_margin must be at least 1, got {}", self.decisive_margin));
+        }
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+
+    pub fn sponsor_chance(&self, district: i32) -> f64 {
+        let index = (district.max(1) as usize - 1).min(self.sponsor_chances.len().saturating_sub(1));
+        self.sponsor_chances.get(index).copied().unwrap_or(0.0)
+    }
+
+    pub fn animal_damage(&self, animal: &Animal) -> i32 {
+        self.animal_damage.get(animal).copied().unwrap_or(animal.damage())
+    }
+
+    /// Whether the finale is due with `living` tributes left on `day`.
+    pub fn finale_due(&self, living: usize, day: i32) -> bool {
+        living <= self.finale_tributes || self.finale_day.is_some_and(|finale_day| day >= finale_day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_match_the_original_balance() {
+        let tuning = Tuning::default();
+        assert_eq!(tuning.sponsor_chance(1), 1.0 / 10.0);
+        assert_eq!(tuning.sponsor_chance(6), 1.0 / 20.0);
+        assert_eq!(tuning.sponsor_chance(12), 1.0 / 50.0);
+        assert_eq!(tuning.sponsor_chance(13), 1.0 / 50.0);
+        assert_eq!(tuning.animal_damage(&Animal::Hippo), 20);
+        assert_eq!(tuning.validate(), Ok(()));
+    }
+
+    #[test]
+    fn files_only_need_what_they_change() {
+        let tuning: Tuning = serde_json::from_str(r#"{"feast_day": 5, "animal_damage": {"Squirrel": 7}}"#).unwrap();
+        assert_eq!(tuning.feast_day, 5);
+        assert_eq!(tuning.animal_damage(&Animal::Squirrel), 7);
+        assert_eq!(tuning.decisive_margin, Tuning::default().decisive_margin);
+
+        let round_trip: Tuning = serde_json::from_str(&serde_json::to_string(&tuning).unwrap()).unwrap();
+        assert_eq!(round_trip, tuning);
+    }
+
+    #[test]
+    fn saved_tuning_is_checked_too() {
+        assert_eq!(Tuning::from_value(serde_json::json!({"feast_day": 5})).unwrap().feast_day, 5);
+        assert!(Tuning::from_value(serde_json::json!({"feast_day": "soon"})).is_err());
+        assert!(Tuning::from_value(serde_json::json!({"feast_day": 0})).is_err());
+    }
+
+    #[test]
+    fn catches_impossible_values() {
+        let tuning = Tuning { night_event_frequency: 1.5, feast_day: 0, sponsor_chances: vec![], ..Tuning::default() };
+        let errors = tuning.validate().unwrap_err();
+        assert_eq!(errors[0], "night_event_frequency must be between 0 and 1, got 1.5");
+        assert_eq!(errors.len(), 3);
+    }
+
+    #[test]
+    fn finale_waits_for_survivors_or_its_day() {
+        let tuning = Tuning::default();
+        assert!(!tuning.finale_due(5, 20));
+        assert!(tuning.finale_due(4, 2));
+
+        let tuning = Tuning { finale_day: Some(6), ..Tuning::default() };
+        assert!(!tuning.finale_due(10, 5));
+        assert!(tuning.finale_due(10, 6));
+    }
+}
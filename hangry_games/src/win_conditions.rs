@@ -0,0 +1,173 @@
+use crate::i18n::{translate, Locale};
+use fluent_bundle::FluentArgs;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// What it takes to win a game.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum WinCondition {
+    /// One tribute left alive.
+    #[default]
+    LastStanding,
+    /// Everyone left alive is from the same district.
+    LastDistrict,
+    /// Everyone left alive is from the same alliance of districts.
+    LastAlliance,
+    /// Last one standing, but after this many days every tribute is driven
+    /// into the Cornucopia to fight it out.
+    TimeLimit(i32),
+}
+
+impl WinCondition {
+    pub fn as_str(&self) -> &str {
+        match self {
+            WinCondition::LastStanding => "last standing",
+            WinCondition::LastDistrict => "last district",
+            WinCondition::LastAlliance => "last alliance",
+            WinCondition::TimeLimit(_) => "time limit",
+        }
+    }
+
+    /// The win condition in `locale`.
+    pub fn localized(&self, locale: &Locale) -> String {
+        let mut args = FluentArgs::new();
+        if let WinCondition::TimeLimit(days) = self {
+            args.set("days", *days);
+        }
+        translate(locale, &format!("win-condition-{}", self.as_str().replace(' ', "-")), Some(&args))
+    }
+
+    /// The last day before sudden death, if there is one.
+    pub fn time_limit(&self) -> Option<i32> {
+        match self {
+            WinCondition::TimeLimit(days) => Some(*days),
+            _ => None,
+        }
+    }
+
+    /// Whether the tributes still alive, given by district, have won the game between them.
+    /// `alliances` groups districts that stand together; districts in none stand alone.
+    /// With `co_victors`, district mates can always win together.
+    pub fn is_won(&self, living_districts: &[i32], alliances: &[Vec<i32>], co_victors: bool) -> bool {
+        let Some(first) = living_districts.first() else { return false };
+        let all_share = |key: &dyn Fn(i32) -> (Option<usize>, i32)| {
+            living_districts.iter().all(|district| key(*district) == key(*first))
+        };
+        let by_district = |district: i32| (None, district);
+        let by_alliance = |district: i32| match alliances.iter().position(|a| a.contains(&district)) {
+            Some(alliance) => (Some(alliance), 0),
+            None => (None, district),
+        };
+
+        match self {
+            _ if living_districts.len() == 1 => true,
+            _ if co_victors && all_share(&by_district) => true,
+            WinCondition::LastDistrict => all_share(&by_district),
+            WinCondition::LastAlliance => all_share(&by_alliance),
+            WinCondition::LastStanding | WinCondition::TimeLimit(_) => false,
+        }
+    }
+}
+
+impl FromStr for WinCondition {
+    type Err = ();
+
+    /// Time limits take their days after the name, e.g. "time-limit 10" or "time-limit=10".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.to_lowercase().replace(['-', '_', '=', ':'], " ");
+        match s.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            ["last", "standing"] => Ok(WinCondition::LastStanding),
+            ["last", "district"] => Ok(WinCondition::LastDistrict),
+            ["last", "alliance"] => Ok(WinCondition::LastAlliance),
+            ["time", "limit", days] => days.parse().map(WinCondition::TimeLimit).map_err(|_| ()),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for WinCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WinCondition::TimeLimit(days) => write!(f, "{} {}", self.as_str(), days),
+            _ => write!(f, "{}", self.as_str()),
+        }
+    }
+}
+
+/// A rule the gamemakers can announce partway through a game, and take back later.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum RuleChange {
+    /// Both tributes from a district can be crowned victors together.
+    DistrictCoVictors,
+}
+
+impl RuleChange {
+    pub fn as_str(&self) -> &str {
+        match self {
+            RuleChange::DistrictCoVictors => "district co-victors",
+        }
+    }
+
+    /// The rule in `locale` as it's announced, or as it's taken back if `revoked`.
+    pub fn localized(&self, locale: &Locale, revoked: bool) -> String {
+        let key = format!("rule-change-{}", self.as_str().replace(' ', "-"));
+        translate(locale, &if revoked { format!("{}-revoked", key) } else { key }, None)
+    }
+}
+
+impl FromStr for RuleChange {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace(['-', '_'], " ").as_str() {
+            "district co victors" | "co victors" => Ok(RuleChange::DistrictCoVictors),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for RuleChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_names_and_time_limits() {
+        assert_eq!(WinCondition::from_str("last-district"), Ok(WinCondition::LastDistrict));
+        assert_eq!(WinCondition::from_str("time-limit=10"), Ok(WinCondition::TimeLimit(10)));
+        assert_eq!(WinCondition::from_str(&WinCondition::TimeLimit(7).to_string()), Ok(WinCondition::TimeLimit(7)));
+        assert_eq!(WinCondition::from_str("time limit"), Err(()));
+        assert_eq!(RuleChange::from_str("co-victors"), Ok(RuleChange::DistrictCoVictors));
+    }
+
+    #[test]
+    fn one_survivor_always_wins() {
+        for condition in [WinCondition::LastStanding, WinCondition::LastAlliance, WinCondition::TimeLimit(5)] {
+            assert!(condition.is_won(&[3], &[], false));
+            assert!(!condition.is_won(&[], &[], true));
+        }
+    }
+
+    #[test]
+    fn districts_and_alliances_win_together() {
+        let careers = vec![vec![1, 2, 4]];
+        assert!(WinCondition::LastDistrict.is_won(&[4, 4], &careers, false));
+        assert!(!WinCondition::LastDistrict.is_won(&[2, 4], &careers, false));
+        assert!(WinCondition::LastAlliance.is_won(&[1, 2, 4], &careers, false));
+        assert!(!WinCondition::LastAlliance.is_won(&[1, 12], &careers, false));
+        assert!(WinCondition::LastAlliance.is_won(&[12, 12], &careers, false));
+    }
+
+    #[test]
+    fn co_victors_rule_lets_district_mates_share_the_win() {
+        assert!(!WinCondition::LastStanding.is_won(&[12, 12], &[], false));
+        assert!(WinCondition::LastStanding.is_won(&[12, 12], &[], true));
+        assert!(!WinCondition::LastStanding.is_won(&[11, 12], &[], true));
+    }
+}